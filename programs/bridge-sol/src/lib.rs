@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program;
 use std::hash::{Hash, Hasher};
 use sha2::{Digest, Sha256};
-use secp256k1::{Message, PublicKey, Secp256k1};
+use solana_secp256k1_recover::secp256k1_recover;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer, Mint, Token, TokenAccount, Transfer},
+    memo::{build_memo, BuildMemo, Memo},
+    token::{burn, mint_to, transfer, Burn, Mint, MintTo, Token, TokenAccount, Transfer},
 };
 
 declare_id!("qbuMdeYxYJXBjU6C6qFKjZKjXmrU83eDQomHdrch826");
@@ -13,66 +19,273 @@ declare_id!("qbuMdeYxYJXBjU6C6qFKjZKjXmrU83eDQomHdrch826");
 pub const AUTHORIZED_LAUNCHER: Pubkey = pubkey!("9FEDyP1t345xFKVrJPN2TgQvQEJGz8KXE2xPV6TVXYY6");
 pub const MAX_SIGNATURES: usize = 16;
 pub const MAX_VALIDATORS: usize = 16;
+pub const MAX_PAYLOAD_LEN: usize = 512; // calldata forwarded to the EVM-side executor
+pub const MAX_TAX_BPS: u16 = 1_000; // protocol-level fee ceiling, 10%. The owner can only go lower.
+pub const MAX_LP_FEE_SHARE_BPS: u16 = 10_000; // LPs can get at most all of the tax
+pub const DEFAULT_VALIDATOR_SET_GRACE_PERIOD: i64 = 24 * 60 * 60; // 1 day, in seconds
+pub const DEFAULT_HEARTBEAT_STALE_SLOTS: u64 = 216_000; // ~1 day at 400ms slots
+pub const DEFAULT_BOND_UNBONDING_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days, in seconds
+pub const DEFAULT_DEPOSIT_PROCESSING_WINDOW: i64 = 3 * 24 * 60 * 60; // 3 days, in seconds
+pub const DEFAULT_RECLAIM_BUFFER: i64 = 60 * 60; // 1 hour past the deadline for a last-second EVM mint to be attested
+pub const DEFAULT_NET_FLOW_WINDOW: i64 = 24 * 60 * 60; // 1 day, in seconds
+pub const RESERVES_HISTORY_LEN: usize = 32; // snapshots kept on-chain, older ones only live in the events
+pub const DEFAULT_RESERVES_SNAPSHOT_INTERVAL: u64 = 9_000; // ~1 hour at 400ms slots, so the history spans more than a few minutes
+pub const DAILY_STATS_LEN: usize = 30; // days of per-day stats kept in the ring buffer
+pub const VALIDATOR_SET_HISTORY_LEN: usize = 4; // previous validator sets kept around for their grace period
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+pub const ROTATION_DOMAIN: &[u8] = b"bridge-sol:rotate"; // keeps rotation approvals from being replayed as anything else
+pub const HEARTBEAT_DOMAIN: &[u8] = b"bridge-sol:heartbeat"; // same, for heartbeats
+pub const BOND_DOMAIN: &[u8] = b"bridge-sol:bond"; // same, for a validator naming its bond authority
+pub const MAX_HEARTBEAT_AGE_SLOTS: u64 = 150; // ~1 minute, how old a signed heartbeat can be when it lands
 
 #[program]
 pub mod bridge_sol {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>, tax: u16) -> Result<()> {
+    pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+        require!(args.max_tax <= MAX_TAX_BPS, ErrorCode::TaxCeilingTooHigh);
+        validate_tax(args.tax, args.max_tax)?;
+        validate_validator_set(&args.validators, args.required_signatures)?;
+        if args.ownership_transfer_window <= 0 {
+            return Err(error!(ErrorCode::InvalidOwnershipTransferWindow));
+        }
+        // a wrapped token has to be mintable by the pool before anything can be bridged in
+        if args.mode == BridgeMode::BurnMint
+            && ctx.accounts.usdc_mint.mint_authority != COption::Some(ctx.accounts.pool_state.key())
+        {
+            return Err(error!(ErrorCode::InvalidMintAuthority));
+        }
         let pool_state = &mut ctx.accounts.pool_state;
+        let now = Clock::get()?.unix_timestamp;
         pool_state.owner = ctx.accounts.owner.key();
-        pool_state.proposed_owner = pool_state.owner;
+        pool_state.proposed_owner = Pubkey::default();
+        pool_state.ownership_proposed_at = 0;
+        pool_state.ownership_transfer_window = args.ownership_transfer_window;
         pool_state.usdc_mint = ctx.accounts.usdc_mint.key();
         pool_state.paused = false;
-        pool_state.required_signatures = 1;
-        pool_state.total_volume = 0;
-        pool_state.tax = tax;
+        pool_state.mode = args.mode;
+        pool_state.permissioned = args.permissioned;
+        pool_state.required_signatures = args.required_signatures;
+        pool_state.validator_set_epoch = 0;
+        pool_state.validator_set_grace_period = DEFAULT_VALIDATOR_SET_GRACE_PERIOD;
+        pool_state.heartbeat_stale_slots = DEFAULT_HEARTBEAT_STALE_SLOTS;
+        pool_state.bond_unbonding_period = DEFAULT_BOND_UNBONDING_PERIOD;
+        pool_state.rotation_requires_approval = false;
+        pool_state.total_deposited = 0;
+        pool_state.total_withdrawn = 0;
+        pool_state.total_fees_collected = 0;
+        pool_state.deposit_count = 0;
+        pool_state.withdrawal_count = 0;
+        pool_state.tax = args.tax;
+        pool_state.max_tax = args.max_tax;
         pool_state.accumulated_fees = 0;
+        pool_state.deposit_sequence = 0;
+        pool_state.pending_deposit_liabilities = 0;
+        pool_state.deposit_processing_window = DEFAULT_DEPOSIT_PROCESSING_WINDOW;
+        pool_state.reclaim_buffer = DEFAULT_RECLAIM_BUFFER;
+        pool_state.refund_fees = false;
+        pool_state.max_deposit_amount = args.max_deposit_amount;
+        pool_state.max_withdrawal_amount = args.max_withdrawal_amount;
+        pool_state.max_native_drop = 0;
+        pool_state.max_native_drop_per_recipient = 0;
+        pool_state.total_liquidity = 0;
+        pool_state.lp_fee_share_bps = 0;
+        pool_state.iou_head = 0;
+        pool_state.iou_tail = 0;
+        pool_state.pending_iou_liabilities = 0;
+        pool_state.reserves_snapshot_interval = DEFAULT_RESERVES_SNAPSHOT_INTERVAL;
+        pool_state.cctp_token_messenger = Pubkey::default();
+        pool_state.cctp_message_transmitter = Pubkey::default();
+        pool_state.net_flow_threshold = 0;
+        pool_state.net_flow_window = DEFAULT_NET_FLOW_WINDOW;
+        pool_state.net_flow_window_start = now;
+        pool_state.net_flow_deposited = 0;
+        pool_state.net_flow_withdrawn = 0;
+        pool_state.circuit_breaker_tripped = false;
+        pool_state.compliance_authority = Pubkey::default();
         pool_state.treasury = ctx.accounts.treasury.key();
         pool_state.bump = ctx.bumps.pool_state;
         pool_state.validators = [Pubkey::default(); MAX_VALIDATORS];
-        pool_state.validators[0] = ctx.accounts.owner.key();
+        pool_state.validator_joined_slots = [0; MAX_VALIDATORS];
+        let slot = Clock::get()?.slot;
+        for (i, validator) in args.validators.iter().enumerate() {
+            pool_state.validators[i] = *validator;
+            pool_state.validator_joined_slots[i] = slot;
+        }
         emit!(PoolCreated {
             address: pool_state.key(),
             treasury: pool_state.treasury,
+            usdc_mint: pool_state.usdc_mint,
+            mode: pool_state.mode,
+            permissioned: pool_state.permissioned,
             tax: pool_state.tax,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        emit!(ValidatorAdded {
-            address: ctx.accounts.owner.key(),
-            pool_state: pool_state.key(),
-            required_validators: pool_state.required_signatures,
-            timestamp: Clock::get()?.unix_timestamp,
+            max_tax: pool_state.max_tax,
+            required_signatures: pool_state.required_signatures,
+            max_deposit_amount: pool_state.max_deposit_amount,
+            max_withdrawal_amount: pool_state.max_withdrawal_amount,
+            timestamp: now,
         });
+        for validator in args.validators.iter() {
+            emit!(ValidatorAdded {
+                address: *validator,
+                pool_state: pool_state.key(),
+                required_validators: pool_state.required_signatures,
+                timestamp: now,
+            });
+        }
 
         Ok(())
     }
 
     pub fn update_state(ctx: Context<UpdateStateContext>, args: ConfigUpdateArgs) -> Result<()> {
         let state = &mut ctx.accounts.pool_state;
+        let now = Clock::get()?.unix_timestamp;
         if let Some(new_treasury) = args.treasury {
             state.treasury = new_treasury;
         };
+        if let Some(new_window) = args.ownership_transfer_window {
+            // a zero or negative window would make every proposal instantly stale
+            if new_window <= 0 {
+                return Err(error!(ErrorCode::InvalidOwnershipTransferWindow));
+            }
+            state.ownership_transfer_window = new_window;
+        };
         if let Some(proposed_owner) = args.owner {
+            if proposed_owner == Pubkey::default() {
+                return Err(error!(ErrorCode::InvalidOwnershipChange));
+            }
             state.proposed_owner = proposed_owner;
+            state.ownership_proposed_at = now;
+            emit!(OwnershipTransferProposed {
+                address: state.key(),
+                current_owner: state.owner,
+                proposed_owner,
+                expires_at: now.saturating_add(state.ownership_transfer_window),
+                timestamp: now,
+            });
+        };
+        if let Some(new_max_tax) = args.max_tax {
+            // the ceiling is a ratchet, it can only come down
+            require!(new_max_tax <= state.max_tax, ErrorCode::TaxCeilingTooHigh);
+            state.max_tax = new_max_tax;
         };
         if let Some(new_tax) = args.tax {
-            // tax cannot be zero
-            if new_tax == 0 {
-                return Err(error!(ErrorCode::ZeroTax));
-            }
             state.tax = new_tax;
         };
+        // re-check even if only the ceiling moved, so it can't be lowered under the live tax
+        validate_tax(state.tax, state.max_tax)?;
         if let Some(new_paused) = args.paused {
             state.paused = new_paused;
+            // unpausing is how the owner resets a tripped breaker, with a clean window so the
+            // outflow that tripped it doesn't trip it again on the next withdrawal
+            if !new_paused && state.circuit_breaker_tripped {
+                state.circuit_breaker_tripped = false;
+                reset_net_flow_window(state, now);
+            }
+        };
+        if let Some(requires_approval) = args.rotation_requires_approval {
+            // one-way, otherwise the owner could just switch it off before rotating
+            require!(
+                requires_approval || !state.rotation_requires_approval,
+                ErrorCode::RotationApprovalLocked
+            );
+            state.rotation_requires_approval = requires_approval;
+        };
+        if let Some(new_grace_period) = args.validator_set_grace_period {
+            // zero is allowed, it just means old attestations die with the rotation
+            require!(new_grace_period >= 0, ErrorCode::InvalidGracePeriod);
+            state.validator_set_grace_period = new_grace_period;
+        };
+        if let Some(new_stale_slots) = args.heartbeat_stale_slots {
+            require!(new_stale_slots > 0, ErrorCode::InvalidStaleWindow);
+            state.heartbeat_stale_slots = new_stale_slots;
+        };
+        if let Some(new_unbonding_period) = args.bond_unbonding_period {
+            require!(new_unbonding_period > 0, ErrorCode::InvalidUnbondingPeriod);
+            state.bond_unbonding_period = new_unbonding_period;
+        };
+        if let Some(new_processing_window) = args.deposit_processing_window {
+            require!(new_processing_window > 0, ErrorCode::InvalidProcessingWindow);
+            state.deposit_processing_window = new_processing_window;
+        };
+        if let Some(new_reclaim_buffer) = args.reclaim_buffer {
+            require!(new_reclaim_buffer > 0, ErrorCode::InvalidReclaimBuffer);
+            state.reclaim_buffer = new_reclaim_buffer;
+        };
+        if let Some(refund_fees) = args.refund_fees {
+            state.refund_fees = refund_fees;
+        };
+        if let Some(new_max_deposit) = args.max_deposit_amount {
+            state.max_deposit_amount = new_max_deposit;
+        };
+        if let Some(new_max_withdrawal) = args.max_withdrawal_amount {
+            state.max_withdrawal_amount = new_max_withdrawal;
+        };
+        if let Some(new_max_drop) = args.max_native_drop {
+            state.max_native_drop = new_max_drop;
+        };
+        if let Some(new_recipient_cap) = args.max_native_drop_per_recipient {
+            state.max_native_drop_per_recipient = new_recipient_cap;
+        };
+        if let Some(new_token_messenger) = args.cctp_token_messenger {
+            state.cctp_token_messenger = new_token_messenger;
+        };
+        if let Some(new_message_transmitter) = args.cctp_message_transmitter {
+            state.cctp_message_transmitter = new_message_transmitter;
+        };
+        if let Some(new_lp_fee_share) = args.lp_fee_share_bps {
+            require!(new_lp_fee_share <= MAX_LP_FEE_SHARE_BPS, ErrorCode::InvalidLpFeeShare);
+            state.lp_fee_share_bps = new_lp_fee_share;
+        };
+        if let Some(new_compliance_authority) = args.compliance_authority {
+            // the point of the role is a second key, handing it to the owner would defeat that
+            require!(
+                new_compliance_authority != state.owner,
+                ErrorCode::ComplianceAuthorityIsOwner
+            );
+            state.compliance_authority = new_compliance_authority;
+        };
+        if let Some(permissioned) = args.permissioned {
+            state.permissioned = permissioned;
+        };
+        if let Some(new_threshold) = args.net_flow_threshold {
+            state.net_flow_threshold = new_threshold;
+        };
+        if let Some(new_net_flow_window) = args.net_flow_window {
+            require!(new_net_flow_window > 0, ErrorCode::InvalidNetFlowWindow);
+            state.net_flow_window = new_net_flow_window;
+        };
+        if let Some(new_snapshot_interval) = args.reserves_snapshot_interval {
+            require!(new_snapshot_interval > 0, ErrorCode::InvalidSnapshotInterval);
+            state.reserves_snapshot_interval = new_snapshot_interval;
         };
         emit!(PoolStateUpdated {
             address: state.key(),
             treasury: state.treasury,
-            proposed_owner: state.proposed_owner,
             tax: state.tax,
+            max_tax: state.max_tax,
             paused: state.paused,
-            timestamp: Clock::get()?.unix_timestamp,
+            rotation_requires_approval: state.rotation_requires_approval,
+            validator_set_grace_period: state.validator_set_grace_period,
+            heartbeat_stale_slots: state.heartbeat_stale_slots,
+            bond_unbonding_period: state.bond_unbonding_period,
+            deposit_processing_window: state.deposit_processing_window,
+            reclaim_buffer: state.reclaim_buffer,
+            refund_fees: state.refund_fees,
+            ownership_transfer_window: state.ownership_transfer_window,
+            max_deposit_amount: state.max_deposit_amount,
+            max_withdrawal_amount: state.max_withdrawal_amount,
+            max_native_drop: state.max_native_drop,
+            max_native_drop_per_recipient: state.max_native_drop_per_recipient,
+            lp_fee_share_bps: state.lp_fee_share_bps,
+            cctp_token_messenger: state.cctp_token_messenger,
+            cctp_message_transmitter: state.cctp_message_transmitter,
+            net_flow_threshold: state.net_flow_threshold,
+            net_flow_window: state.net_flow_window,
+            compliance_authority: state.compliance_authority,
+            permissioned: state.permissioned,
+            reserves_snapshot_interval: state.reserves_snapshot_interval,
+            timestamp: now,
         });
         Ok(())
     } 
@@ -80,17 +293,48 @@ pub mod bridge_sol {
     pub fn accept_ownership(ctx: Context<AcceptOwnershipContext>) -> Result<()> {
         let state = &mut ctx.accounts.pool_state;
         let signer_key = ctx.accounts.signer.key();
-        if signer_key == state.proposed_owner {
-            state.owner = signer_key;
-            state.proposed_owner = Pubkey::default();
-            emit!(OwnerChanged {
-                address: state.key(),
-                new_owner: signer_key,
-                timestamp: Clock::get()?.unix_timestamp,
-            });
-        } else {
+        let now = Clock::get()?.unix_timestamp;
+        if state.proposed_owner == Pubkey::default() {
+            return Err(error!(ErrorCode::NoPendingOwnershipTransfer));
+        }
+        if signer_key != state.proposed_owner {
             return Err(error!(ErrorCode::InvalidOwnershipChange));
         }
+        // proposals only live for `ownership_transfer_window` seconds
+        let expires_at = state
+            .ownership_proposed_at
+            .saturating_add(state.ownership_transfer_window);
+        if now > expires_at {
+            return Err(error!(ErrorCode::OwnershipTransferExpired));
+        }
+        require!(
+            signer_key != state.compliance_authority,
+            ErrorCode::ComplianceAuthorityIsOwner
+        );
+        state.owner = signer_key;
+        state.proposed_owner = Pubkey::default();
+        state.ownership_proposed_at = 0;
+        emit!(OwnerChanged {
+            address: state.key(),
+            new_owner: signer_key,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransferContext>) -> Result<()> {
+        let state = &mut ctx.accounts.pool_state;
+        if state.proposed_owner == Pubkey::default() {
+            return Err(error!(ErrorCode::NoPendingOwnershipTransfer));
+        }
+        let cancelled_owner = state.proposed_owner;
+        state.proposed_owner = Pubkey::default();
+        state.ownership_proposed_at = 0;
+        emit!(OwnershipTransferCancelled {
+            address: state.key(),
+            cancelled_owner,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
@@ -106,13 +350,26 @@ pub mod bridge_sol {
         if count >= MAX_VALIDATORS {
             return Err(error!(ErrorCode::TooManyValidators));
         }
+        let now = Clock::get()?.unix_timestamp;
+        snapshot_validator_set(
+            &mut ctx.accounts.validator_set_snapshot,
+            state,
+            ctx.bumps.validator_set_snapshot,
+            now,
+        );
         state.validators[count] = new_validator_key;
+        state.validator_joined_slots[count] = Clock::get()?.slot;
         state.required_signatures = (count as u8) + 1; // zero indexing, idk, if validators[0] is filled, this would be 1, if validators[0] and validators[1], this would be 2, etc
+        // any change to the set invalidates attestations signed for the old one
+        state.validator_set_epoch = state
+            .validator_set_epoch
+            .checked_add(1)
+            .ok_or_else(|| error!(ErrorCode::EpochOverflow))?;
         emit!(ValidatorAdded {
             address: new_validator_key,
             pool_state: state.key(),
             required_validators: state.required_signatures,
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
         });
         Ok(())
     }
@@ -120,288 +377,1203 @@ pub mod bridge_sol {
     pub fn remove_validator(ctx: Context<RemoveValidatorContext>) -> Result<()> {
         let state = &mut ctx.accounts.pool_state;
         let old_validator_key = &ctx.accounts.old_validator.key();
-        // find the index of the old validator
-        let mut index = 0;
-        let mut found_index = false;
-        for i in 0..MAX_VALIDATORS {
-            if state.validators[i] == *old_validator_key {
-                index = i;
-                found_index = true;
-            }
-        }
-        if found_index == false {
-            return Err(error!(ErrorCode::ValidatorDoesNotExist))
-        }
-        // oh god, shuffling an index array smdh
-        // Shift all validators above the found index down by one
-        for i in index..(MAX_VALIDATORS - 1) {
-            state.validators[i] = state.validators[i + 1];
-        }
-        // Set the last index to the default value
-        state.validators[MAX_VALIDATORS - 1] = Pubkey::default();
-
+        let now = Clock::get()?.unix_timestamp;
+        snapshot_validator_set(
+            &mut ctx.accounts.validator_set_snapshot,
+            state,
+            ctx.bumps.validator_set_snapshot,
+            now,
+        );
+        remove_validator_from_set(state, old_validator_key)?;
         // Update the required_signatures to reflect the new count of validators
-        state.required_signatures -= 1;
+        state.required_signatures = state.required_signatures.saturating_sub(1).max(1);
 
         // Emit an event for the removed validator
         emit!(ValidatorRemoved {
             address: *old_validator_key,
             pool_state: state.key(),
             required_validators: state.required_signatures,
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
         });
         Ok(())
     }
 
-    pub fn deposit_usdc(
-            ctx: Context<DepositUSDCContext>,
-            args: DepositUSDCArgs,
-        ) -> Result<()> {
+    pub fn rotate_validator_set(
+        ctx: Context<RotateValidatorSetContext>,
+        args: RotateValidatorSetArgs,
+    ) -> Result<()> {
         let state = &mut ctx.accounts.pool_state;
-        let depositor = &mut ctx.accounts.depositor;
-        let state_ata = &mut ctx.accounts.pool_ata;
-        let depositor_ata = &mut ctx.accounts.depositor_ata;
-        let amount = args.amount;
-        let recipient_evm_address = args.recipient_evm_address;
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
-        depositor.key().hash(&mut hasher);
-        state_ata.key().hash(&mut hasher);
-        Clock::get()?.unix_timestamp.hash(&mut hasher);
-        recipient_evm_address.hash(&mut hasher);
-        amount.hash(&mut hasher);
-        let nonce: u64 = hasher.finish();
-        // calculate the tax from state.tax, a basis point fee
-        let tax_amount = amount
-            .checked_mul(state.tax as u64)
-            .and_then(|result| result.checked_div(10000))
-            .ok_or_else(|| error!(ErrorCode::TaxFailed))?;
-        let deposit_amount = amount.checked_sub(tax_amount).ok_or_else(|| error!(ErrorCode::TaxFailed))?;
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: depositor_ata.to_account_info(),
-                to: state_ata.to_account_info(),
-                authority: depositor.to_account_info(),
-            },
+        validate_validator_set(&args.validators, args.required_signatures)?;
+        let new_epoch = state
+            .validator_set_epoch
+            .checked_add(1)
+            .ok_or_else(|| error!(ErrorCode::EpochOverflow))?;
+
+        if state.rotation_requires_approval {
+            // the outgoing set signs off on its replacement
+            let message = build_rotation_message(
+                &state.key(),
+                new_epoch,
+                &args.validators,
+                args.required_signatures,
+            );
+            let signatures = collect_signatures(&args.r, &args.s, &args.v);
+            let verified = verify_signatures(
+                &signatures,
+                &state.validators,
+                &message,
+                state.required_signatures
+            )?;
+            if !verified {
+                return Err(error!(ErrorCode::FailedToValidate));
+            }
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let previous_set_expires_at = snapshot_validator_set(
+            &mut ctx.accounts.validator_set_snapshot,
+            state,
+            ctx.bumps.validator_set_snapshot,
+            now,
         );
-        // NOTE: Transfers the original `amount` value - leaves the `tax` **inside** the pool!
-        // tax can be recovered by accumulating the event emissions
-        transfer(transfer_ctx, amount)?;
-        state.accumulated_fees += tax_amount;
-        if let Some(new_total) = state.total_volume.checked_add(amount) {
-            state.total_volume = new_total;
-        } else {
-            // Log or handle the overflow case without throwing an error
-            msg!("Warning: total_volume overflowed, skipping update.");
+        let previous_validators = state.validators;
+        let previous_joined_slots = state.validator_joined_slots;
+        let previous_required_signatures = state.required_signatures;
+        let slot = Clock::get()?.slot;
+        state.validators = [Pubkey::default(); MAX_VALIDATORS];
+        state.validator_joined_slots = [0; MAX_VALIDATORS];
+        for (i, validator) in args.validators.iter().enumerate() {
+            state.validators[i] = *validator;
+            // validators that stay on keep their liveness history
+            state.validator_joined_slots[i] = previous_validators
+                .iter()
+                .position(|previous| previous == validator)
+                .map_or(slot, |j| previous_joined_slots[j]);
         }
+        state.required_signatures = args.required_signatures;
+        state.validator_set_epoch = new_epoch;
 
-        emit!(USDCDeposited {
-            address: state.key(),
-            depositor: depositor.key(),
-            amount: deposit_amount, // Amount after fee
-            recipient_evm_address,
-            tax: tax_amount,
-            nonce,
+        emit!(ValidatorSetRotated {
+            pool_state: state.key(),
+            previous_validators: previous_validators
+                .iter()
+                .filter(|v| **v != Pubkey::default())
+                .copied()
+                .collect(),
+            previous_required_signatures,
+            validators: args.validators,
+            required_signatures: state.required_signatures,
+            validator_set_epoch: state.validator_set_epoch,
+            previous_set_expires_at,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// Drops a previous validator set before its grace period is up, e.g. once its keys are
+    /// known to be compromised. Attestations it signed stop verifying right away.
+    pub fn revoke_validator_set_snapshot(
+        ctx: Context<RevokeValidatorSetSnapshotContext>,
+        validator_set_epoch: u64,
+    ) -> Result<()> {
+        let entry = ctx
+            .accounts
+            .validator_set_snapshot
+            .sets
+            .iter_mut()
+            .find(|set| set.required_signatures > 0 && set.validator_set_epoch == validator_set_epoch)
+            .ok_or_else(|| error!(ErrorCode::UnknownValidatorSetEpoch))?;
+        *entry = PreviousValidatorSet::default();
+        emit!(ValidatorSetSnapshotRevoked {
+            pool_state: ctx.accounts.pool_state.key(),
+            validator_set_epoch,
             timestamp: Clock::get()?.unix_timestamp,
         });
         Ok(())
     }
 
-    pub fn withdraw_usdc(
-        ctx: Context<WithdrawUSDCContext>,
-        recipient: Pubkey,
-        args: WithdrawUSDCArgs,
+    /// Records that a validator was alive at `args.slot`. The validator signs the heartbeat with
+    /// its secp256k1 key and anyone can relay it, so validators don't need a funded Solana key.
+    pub fn validator_heartbeat(
+        ctx: Context<ValidatorHeartbeatContext>,
+        args: ValidatorHeartbeatArgs,
     ) -> Result<()> {
-        let message = build_message(
-            &args.nonce,
-            &args.amount,
-            &args.sender_evm_address,
-            &recipient
+        let liveness = &mut ctx.accounts.validator_liveness;
+        let clock = Clock::get()?;
+        // not from the future, not a replay of an older one, and not sitting around for too long
+        require!(
+            args.slot <= clock.slot && args.slot > liveness.last_seen_slot,
+            ErrorCode::InvalidHeartbeatSlot
+        );
+        require!(
+            clock.slot - args.slot <= MAX_HEARTBEAT_AGE_SLOTS,
+            ErrorCode::HeartbeatTooOld
+        );
+        let message = build_heartbeat_message(&ctx.accounts.pool_state.key(), &args.validator, args.slot);
+        let signature = Signature {
+            r: args.r,
+            s: args.s,
+            v: args.v,
+        };
+        require!(
+            verify_signature(&signature, &args.validator, &message)?,
+            ErrorCode::FailedToValidate
         );
-        let pool_state = &mut ctx.accounts.pool_state;
-        let validators = pool_state.validators;
-        let required_signatures = pool_state.required_signatures;
-        // args.r, args.s, args.v need to be converted into &[Signatures], which is a struct with fields r, s, v.
-        // the index of args.r, etc should match the index of &[Signatures].
-        let mut signatures: [Signature; MAX_VALIDATORS] = [Signature {
-            r: [0u8; 32],
-            s: [0u8; 32],
-            v: 0,
-        }; MAX_VALIDATORS];
-        for i in 0..MAX_VALIDATORS {
-            signatures[i].r = args.r[i];
-            signatures[i].s = args.s[i];
-            signatures[i].v = args.v[i];
-        }
 
-        // **I GUESS!!!** validators[i] == signatures[i], maybe.
-        // This is kind of fucked
+        liveness.validator = args.validator;
+        liveness.last_seen_slot = args.slot;
+        liveness.last_seen_timestamp = clock.unix_timestamp;
+        liveness.stale = false;
+        liveness.bump = ctx.bumps.validator_liveness;
+        emit!(ValidatorHeartbeat {
+            pool_state: ctx.accounts.pool_state.key(),
+            validator: liveness.validator,
+            slot: args.slot,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
 
-        let verified = verify_signatures(
-            &signatures,
-            &validators,
-            &message,
-            required_signatures
-        )?;
-        if verified {
-            // do the transfer
-            let pool_ata = &mut ctx.accounts.pool_ata;
-            let recipient_ata = &mut ctx.accounts.recipient_ata;
+    /// Flags a validator that has not sent a heartbeat within `heartbeat_stale_slots` and takes
+    /// it out of the live set, bringing the threshold down if it can no longer be met. The last
+    /// validator is flagged but never removed. Previous sets keep it, it was quiet, not malicious.
+    pub fn flag_stale_validator(ctx: Context<FlagStaleValidatorContext>) -> Result<()> {
+        let validator_key = ctx.accounts.validator.key();
+        let state = &mut ctx.accounts.pool_state;
+        let liveness = &mut ctx.accounts.validator_liveness;
+        let clock = Clock::get()?;
+        // a validator that never sent a heartbeat counts from the slot it joined the set
+        let joined_slot = state
+            .validators
+            .iter()
+            .position(|v| *v == validator_key)
+            .map_or(0, |i| state.validator_joined_slots[i]);
+        // a flag from before it was rotated back in doesn't count
+        require!(
+            !liveness.stale || liveness.flagged_slot < joined_slot,
+            ErrorCode::ValidatorAlreadyStale
+        );
+        let slots_since_seen = clock
+            .slot
+            .saturating_sub(liveness.last_seen_slot.max(joined_slot));
+        require!(
+            slots_since_seen > state.heartbeat_stale_slots,
+            ErrorCode::ValidatorNotStale
+        );
+        liveness.validator = validator_key;
+        liveness.stale = true;
+        liveness.flagged_slot = clock.slot;
+        liveness.bump = ctx.bumps.validator_liveness;
 
-            let transfer_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: pool_ata.to_account_info(),
-                    to: recipient_ata.to_account_info(),
-                    authority: pool_state.to_account_info(),
-                },
+        let remaining = state
+            .validators
+            .iter()
+            .filter(|v| **v != Pubkey::default())
+            .count() as u8;
+        let removed = remaining > 1;
+        if removed {
+            snapshot_validator_set(
+                &mut ctx.accounts.validator_set_snapshot,
+                state,
+                ctx.bumps.validator_set_snapshot,
+                clock.unix_timestamp,
             );
-            // NOTE: Transfers the amount **less tax** paid on the opposing chain
-            // NOTE: transfers *from* EVM have already paid the tax to treasury
-            transfer(transfer_ctx, args.amount)?;
-
-            // flex the transfer
-            if let Some(new_total) = pool_state.total_volume.checked_add(args.amount) {
-                pool_state.total_volume = new_total;
-            } else {
-                // Log or handle the overflow case without throwing an error
-                msg!("Warning: total_volume overflowed, skipping update.");
-            }
-
-            emit!(USDCWithdrawn {
-                address: pool_state.key(),
-                recipient: recipient.key(),
-                amount: args.amount, // Amount after fee
-                timestamp: Clock::get()?.unix_timestamp,
+            remove_validator_from_set(state, &validator_key)?;
+            // the threshold only comes down if there are no longer enough validators to meet it
+            state.required_signatures = state.required_signatures.min(remaining - 1);
+        }
+        emit!(ValidatorStale {
+            pool_state: state.key(),
+            validator: validator_key,
+            last_seen_slot: liveness.last_seen_slot,
+            slots_since_seen,
+            flagged_by: ctx.accounts.flagger.key(),
+            removed,
+            required_signatures: state.required_signatures,
+            timestamp: clock.unix_timestamp,
+        });
+        if removed {
+            emit!(ValidatorRemoved {
+                address: validator_key,
+                pool_state: state.key(),
+                required_validators: state.required_signatures,
+                timestamp: clock.unix_timestamp,
             });
-        } else {
-            return Err(error!(ErrorCode::FailedToValidate))
         }
-
         Ok(())
     }
 
-    pub fn withdraw_fees(ctx: Context<WithdrawFeesContext>) -> Result<()> {
-        let pool_state = &mut ctx.accounts.pool_state;
-        let pool_ata = &mut ctx.accounts.pool_ata;
-        let treasury_ata = &mut ctx.accounts.treasury_ata;
+    /// Adds to a validator's bond. Validators only have secp256k1 keys, so the bond belongs to
+    /// a Solana `authority` the validator names by signing `build_bond_authority_message`.
+    pub fn bond_validator(ctx: Context<BondValidatorContext>, args: BondValidatorArgs) -> Result<()> {
+        require!(args.amount > 0, ErrorCode::ZeroBond);
+        let authority = ctx.accounts.authority.key();
+        let message = build_bond_authority_message(
+            &ctx.accounts.pool_state.key(),
+            &args.validator,
+            &authority,
+        );
+        let signature = Signature {
+            r: args.r,
+            s: args.s,
+            v: args.v,
+        };
+        require!(
+            verify_signature(&signature, &args.validator, &message)?,
+            ErrorCode::FailedToValidate
+        );
+        let bond = &ctx.accounts.validator_bond;
+        require!(
+            bond.authority == Pubkey::default() || bond.authority == authority,
+            ErrorCode::NotBondAuthority
+        );
+        require!(bond.unbonding_at == 0, ErrorCode::BondUnbonding);
 
         let transfer_ctx = CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
-                from: pool_ata.to_account_info(),
-                to: treasury_ata.to_account_info(),
-                authority: pool_state.to_account_info(),
+                from: ctx.accounts.authority_ata.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
             },
         );
-        transfer(transfer_ctx, pool_state.accumulated_fees)?;
-        let emission_fees = pool_state.accumulated_fees;
-        pool_state.accumulated_fees = 0;
-        emit!(FeesWithdrawn {
-            address: pool_state.key(),
-            recipient: ctx.accounts.treasury.key(),
-            amount: emission_fees,
+        transfer(transfer_ctx, args.amount)?;
+
+        let bond = &mut ctx.accounts.validator_bond;
+        bond.validator = args.validator;
+        bond.authority = authority;
+        bond.amount = bond
+            .amount
+            .checked_add(args.amount)
+            .ok_or_else(|| error!(ErrorCode::BondOverflow))?;
+        bond.bump = ctx.bumps.validator_bond;
+        emit!(ValidatorBonded {
+            pool_state: ctx.accounts.pool_state.key(),
+            validator: bond.validator,
+            authority,
+            amount: args.amount,
+            total_bond: bond.amount,
             timestamp: Clock::get()?.unix_timestamp,
         });
         Ok(())
     }
 
-}
+    /// Starts the unbonding clock once the validator has left the live set. The bond can
+    /// still be slashed until it is withdrawn.
+    pub fn start_unbonding(ctx: Context<StartUnbondingContext>) -> Result<()> {
+        let state = &ctx.accounts.pool_state;
+        let bond = &mut ctx.accounts.validator_bond;
+        require!(
+            !state.validators.contains(&bond.validator),
+            ErrorCode::ValidatorStillActive
+        );
+        require!(bond.unbonding_at == 0, ErrorCode::BondUnbonding);
+        let now = Clock::get()?.unix_timestamp;
+        bond.unbonding_at = now.saturating_add(state.bond_unbonding_period);
+        emit!(ValidatorUnbonding {
+            pool_state: state.key(),
+            validator: bond.validator,
+            amount: bond.amount,
+            withdrawable_at: bond.unbonding_at,
+            timestamp: now,
+        });
+        Ok(())
+    }
 
-//  ========================================================================================================  //
-//  Account Contexts                                                                                          //
-//    ▄████████  ▄████████  ▄████████     ███           ▄████████     ███     ▀████    ▐████▀    ▄████████    //
-//    ███    ███ ███    ███ ███    ███ ▀█████████▄      ███    ███ ▀█████████▄   ███▌   ████▀    ███    ███   //
-//    ███    ███ ███    █▀  ███    █▀     ▀███▀▀██      ███    █▀     ▀███▀▀██    ███  ▐███      ███    █▀    //
-//    ███    ███ ███        ███            ███   ▀      ███            ███   ▀    ▀███▄███▀      ███          //
-//  ▀███████████ ███        ███            ███          ███            ███        ████▀██▄     ▀███████████   //
-//    ███    ███ ███    █▄  ███    █▄      ███          ███    █▄      ███       ▐███  ▀███             ███   //
-//    ███    ███ ███    ███ ███    ███     ███          ███    ███     ███      ▄███     ███▄     ▄█    ███   //
-//    ███    █▀  ████████▀  ████████▀     ▄████▀        ████████▀     ▄████▀   ████       ███▄  ▄████████▀    //
-//  ========================================================================================================  //    
+    /// Pays an unbonded bond back to its authority and closes it.
+    pub fn withdraw_bond(ctx: Context<WithdrawBondContext>) -> Result<()> {
+        let state = &ctx.accounts.pool_state;
+        let bond = &ctx.accounts.validator_bond;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            bond.unbonding_at != 0 && now >= bond.unbonding_at,
+            ErrorCode::BondStillLocked
+        );
+        // it may have been added back in the meantime
+        require!(
+            !state.validators.contains(&bond.validator),
+            ErrorCode::ValidatorStillActive
+        );
+        let amount = bond.amount;
+        if amount > 0 {
+            transfer_from_pool(
+                &ctx.accounts.token_program,
+                state,
+                &ctx.accounts.bond_vault,
+                ctx.accounts.authority_ata.to_account_info(),
+                amount,
+            )?;
+        }
+        emit!(ValidatorBondWithdrawn {
+            pool_state: state.key(),
+            validator: bond.validator,
+            authority: bond.authority,
+            amount,
+            timestamp: now,
+        });
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        mut,
-        // constraint = owner.key() == AUTHORIZED_LAUNCHER,
-    )]
-    pub owner: Signer<'info>,
+    pub fn slash_validator(ctx: Context<SlashValidatorContext>, args: SlashValidatorArgs) -> Result<()> {
+        let validator_key = ctx.accounts.validator.key();
+        let first = &args.first;
+        let second = &args.second;
+        // equivocation is two different transfers attested under the same nonce from the same chain
+        require!(
+            first.nonce == second.nonce && first.source_chain_id == second.source_chain_id,
+            ErrorCode::InvalidEquivocationProof
+        );
+        require!(
+            first.amount != second.amount || first.recipient != second.recipient,
+            ErrorCode::InvalidEquivocationProof
+        );
+        for attestation in [first, second] {
+            let message = build_message(
+                &attestation.nonce,
+                &attestation.amount,
+                &attestation.sender_evm_address,
+                &attestation.recipient,
+                attestation.validator_set_epoch,
+                &attestation.reference,
+                attestation.source_chain_id,
+                attestation.relayer_fee,
+                attestation.native_drop,
+            );
+            let signature = Signature {
+                r: attestation.r,
+                s: attestation.s,
+                v: attestation.v,
+            };
+            require!(
+                verify_signature(&signature, &validator_key, &message)?,
+                ErrorCode::InvalidEquivocationProof
+            );
+        }
 
-    #[account(
-        init, 
-        payer = owner, 
-        space = 8 + PoolState::INIT_SPACE,
-        seeds = [b"pool_state"],
-        bump
-    )]
-    pub pool_state: Account<'info, PoolState>,
+        let now = Clock::get()?.unix_timestamp;
+        let slashed_amount = ctx.accounts.validator_bond.amount;
+        // a validator that was already rotated out still loses its bond
+        let active = ctx.accounts.pool_state.validators.contains(&validator_key);
+        require!(slashed_amount > 0 || active, ErrorCode::NothingToSlash);
+        if slashed_amount > 0 {
+            let bump = ctx.accounts.pool_state.bump;
+            let signer_seeds: &[&[&[u8]]] = &[&[b"pool_state", &[bump]]];
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bond_vault.to_account_info(),
+                    to: ctx.accounts.insurance_fund.to_account_info(),
+                    authority: ctx.accounts.pool_state.to_account_info(),
+                },
+                signer_seeds,
+            );
+            transfer(transfer_ctx, slashed_amount)?;
+        }
+        let bond = &mut ctx.accounts.validator_bond;
+        bond.validator = validator_key;
+        bond.amount = 0;
+        bond.bump = ctx.bumps.validator_bond;
 
-    /// CHECK: The treasury where fees go
-    pub treasury: AccountInfo<'info>,
+        let state = &mut ctx.accounts.pool_state;
+        if active {
+            snapshot_validator_set(
+                &mut ctx.accounts.validator_set_snapshot,
+                state,
+                ctx.bumps.validator_set_snapshot,
+                now,
+            );
+            remove_validator_from_set(state, &validator_key)?;
+            // the threshold only comes down if there are no longer enough validators to meet it
+            let remaining = state
+                .validators
+                .iter()
+                .filter(|v| **v != Pubkey::default())
+                .count() as u8;
+            state.required_signatures = state.required_signatures.min(remaining).max(1);
+        }
+        // its signatures stop counting under every previous set too, including the one just saved
+        let snapshot = &mut ctx.accounts.validator_set_snapshot;
+        snapshot.bump = ctx.bumps.validator_set_snapshot;
+        scrub_validator_from_snapshot(snapshot, &validator_key);
 
-    /// CHECK: This is the USDC mint address
-    pub usdc_mint: AccountInfo<'info>,
-    pub system_program: Program<'info, System>,
-}
+        emit!(ValidatorSlashed {
+            pool_state: state.key(),
+            validator: validator_key,
+            amount: slashed_amount,
+            nonce: first.nonce,
+            reporter: ctx.accounts.reporter.key(),
+            removed: active,
+            required_signatures: state.required_signatures,
+            timestamp: now,
+        });
+        if active {
+            emit!(ValidatorRemoved {
+                address: validator_key,
+                pool_state: state.key(),
+                required_validators: state.required_signatures,
+                timestamp: now,
+            });
+        }
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct UpdateStateContext<'info> {
-    #[account(
-        mut,
-    )]
-    pub owner: Signer<'info>,
+    pub fn deposit_usdc(
+            ctx: Context<DepositUSDCContext>,
+            args: DepositUSDCArgs,
+        ) -> Result<()> {
+        execute_deposit(ctx.accounts, &ctx.bumps, args, false)
+    }
 
-    #[account(
-        mut,
-        seeds = [b"pool_state"],
-        bump = pool_state.bump,
-        constraint = pool_state.owner == owner.key(),
-    )]
-    pub pool_state: Account<'info, PoolState>,
+    pub fn deposit_usdc_cctp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DepositUSDCContext<'info>>,
+        args: DepositUSDCArgs,
+    ) -> Result<()> {
+        let state = &ctx.accounts.pool_state;
+        // CCTP burns real USDC out of the pool, there is nothing to burn in burn/mint mode
+        require!(state.mode == BridgeMode::LockUnlock, ErrorCode::WrongBridgeMode);
+        // Circle mints straight to the recipient, there is no executor over there to call
+        require!(args.target_contract.is_none(), ErrorCode::CctpCallNotSupported);
+        // the burn has to land on the chain the deposit id commits to
+        let destination_domain = cctp_domain(args.destination_chain_id)
+            .ok_or_else(|| error!(ErrorCode::CctpUnsupportedChain))?;
+        let token_messenger = state.cctp_token_messenger;
+        let mut mint_recipient = [0u8; 32];
+        mint_recipient[12..].copy_from_slice(&args.recipient_evm_address);
 
-    pub system_program: Program<'info, System>,
-}
+        execute_deposit(ctx.accounts, &ctx.bumps, args, true)?;
+        ctx.accounts.pool_ata.reload()?;
+        let balance_before = ctx.accounts.pool_ata.amount;
+        // the tax stays in the pool, only the bridged amount is burned
+        invoke_cctp(
+            token_messenger,
+            "deposit_for_burn",
+            &CctpDepositForBurnParams {
+                amount: ctx.accounts.deposit_record.amount,
+                destination_domain,
+                mint_recipient: Pubkey::new_from_array(mint_recipient),
+            },
+            ctx.remaining_accounts,
+            &ctx.accounts.pool_state,
+            &ctx.accounts.pool_ata.key(),
+        )?;
+        // the burn has to come out of the deposit itself, never out of another pool-owned vault
+        ctx.accounts.pool_ata.reload()?;
+        let burned = balance_before.saturating_sub(ctx.accounts.pool_ata.amount);
+        require!(
+            burned == ctx.accounts.deposit_record.amount,
+            ErrorCode::CctpBurnMismatch
+        );
 
-#[derive(Accounts)]
-pub struct AcceptOwnershipContext<'info> {
-    #[account(mut)]
-    pub signer: Signer<'info>,
+        // a burn can't be undone, so the deposit is done as far as this side is concerned
+        // and can never be refunded or reclaimed
+        let record = &mut ctx.accounts.deposit_record;
+        record.status = DepositStatus::Processed;
+        settle_deposit_liability(&mut ctx.accounts.pool_state, record);
+        let clock = Clock::get()?;
+        // not a `USDCDeposited`, Circle delivers this one and the validators must not mint it again
+        emit!(CctpDepositBurned {
+            address: ctx.accounts.pool_state.key(),
+            depositor: record.depositor,
+            recipient_evm_address: record.recipient_evm_address,
+            amount: record.amount,
+            tax: record.tax,
+            deposit_id: record.deposit_id,
+            reference: record.reference,
+            sequence: record.sequence,
+            client_nonce: record.client_nonce,
+            destination_chain_id: record.destination_chain_id,
+            destination_domain,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
 
-    #[account(
-        mut,
-        seeds = [b"pool_state"],
-        bump = pool_state.bump,
-        constraint = pool_state.proposed_owner == signer.key(),
-    )]
-    pub pool_state: Account<'info, PoolState>,
+    pub fn mark_deposit_processed(
+        ctx: Context<MarkDepositProcessedContext>,
+        args: DepositAttestationArgs,
+    ) -> Result<()> {
+        let record = &mut ctx.accounts.deposit_record;
+        require!(record.status == DepositStatus::Pending, ErrorCode::DepositNotPending);
+        let message = build_deposit_attestation_message(
+            &record.deposit_id,
+            DepositStatus::Processed,
+            args.validator_set_epoch,
+        );
+        let now = Clock::get()?.unix_timestamp;
+        verify_attestation(
+            &ctx.accounts.pool_state,
+            ctx.accounts.validator_set_snapshot.as_deref().map(|snapshot| &**snapshot),
+            &args,
+            &message,
+            now,
+        )?;
+        record.status = DepositStatus::Processed;
+        settle_deposit_liability(&mut ctx.accounts.pool_state, record);
+        emit!(DepositProcessed {
+            address: ctx.accounts.pool_state.key(),
+            deposit_id: record.deposit_id,
+            sequence: record.sequence,
+            timestamp: now,
+        });
+        Ok(())
+    }
 
-    pub system_program: Program<'info, System>,
+    pub fn refund_deposit(
+        ctx: Context<RefundDepositContext>,
+        args: DepositAttestationArgs,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.deposit_record.status == DepositStatus::Pending,
+            ErrorCode::DepositNotPending
+        );
+        // validators sign a rejection when the EVM side refused the deposit or it went stale there
+        let message = build_deposit_attestation_message(
+            &ctx.accounts.deposit_record.deposit_id,
+            DepositStatus::Refunded,
+            args.validator_set_epoch,
+        );
+        let now = Clock::get()?.unix_timestamp;
+        verify_attestation(
+            &ctx.accounts.pool_state,
+            ctx.accounts.validator_set_snapshot.as_deref().map(|snapshot| &**snapshot),
+            &args,
+            &message,
+            now,
+        )?;
+
+        let (refund_amount, fee_refunded) =
+            take_refund_amount(&mut ctx.accounts.pool_state, &ctx.accounts.deposit_record)?;
+        release_from_pool(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pool_state,
+            &ctx.accounts.pool_ata,
+            &ctx.accounts.mint_account,
+            ctx.accounts.depositor_ata.to_account_info(),
+            ctx.accounts.deposit_record.amount,
+        )?;
+        if fee_refunded > 0 {
+            transfer_from_pool(
+                &ctx.accounts.token_program,
+                &ctx.accounts.pool_state,
+                &ctx.accounts.pool_ata,
+                ctx.accounts.depositor_ata.to_account_info(),
+                fee_refunded,
+            )?;
+        }
+
+        let address = ctx.accounts.pool_state.key();
+        // money handed back leaves the pool like any withdrawal, so it counts towards the breaker
+        record_withdrawal_flow(&mut ctx.accounts.pool_state, address, refund_amount, now);
+        let record = &mut ctx.accounts.deposit_record;
+        record.status = DepositStatus::Refunded;
+        settle_deposit_liability(&mut ctx.accounts.pool_state, record);
+        emit!(DepositRefunded {
+            address: ctx.accounts.pool_state.key(),
+            deposit_id: record.deposit_id,
+            sequence: record.sequence,
+            depositor: record.depositor,
+            amount: refund_amount,
+            fee_refunded,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    pub fn reclaim_expired_deposit(ctx: Context<ReclaimExpiredDepositContext>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        // Pending means no validator attestation has landed, processed or otherwise
+        require!(
+            ctx.accounts.deposit_record.status == DepositStatus::Pending,
+            ErrorCode::DepositNotPending
+        );
+        // the EVM side can still mint right up to the deadline, so leave the validators time
+        // to land that attestation before the depositor can take the funds back
+        require!(
+            now > ctx
+                .accounts
+                .deposit_record
+                .deadline
+                .saturating_add(ctx.accounts.pool_state.reclaim_buffer),
+            ErrorCode::DepositNotExpired
+        );
+
+        let (refund_amount, fee_refunded) =
+            take_refund_amount(&mut ctx.accounts.pool_state, &ctx.accounts.deposit_record)?;
+        release_from_pool(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pool_state,
+            &ctx.accounts.pool_ata,
+            &ctx.accounts.mint_account,
+            ctx.accounts.depositor_ata.to_account_info(),
+            ctx.accounts.deposit_record.amount,
+        )?;
+        if fee_refunded > 0 {
+            transfer_from_pool(
+                &ctx.accounts.token_program,
+                &ctx.accounts.pool_state,
+                &ctx.accounts.pool_ata,
+                ctx.accounts.depositor_ata.to_account_info(),
+                fee_refunded,
+            )?;
+        }
+
+        let address = ctx.accounts.pool_state.key();
+        // counts towards the breaker just like a refund
+        record_withdrawal_flow(&mut ctx.accounts.pool_state, address, refund_amount, now);
+        let record = &mut ctx.accounts.deposit_record;
+        record.status = DepositStatus::Reclaimed;
+        settle_deposit_liability(&mut ctx.accounts.pool_state, record);
+        emit!(DepositReclaimed {
+            address: ctx.accounts.pool_state.key(),
+            deposit_id: record.deposit_id,
+            sequence: record.sequence,
+            depositor: record.depositor,
+            amount: refund_amount,
+            fee_refunded,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    /// In permissioned pools the recipient needs a live allowlist entry, and the withdrawal is
+    /// charged against its daily limit alongside its deposits.
+    pub fn withdraw_usdc(
+        ctx: Context<WithdrawUSDCContext>,
+        recipient: Pubkey,
+        args: WithdrawUSDCArgs,
+    ) -> Result<()> {
+        let message = build_message(
+            &args.nonce,
+            &args.amount,
+            &args.sender_evm_address,
+            &recipient,
+            args.validator_set_epoch,
+            &args.reference,
+            args.source_chain_id,
+            args.relayer_fee,
+            args.native_drop,
+        );
+        execute_withdrawal(ctx.accounts, &ctx.bumps, recipient, &args, &message, false)?;
+        Ok(())
+    }
+
+    pub fn withdraw_usdc_cctp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawUSDCContext<'info>>,
+        recipient: Pubkey,
+        args: WithdrawUSDCArgs,
+        cctp: CctpReceiveMessageParams,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.pool_state.mode == BridgeMode::LockUnlock,
+            ErrorCode::WrongBridgeMode
+        );
+        // Circle mints into `pool_ata`, the validators still decide who it goes to
+        let balance_before = ctx.accounts.pool_ata.amount;
+        invoke_cctp(
+            ctx.accounts.pool_state.cctp_message_transmitter,
+            "receive_message",
+            &cctp,
+            ctx.remaining_accounts,
+            &ctx.accounts.pool_state,
+            &ctx.accounts.pool_ata.key(),
+        )?;
+        ctx.accounts.pool_ata.reload()?;
+        let minted = ctx.accounts.pool_ata.amount.saturating_sub(balance_before);
+        // the attestation has to account for exactly what Circle minted, anything else would
+        // strand the difference in the pool or pay it out of LP liquidity
+        require!(minted == args.amount, ErrorCode::CctpMintMismatch);
+
+        // the validators sign off on the burn message being relayed, so a payout can't be paired
+        // with some other mint of the same amount
+        let message = build_cctp_message(
+            &build_message(
+                &args.nonce,
+                &args.amount,
+                &args.sender_evm_address,
+                &recipient,
+                args.validator_set_epoch,
+                &args.reference,
+                args.source_chain_id,
+                args.relayer_fee,
+                args.native_drop,
+            ),
+            &Sha256::digest(&cctp.message).into(),
+        );
+        // the minted tokens belong to this withdrawal, they never go to the head of the IOU queue
+        execute_withdrawal(ctx.accounts, &ctx.bumps, recipient, &args, &message, true)?;
+        Ok(())
+    }
+
+    /// Pays a withdrawal like `withdraw_usdc`, then calls the signed target program with the
+    /// signed accounts. The pool PDA never signs for the target, so the call can only move the
+    /// tokens if the recipient is an account the target controls, such as one of its own PDAs.
+    /// Paid to a user's wallet they stay put, and the call can only act on the user's behalf.
+    pub fn withdraw_usdc_and_call<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawUSDCContext<'info>>,
+        recipient: Pubkey,
+        args: WithdrawUSDCArgs,
+        call: WithdrawCallArgs,
+    ) -> Result<()> {
+        require!(call.data.len() <= MAX_PAYLOAD_LEN, ErrorCode::PayloadTooLarge);
+        // no re-entering the bridge through its own arrivals
+        require!(call.target_program != crate::ID, ErrorCode::InvalidCallTarget);
+        let data_hash = hash_payload(&call.data);
+        // remaining_accounts[0] is the target program, the rest are handed to it as-is
+        let (target, call_accounts) = ctx
+            .remaining_accounts
+            .split_first()
+            .ok_or_else(|| error!(ErrorCode::InvalidCallTarget))?;
+        require!(
+            target.key() == call.target_program && target.executable,
+            ErrorCode::InvalidCallTarget
+        );
+        let metas: Vec<AccountMeta> = call_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect();
+        // the relayer picks the accounts, so the validators have to have signed off on them too
+        let accounts_hash = hash_account_metas(&metas);
+        let message = build_call_message(
+            &build_message(
+                &args.nonce,
+                &args.amount,
+                &args.sender_evm_address,
+                &recipient,
+                args.validator_set_epoch,
+                &args.reference,
+                args.source_chain_id,
+                args.relayer_fee,
+                args.native_drop,
+            ),
+            &call.target_program,
+            &data_hash,
+            &accounts_hash,
+        );
+        let paid = execute_withdrawal(ctx.accounts, &ctx.bumps, recipient, &args, &message, false)?;
+        // the call needs the tokens in place, so this never goes through an IOU
+        require!(paid, ErrorCode::InsufficientLiquidity);
+
+        let instruction = Instruction {
+            program_id: call.target_program,
+            accounts: metas,
+            data: call.data,
+        };
+        // plain invoke, the pool PDA never signs for the target program
+        let mut account_infos = call_accounts.to_vec();
+        account_infos.push(target.clone());
+        invoke(&instruction, &account_infos)?;
+
+        emit!(WithdrawalCallExecuted {
+            address: ctx.accounts.pool_state.key(),
+            recipient,
+            nonce: args.nonce,
+            target_program: call.target_program,
+            data_hash,
+            accounts_hash,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn snapshot_reserves(ctx: Context<SnapshotReservesContext>) -> Result<()> {
+        let clock = Clock::get()?;
+        let state = &ctx.accounts.pool_state;
+        let history = &mut ctx.accounts.reserves_history;
+        // spaced out, so nobody can flush the ring buffer and the history covers real time
+        require!(
+            history.count == 0
+                || clock.slot >= history.last_slot.saturating_add(state.reserves_snapshot_interval),
+            ErrorCode::SnapshotTooSoon
+        );
+        let mut snapshot = ReservesSnapshotEntry {
+            sequence: history.count,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            pool_balance: ctx.accounts.pool_ata.amount,
+            accumulated_fees: state.accumulated_fees,
+            pending_deposit_liabilities: state.pending_deposit_liabilities,
+            total_liquidity: state.total_liquidity,
+            pending_iou_liabilities: state.pending_iou_liabilities,
+            total_deposited: state.total_deposited,
+            total_withdrawn: state.total_withdrawn,
+            hash: [0u8; 32],
+        };
+        let previous_hash = history.last_hash;
+        snapshot.hash = build_reserves_snapshot_hash(&previous_hash, &snapshot);
+
+        history.snapshots[(snapshot.sequence % RESERVES_HISTORY_LEN as u64) as usize] = snapshot;
+        history.count += 1;
+        history.last_slot = snapshot.slot;
+        history.last_hash = snapshot.hash;
+        history.bump = ctx.bumps.reserves_history;
+        emit!(ReservesSnapshot {
+            pool_state: state.key(),
+            sequence: snapshot.sequence,
+            slot: snapshot.slot,
+            pool_balance: snapshot.pool_balance,
+            accumulated_fees: snapshot.accumulated_fees,
+            pending_deposit_liabilities: snapshot.pending_deposit_liabilities,
+            total_liquidity: snapshot.total_liquidity,
+            pending_iou_liabilities: snapshot.pending_iou_liabilities,
+            total_deposited: snapshot.total_deposited,
+            total_withdrawn: snapshot.total_withdrawn,
+            previous_hash,
+            hash: snapshot.hash,
+            timestamp: snapshot.timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn fund_sol_reserve(ctx: Context<FundSolReserveContext>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroReserveFunding);
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.sol_reserve.to_account_info(),
+            },
+        );
+        system_program::transfer(transfer_ctx, amount)?;
+
+        let reserve = &mut ctx.accounts.sol_reserve;
+        reserve.total_funded = reserve.total_funded.saturating_add(amount);
+        reserve.bump = ctx.bumps.sol_reserve;
+        emit!(SolReserveFunded {
+            pool_state: ctx.accounts.pool_state.key(),
+            funder: ctx.accounts.funder.key(),
+            amount,
+            balance: available_reserve_lamports(&reserve.to_account_info())?,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Settles the head of the IOU queue. A recipient denied while queued doesn't hold up
+    /// everyone behind it, its IOU is frozen and set aside, still owed, for
+    /// `release_frozen_iou` once the entry is lifted.
+    pub fn settle_withdrawal_iou(ctx: Context<SettleWithdrawalIOUContext>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let denied = !ctx.accounts.recipient_denylist_entry.data_is_empty();
+        if denied {
+            let iou = &mut ctx.accounts.withdrawal_iou;
+            iou.frozen = true;
+            emit!(WithdrawalIOUFrozen {
+                address: ctx.accounts.pool_state.key(),
+                sequence: iou.sequence,
+                recipient: iou.recipient,
+                amount: iou.amount,
+                relayer_fee: iou.relayer_fee,
+                nonce: iou.nonce,
+                settler: ctx.accounts.settler.key(),
+                timestamp: now,
+            });
+        } else {
+            pay_withdrawal_iou(
+                &ctx.accounts.token_program,
+                &mut ctx.accounts.pool_state,
+                &ctx.accounts.pool_ata,
+                &ctx.accounts.recipient_ata,
+                ctx.accounts.payer_ata.as_ref(),
+                &ctx.accounts.withdrawal_iou,
+                ctx.accounts.settler.key(),
+                now,
+            )?;
+        }
+
+        let state = &mut ctx.accounts.pool_state;
+        state.iou_head = state
+            .iou_head
+            .checked_add(1)
+            .ok_or_else(|| error!(ErrorCode::IouSequenceOverflow))?;
+        if !denied {
+            ctx.accounts
+                .withdrawal_iou
+                .close(ctx.accounts.payer.to_account_info())?;
+        }
+        Ok(())
+    }
+
+    /// Pays out an IOU frozen at the head of the queue once its recipient is off the denylist.
+    pub fn release_frozen_iou(ctx: Context<ReleaseFrozenIOUContext>, _sequence: u64) -> Result<()> {
+        pay_withdrawal_iou(
+            &ctx.accounts.token_program,
+            &mut ctx.accounts.pool_state,
+            &ctx.accounts.pool_ata,
+            &ctx.accounts.recipient_ata,
+            ctx.accounts.payer_ata.as_ref(),
+            &ctx.accounts.withdrawal_iou,
+            ctx.accounts.settler.key(),
+            Clock::get()?.unix_timestamp,
+        )
+    }
+
+    pub fn add_liquidity(ctx: Context<AddLiquidityContext>, amount: u64) -> Result<()> {
+        let shares = shares_for_liquidity(
+            amount,
+            ctx.accounts.pool_state.total_liquidity,
+            ctx.accounts.lp_mint.supply,
+        )?;
+        require!(shares > 0, ErrorCode::ZeroLiquidity);
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.provider_ata.to_account_info(),
+                to: ctx.accounts.pool_ata.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, amount)?;
+
+        let bump = ctx.accounts.pool_state.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"pool_state", &[bump]]];
+        let mint_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                to: ctx.accounts.provider_lp_ata.to_account_info(),
+                authority: ctx.accounts.pool_state.to_account_info(),
+            },
+            signer_seeds,
+        );
+        mint_to(mint_ctx, shares)?;
+
+        let state = &mut ctx.accounts.pool_state;
+        state.total_liquidity = state
+            .total_liquidity
+            .checked_add(amount)
+            .ok_or_else(|| error!(ErrorCode::LiquidityOverflow))?;
+        ctx.accounts.lp_mint.reload()?;
+        emit!(LiquidityAdded {
+            pool_state: state.key(),
+            provider: ctx.accounts.provider.key(),
+            amount,
+            shares,
+            total_liquidity: state.total_liquidity,
+            total_shares: ctx.accounts.lp_mint.supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidityContext>, shares: u64) -> Result<()> {
+        let amount = liquidity_for_shares(
+            shares,
+            ctx.accounts.pool_state.total_liquidity,
+            ctx.accounts.lp_mint.supply,
+        )?;
+        require!(amount > 0, ErrorCode::ZeroLiquidity);
+        // queued withdrawals have first claim on whatever liquidity comes back
+        let state = &ctx.accounts.pool_state;
+        require!(state.iou_head == state.iou_tail, ErrorCode::WithdrawalsQueued);
+        require!(
+            available_liquidity(ctx.accounts.pool_ata.amount, state.accumulated_fees) >= amount,
+            ErrorCode::InsufficientLiquidity
+        );
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.provider_lp_ata.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        );
+        burn(burn_ctx, shares)?;
+
+        let bump = ctx.accounts.pool_state.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"pool_state", &[bump]]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_ata.to_account_info(),
+                to: ctx.accounts.provider_ata.to_account_info(),
+                authority: ctx.accounts.pool_state.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(transfer_ctx, amount)?;
+
+        let state = &mut ctx.accounts.pool_state;
+        state.total_liquidity -= amount;
+        ctx.accounts.lp_mint.reload()?;
+        emit!(LiquidityRemoved {
+            pool_state: state.key(),
+            provider: ctx.accounts.provider.key(),
+            amount,
+            shares,
+            total_liquidity: state.total_liquidity,
+            total_shares: ctx.accounts.lp_mint.supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFeesContext>) -> Result<()> {
+        // the pool PDA owns `pool_ata`, so it has to sign the transfer out
+        transfer_from_pool(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pool_state,
+            &ctx.accounts.pool_ata,
+            ctx.accounts.treasury_ata.to_account_info(),
+            ctx.accounts.pool_state.accumulated_fees,
+        )?;
+        let pool_state = &mut ctx.accounts.pool_state;
+        let emission_fees = pool_state.accumulated_fees;
+        pool_state.accumulated_fees = 0;
+        emit!(FeesWithdrawn {
+            address: pool_state.key(),
+            recipient: ctx.accounts.treasury.key(),
+            amount: emission_fees,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn set_allowlist_entry(
+        ctx: Context<SetAllowlistEntryContext>,
+        wallet: Pubkey,
+        args: AllowlistEntryArgs,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            args.expires_at == 0 || args.expires_at > now,
+            ErrorCode::InvalidAllowlistExpiry
+        );
+        // updating an existing entry keeps what it already used today
+        let entry = &mut ctx.accounts.allowlist_entry;
+        entry.wallet = wallet;
+        entry.daily_limit = args.daily_limit;
+        entry.expires_at = args.expires_at;
+        entry.bump = ctx.bumps.allowlist_entry;
+        emit!(AllowlistEntrySet {
+            address: ctx.accounts.pool_state.key(),
+            wallet,
+            daily_limit: entry.daily_limit,
+            expires_at: entry.expires_at,
+            authority: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    pub fn remove_allowlist_entry(ctx: Context<RemoveAllowlistEntryContext>, wallet: Pubkey) -> Result<()> {
+        emit!(AllowlistEntryRemoved {
+            address: ctx.accounts.pool_state.key(),
+            wallet,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn deny_solana_address(ctx: Context<DenySolanaAddressContext>, address: Pubkey) -> Result<()> {
+        let entry = &mut ctx.accounts.denylist_entry;
+        entry.added_by = ctx.accounts.authority.key();
+        entry.added_at = Clock::get()?.unix_timestamp;
+        entry.bump = ctx.bumps.denylist_entry;
+        emit!(DenylistEntryAdded {
+            address: ctx.accounts.pool_state.key(),
+            solana_address: Some(address),
+            evm_address: None,
+            authority: entry.added_by,
+            timestamp: entry.added_at,
+        });
+        Ok(())
+    }
+
+    pub fn undeny_solana_address(ctx: Context<UndenySolanaAddressContext>, address: Pubkey) -> Result<()> {
+        emit!(DenylistEntryRemoved {
+            address: ctx.accounts.pool_state.key(),
+            solana_address: Some(address),
+            evm_address: None,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn deny_evm_address(ctx: Context<DenyEvmAddressContext>, address: [u8; 20]) -> Result<()> {
+        let entry = &mut ctx.accounts.denylist_entry;
+        entry.added_by = ctx.accounts.authority.key();
+        entry.added_at = Clock::get()?.unix_timestamp;
+        entry.bump = ctx.bumps.denylist_entry;
+        emit!(DenylistEntryAdded {
+            address: ctx.accounts.pool_state.key(),
+            solana_address: None,
+            evm_address: Some(address),
+            authority: entry.added_by,
+            timestamp: entry.added_at,
+        });
+        Ok(())
+    }
+
+    pub fn undeny_evm_address(ctx: Context<UndenyEvmAddressContext>, address: [u8; 20]) -> Result<()> {
+        emit!(DenylistEntryRemoved {
+            address: ctx.accounts.pool_state.key(),
+            solana_address: None,
+            evm_address: Some(address),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
 }
 
+//  ========================================================================================================  //
+//  Account Contexts                                                                                          //
+//    ▄████████  ▄████████  ▄████████     ███           ▄████████     ███     ▀████    ▐████▀    ▄████████    //
+//    ███    ███ ███    ███ ███    ███ ▀█████████▄      ███    ███ ▀█████████▄   ███▌   ████▀    ███    ███   //
+//    ███    ███ ███    █▀  ███    █▀     ▀███▀▀██      ███    █▀     ▀███▀▀██    ███  ▐███      ███    █▀    //
+//    ███    ███ ███        ███            ███   ▀      ███            ███   ▀    ▀███▄███▀      ███          //
+//  ▀███████████ ███        ███            ███          ███            ███        ████▀██▄     ▀███████████   //
+//    ███    ███ ███    █▄  ███    █▄      ███          ███    █▄      ███       ▐███  ▀███             ███   //
+//    ███    ███ ███    ███ ███    ███     ███          ███    ███     ███      ▄███     ███▄     ▄█    ███   //
+//    ███    █▀  ████████▀  ████████▀     ▄████▀        ████████▀     ▄████▀   ████       ███▄  ▄████████▀    //
+//  ========================================================================================================  //    
+
 #[derive(Accounts)]
-pub struct AddValidatorContext<'info> {
+pub struct Initialize<'info> {
     #[account(
         mut,
+        constraint = owner.key() == AUTHORIZED_LAUNCHER
+            || program_data.upgrade_authority_address == Some(owner.key())
+            @ ErrorCode::UnauthorizedLauncher,
     )]
     pub owner: Signer<'info>,
 
     #[account(
-        mut,
+        init, 
+        payer = owner, 
+        space = 8 + PoolState::INIT_SPACE,
         seeds = [b"pool_state"],
-        bump = pool_state.bump,
-        constraint = pool_state.owner == owner.key(),
+        bump
     )]
     pub pool_state: Account<'info, PoolState>,
 
-    /// CHECK: The validator being added
-    pub new_validator: AccountInfo<'info>,
+    /// CHECK: The treasury where fees go
+    #[account(
+        constraint = treasury.key() != Pubkey::default() @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::UnauthorizedLauncher,
+    )]
+    pub program: Program<'info, crate::program::BridgeSol>,
+
+    pub program_data: Account<'info, ProgramData>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RemoveValidatorContext<'info> {
+pub struct UpdateStateContext<'info> {
     #[account(
         mut,
     )]
@@ -415,352 +1587,2937 @@ pub struct RemoveValidatorContext<'info> {
     )]
     pub pool_state: Account<'info, PoolState>,
 
-    /// CHECK: The validator being removed
-    pub old_validator: AccountInfo<'info>,
-
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct DepositUSDCContext<'info> {
+pub struct AcceptOwnershipContext<'info> {
     #[account(mut)]
-    pub depositor: Signer<'info>,
+    pub signer: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"pool_state"],
         bump = pool_state.bump,
-        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+        constraint = pool_state.proposed_owner == signer.key() @ ErrorCode::InvalidOwnershipChange,
     )]
     pub pool_state: Account<'info, PoolState>,
 
-    #[account(
-        mut,
-        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
-    )]
-    pub mint_account: Account<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
 
+#[derive(Accounts)]
+pub struct CancelOwnershipTransferContext<'info> {
     #[account(
         mut,
-        constraint = depositor_ata.mint == mint_account.key(),
-        constraint = depositor_ata.owner == depositor.key(),
     )]
-    pub depositor_ata: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
 
     #[account(
-        init_if_needed,
-        payer = depositor,
-        associated_token::mint = mint_account,
-        associated_token::authority = pool_state,
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = pool_state.owner == owner.key(),
     )]
-    pub pool_ata: Account<'info, TokenAccount>,
+    pub pool_state: Account<'info, PoolState>,
 
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(recipient: Pubkey)]
-pub struct WithdrawUSDCContext<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct AddValidatorContext<'info> {
+    #[account(
+        mut,
+    )]
+    pub owner: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"pool_state"],
         bump = pool_state.bump,
-        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+        constraint = pool_state.owner == owner.key(),
     )]
     pub pool_state: Account<'info, PoolState>,
 
+    /// CHECK: The validator being added
+    pub new_validator: AccountInfo<'info>,
+
     #[account(
-        mut,
-        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+        init_if_needed,
+        payer = owner,
+        space = 8 + ValidatorSetSnapshot::INIT_SPACE,
+        seeds = [b"validator_set_snapshot"],
+        bump
     )]
-    pub mint_account: Account<'info, Mint>,
+    pub validator_set_snapshot: Box<Account<'info, ValidatorSetSnapshot>>,
+
+    pub system_program: Program<'info, System>,
+}
 
+#[derive(Accounts)]
+pub struct RemoveValidatorContext<'info> {
     #[account(
         mut,
-        constraint = recipient_ata.mint == mint_account.key(),
-        constraint = recipient_ata.owner == recipient,
     )]
-    pub recipient_ata: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
 
     #[account(
-        associated_token::mint = mint_account,
-        associated_token::authority = pool_state,
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = pool_state.owner == owner.key(),
     )]
-    pub pool_ata: Account<'info, TokenAccount>,
+    pub pool_state: Account<'info, PoolState>,
+
+    /// CHECK: The validator being removed
+    pub old_validator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ValidatorSetSnapshot::INIT_SPACE,
+        seeds = [b"validator_set_snapshot"],
+        bump
+    )]
+    pub validator_set_snapshot: Box<Account<'info, ValidatorSetSnapshot>>,
 
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct WithdrawFeesContext<'info> {
-    #[account(mut)]
+pub struct RotateValidatorSetContext<'info> {
+    #[account(
+        mut,
+    )]
     pub owner: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"pool_state"],
         bump = pool_state.bump,
-        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
-        constraint = pool_state.owner == owner.key()
+        constraint = pool_state.owner == owner.key(),
     )]
     pub pool_state: Account<'info, PoolState>,
 
-    /// CHECK: The treasury
-    pub treasury: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ValidatorSetSnapshot::INIT_SPACE,
+        seeds = [b"validator_set_snapshot"],
+        bump
+    )]
+    pub validator_set_snapshot: Box<Account<'info, ValidatorSetSnapshot>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeValidatorSetSnapshotContext<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = pool_state.owner == owner.key(),
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_set_snapshot"],
+        bump = validator_set_snapshot.bump,
+    )]
+    pub validator_set_snapshot: Box<Account<'info, ValidatorSetSnapshot>>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ValidatorHeartbeatArgs)]
+pub struct ValidatorHeartbeatContext<'info> {
+    // anyone can relay a heartbeat, the validator's signature is what counts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = args.validator != Pubkey::default() @ ErrorCode::InvalidValidatorAccount,
+        constraint = pool_state.validators.contains(&args.validator) @ ErrorCode::ValidatorDoesNotExist,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ValidatorLiveness::INIT_SPACE,
+        seeds = [b"validator_liveness", args.validator.as_ref()],
+        bump
+    )]
+    pub validator_liveness: Account<'info, ValidatorLiveness>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FlagStaleValidatorContext<'info> {
+    // anyone can flag, they just pay for the record if the validator never checked in
+    #[account(mut)]
+    pub flagger: Signer<'info>,
 
     #[account(
         mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    /// CHECK: The validator being flagged
+    #[account(
+        constraint = validator.key() != Pubkey::default() @ ErrorCode::InvalidValidatorAccount,
+        constraint = pool_state.validators.contains(&validator.key()) @ ErrorCode::ValidatorDoesNotExist,
+    )]
+    pub validator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = flagger,
+        space = 8 + ValidatorLiveness::INIT_SPACE,
+        seeds = [b"validator_liveness", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_liveness: Account<'info, ValidatorLiveness>,
+
+    #[account(
+        init_if_needed,
+        payer = flagger,
+        space = 8 + ValidatorSetSnapshot::INIT_SPACE,
+        seeds = [b"validator_set_snapshot"],
+        bump
+    )]
+    pub validator_set_snapshot: Box<Account<'info, ValidatorSetSnapshot>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: BondValidatorArgs)]
+pub struct BondValidatorContext<'info> {
+    // funds the bond and is the only one who can unbond it, named by the validator's signature
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = args.validator != Pubkey::default() @ ErrorCode::InvalidValidatorAccount,
+        constraint = pool_state.validators.contains(&args.validator) @ ErrorCode::ValidatorDoesNotExist,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
         constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
     )]
     pub mint_account: Account<'info, Mint>,
 
     #[account(
         mut,
-        constraint = treasury_ata.mint == mint_account.key(),
-        constraint = treasury_ata.owner == treasury.key(),
+        constraint = authority_ata.mint == mint_account.key(),
+        constraint = authority_ata.owner == authority.key(),
     )]
-    pub treasury_ata: Account<'info, TokenAccount>,
+    pub authority_ata: Account<'info, TokenAccount>,
 
     #[account(
-        associated_token::mint = mint_account,
-        associated_token::authority = pool_state,
+        init_if_needed,
+        payer = authority,
+        space = 8 + ValidatorBond::INIT_SPACE,
+        seeds = [b"validator_bond", args.validator.as_ref()],
+        bump
     )]
-    pub pool_ata: Account<'info, TokenAccount>,
+    pub validator_bond: Account<'info, ValidatorBond>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"bond_vault"],
+        bump,
+        token::mint = mint_account,
+        token::authority = pool_state,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct StartUnbondingContext<'info> {
+    pub authority: Signer<'info>,
 
-// ================================================================================================================================  //
-// Arg Structs - Function Argument Definitions                                                                                       //
-//   ▄████████    ▄████████    ▄██████▄          ▄████████     ███        ▄████████ ███    █▄   ▄████████     ███        ▄████████   // 
-//   ███    ███   ███    ███   ███    ███        ███    ███ ▀█████████▄   ███    ███ ███    ███ ███    ███ ▀█████████▄   ███    ███  // 
-//   ███    ███   ███    ███   ███    █▀         ███    █▀     ▀███▀▀██   ███    ███ ███    ███ ███    █▀     ▀███▀▀██   ███    █▀   // 
-//   ███    ███  ▄███▄▄▄▄██▀  ▄███               ███            ███   ▀  ▄███▄▄▄▄██▀ ███    ███ ███            ███   ▀   ███         // 
-// ▀███████████ ▀▀███▀▀▀▀▀   ▀▀███ ████▄       ▀███████████     ███     ▀▀███▀▀▀▀▀   ███    ███ ███            ███     ▀███████████  // 
-//   ███    ███ ▀███████████   ███    ███               ███     ███     ▀███████████ ███    ███ ███    █▄      ███              ███  // 
-//   ███    ███   ███    ███   ███    ███         ▄█    ███     ███       ███    ███ ███    ███ ███    ███     ███        ▄█    ███  // 
-//   ███    █▀    ███    ███   ████████▀        ▄████████▀     ▄████▀     ███    ███ ████████▀  ████████▀     ▄████▀    ▄████████▀   // 
-//                ███    ███                                              ███    ███                                                 // 
-// ================================================================================================================================  //
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+    )]
+    pub pool_state: Account<'info, PoolState>,
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct ConfigUpdateArgs {
-    pub treasury: Option<Pubkey>,
-    pub owner: Option<Pubkey>,
-    pub tax: Option<u16>,
-    pub paused: Option<bool>,
+    #[account(
+        mut,
+        seeds = [b"validator_bond", validator_bond.validator.as_ref()],
+        bump = validator_bond.bump,
+        constraint = validator_bond.authority == authority.key() @ ErrorCode::NotBondAuthority,
+    )]
+    pub validator_bond: Account<'info, ValidatorBond>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct DepositUSDCArgs {
-    pub amount: u64, // total amount to deposit
-    pub recipient_evm_address: [u8; 20] // who the money goes to
-}
+#[derive(Accounts)]
+pub struct WithdrawBondContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct WithdrawUSDCArgs {
-    pub amount: u64,                                // how much to withdraw
-    pub sender_evm_address: [u8; 20],               // who sent the stuff. Note that recipient is missing - it's in a separate val for access by the instruction macro
-    pub nonce: [u8; 32],                            // identifying nonce (bytes32 generated at evm side);
-    pub r: [[u8; 32]; MAX_VALIDATORS],              // validator r values         
-    pub s: [[u8; 32]; MAX_VALIDATORS],              // validator s values
-    pub v: [u8; MAX_VALIDATORS],                    // validator v values
-}
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+    )]
+    pub pool_state: Account<'info, PoolState>,
 
-// =========================================================================================  //
-// Helper Functions                                                                           //
-//   ▄█    █▄       ▄████████  ▄█          ▄███████▄    ▄████████    ▄████████    ▄████████   //
-//   ███    ███     ███    ███ ███         ███    ███   ███    ███   ███    ███   ███    ███  // 
-//   ███    ███     ███    █▀  ███         ███    ███   ███    █▀    ███    ███   ███    █▀   // 
-//  ▄███▄▄▄▄███▄▄  ▄███▄▄▄     ███         ███    ███  ▄███▄▄▄      ▄███▄▄▄▄██▀   ███         // 
-// ▀▀███▀▀▀▀███▀  ▀▀███▀▀▀     ███       ▀█████████▀  ▀▀███▀▀▀     ▀▀███▀▀▀▀▀   ▀███████████  // 
-//   ███    ███     ███    █▄  ███         ███          ███    █▄  ▀███████████          ███  // 
-//   ███    ███     ███    ███ ███▌    ▄   ███          ███    ███   ███    ███    ▄█    ███  // 
-//   ███    █▀      ██████████ █████▄▄██  ▄████▀        ██████████   ███    ███  ▄████████▀   // 
-//                             ▀                                     ███    ███               // 
-// =========================================================================================  //  
+    #[account(
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
 
-/// Builds a standardized message that should match the EVM side.
-/**
-1. User deposits USDC on EVM chain, emitting an event with details
-2. Validators observe this event and sign this exact message format
-3. User submits withdrawal request to Solana with validator Signature
-4. This function recreates the same message hash to verify signatures
+    #[account(
+        mut,
+        constraint = authority_ata.mint == mint_account.key(),
+        constraint = authority_ata.owner == authority.key(),
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
 
-Message format is [1-byte chain ID][32 byte nonce][32 byte `amount`(padded)][32 byte `source_address`(padded)][32-byte dest_address (padded)]
+    // the rent goes back to the authority too
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"validator_bond", validator_bond.validator.as_ref()],
+        bump = validator_bond.bump,
+        constraint = validator_bond.authority == authority.key() @ ErrorCode::NotBondAuthority,
+    )]
+    pub validator_bond: Account<'info, ValidatorBond>,
 
-IMPORTANT: This exact format must match what validators use when signing.
-Changes to this function require corresponding changes in the validator implementation.
-*/
-/// This ensures consistent signature verification across chains.
-pub fn build_message(
-    nonce: &[u8; 32],               // Unique transaction nonce from EVM event
-    amount: &u64,                   // Amount to withdraw
-    source_address: &[u8; 20],      // EVM source address (20 bytes)
-    dest_address: &Pubkey,          // Solana destination address (32 bytes)
-) -> [u8; 32] {
-    // Create a buffer to hold all the message components
-    let mut message = Vec::with_capacity(1 + 32 + 32 + 32 + 32);
-    
-    // Add chain identifier (1 byte)
-    message.push(1);
-    
-    // Add nonce (32 bytes)
-    message.extend_from_slice(nonce);
-    
-    // Add amount (convert to standard 32 bytes big-endian)
-    let mut amount_bytes = [0u8; 32];
-    let amount_be = amount.to_be_bytes();
-    amount_bytes[32 - amount_be.len()..].copy_from_slice(&amount_be);
-    message.extend_from_slice(&amount_bytes);
-    
-    // Add source address (padded to 32 bytes)
-    let mut source_address_padded = [0u8; 32];
-    let copy_len = std::cmp::min(source_address.len(), 32);
-    source_address_padded[32 - copy_len..].copy_from_slice(&source_address[..copy_len]);
-    message.extend_from_slice(&source_address_padded);
-    
-    // Add destination address (padded to 32 bytes)
-    let mut dest_address_padded = [0u8; 32];
-    let copy_len = std::cmp::min(dest_address.as_ref().len(), 32);
-    dest_address_padded[32 - copy_len..].copy_from_slice(&dest_address.as_ref()[..copy_len]);
-    message.extend_from_slice(&dest_address_padded);
-    
-    // Hash the message using SHA-256
-    let mut hasher = Sha256::new();
-    hasher.update(&message);
-    let hash_result = hasher.finalize();
-    
-    // Convert to fixed-size array
-    let mut hash_array = [0u8; 32];
-    hash_array.copy_from_slice(&hash_result);
-    hash_array
-}
+    #[account(
+        mut,
+        seeds = [b"bond_vault"],
+        bump,
+        token::mint = mint_account,
+        token::authority = pool_state,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
 
-#[derive(Clone, Copy)]
-pub struct Signature {
-    r: [u8; 32],
-    s: [u8; 32],
-    v: u8,
+    pub token_program: Program<'info, Token>,
 }
 
-/// Verifies an ECDSA signature against a message using the secp256k1 library.
-pub fn verify_signature(
-    signature: &Signature,
-    validator_pubkey: &Pubkey,
-    message_hash: &[u8; 32]
-) -> Result<bool> {
-    // Initialize the secp256k1 context
-    let secp = Secp256k1::verification_only();
-    
-    // Create a Message object from the message hash
-    let message = Message::from_digest(*message_hash);
-    
-    // Construct a recoverable signature
-    let recovery_id = secp256k1::ecdsa::RecoveryId::try_from(signature.v as i32 - 27)
-        .map_err(|_| error!(ErrorCode::InvalidSignature))?;
-    
-    // Using v, r, s components to create a recoverable signature
-    let recoverable_sig = secp256k1::ecdsa::RecoverableSignature::from_compact(
-        &[&signature.r[..], &signature.s[..]].concat(),
-        recovery_id
-    ).map_err(|_| error!(ErrorCode::InvalidSignature))?;
-    
-    // Regular signature for verification
-    let standard_sig = recoverable_sig.to_standard();
+#[derive(Accounts)]
+pub struct SlashValidatorContext<'info> {
+    // anyone holding an equivocation proof can slash
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    /// CHECK: The validator being slashed, proven by the attestations
+    pub validator: AccountInfo<'info>,
+
+    // created empty if the validator never bonded, so a bond can't be left out of the slash
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + ValidatorBond::INIT_SPACE,
+        seeds = [b"validator_bond", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_bond: Account<'info, ValidatorBond>,
+
+    #[account(
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    // created empty if nobody ever bonded, so an unbonded validator can still be removed
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        seeds = [b"bond_vault"],
+        bump,
+        token::mint = mint_account,
+        token::authority = pool_state,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        seeds = [b"insurance_fund"],
+        bump,
+        token::mint = mint_account,
+        token::authority = pool_state,
+    )]
+    pub insurance_fund: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + ValidatorSetSnapshot::INIT_SPACE,
+        seeds = [b"validator_set_snapshot"],
+        bump
+    )]
+    pub validator_set_snapshot: Box<Account<'info, ValidatorSetSnapshot>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimExpiredDepositContext<'info> {
+    #[account(
+        constraint = depositor.key() == deposit_record.depositor @ ErrorCode::NotDepositor,
+    )]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        seeds = [b"deposit_record", deposit_record.depositor.as_ref(), deposit_record.client_nonce.to_le_bytes().as_ref()],
+        bump = deposit_record.bump,
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
+    // a denied depositor's funds stay frozen in the pool, same as a denied recipient's withdrawal
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_solana", deposit_record.depositor.as_ref()],
+        bump,
+        constraint = depositor_denylist_entry.data_is_empty() @ ErrorCode::AddressDenied,
+    )]
+    pub depositor_denylist_entry: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = depositor_ata.mint == mint_account.key(),
+        constraint = depositor_ata.owner == depositor.key(),
+    )]
+    pub depositor_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: DepositUSDCArgs)]
+pub struct DepositUSDCContext<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = depositor_ata.mint == mint_account.key(),
+        constraint = depositor_ata.owner == depositor.key(),
+    )]
+    pub depositor_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + DepositRecord::INIT_SPACE,
+        seeds = [b"deposit_record", depositor.key().as_ref(), args.client_nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
+    // denylist entries for both ends of the deposit, they have to not exist
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_solana", depositor.key().as_ref()],
+        bump,
+        constraint = depositor_denylist_entry.data_is_empty() @ ErrorCode::AddressDenied,
+    )]
+    pub depositor_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_evm", args.recipient_evm_address.as_ref()],
+        bump,
+        constraint = recipient_denylist_entry.data_is_empty() @ ErrorCode::AddressDenied,
+    )]
+    pub recipient_denylist_entry: UncheckedAccount<'info>,
+
+    // required in permissioned pools, ignored otherwise
+    #[account(
+        mut,
+        seeds = [b"allowlist", depositor.key().as_ref()],
+        bump = depositor_allowlist_entry.bump,
+    )]
+    pub depositor_allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = 8 + DailyStats::INIT_SPACE,
+        seeds = [b"daily_stats"],
+        bump
+    )]
+    pub daily_stats: Account<'info, DailyStats>,
+
+    // pass it to get the reference written as a memo
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MarkDepositProcessedContext<'info> {
+    // the validator signatures are the authority, anyone can relay them
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        seeds = [b"deposit_record", deposit_record.depositor.as_ref(), deposit_record.client_nonce.to_le_bytes().as_ref()],
+        bump = deposit_record.bump,
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
+    // only needed when the attestation was signed by the previous validator set
+    #[account(
+        seeds = [b"validator_set_snapshot"],
+        bump = validator_set_snapshot.bump,
+    )]
+    pub validator_set_snapshot: Option<Box<Account<'info, ValidatorSetSnapshot>>>,
+}
+
+#[derive(Accounts)]
+pub struct RefundDepositContext<'info> {
+    // the validator signatures are the authority, anyone can relay them
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        seeds = [b"deposit_record", deposit_record.depositor.as_ref(), deposit_record.client_nonce.to_le_bytes().as_ref()],
+        bump = deposit_record.bump,
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
+    // refunds pay out to the depositor too, so they're held back the same way as reclaims
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_solana", deposit_record.depositor.as_ref()],
+        bump,
+        constraint = depositor_denylist_entry.data_is_empty() @ ErrorCode::AddressDenied,
+    )]
+    pub depositor_denylist_entry: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = depositor_ata.mint == mint_account.key(),
+        constraint = depositor_ata.owner == deposit_record.depositor,
+    )]
+    pub depositor_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    // only needed when the attestation was signed by the previous validator set
+    #[account(
+        seeds = [b"validator_set_snapshot"],
+        bump = validator_set_snapshot.bump,
+    )]
+    pub validator_set_snapshot: Option<Box<Account<'info, ValidatorSetSnapshot>>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey, args: WithdrawUSDCArgs)]
+pub struct WithdrawUSDCContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    /// CHECK: The signed recipient wallet, derives its associated token account and gets the native drop
+    #[account(
+        mut,
+        address = recipient @ ErrorCode::InvalidRecipient,
+    )]
+    pub recipient_wallet: AccountInfo<'info>,
+
+    // first-time arrivals get their ATA created here. The payer fronts the rent and can
+    // get it back through the signed relayer fee.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint_account,
+        associated_token::authority = recipient_wallet,
+    )]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    // only needed when the attestation pays a relayer fee
+    #[account(
+        mut,
+        constraint = payer_ata.mint == mint_account.key(),
+        constraint = payer_ata.owner == payer.key(),
+    )]
+    pub payer_ata: Option<Account<'info, TokenAccount>>,
+
+    // only needed when the attestation was signed by the previous validator set
+    #[account(
+        seeds = [b"validator_set_snapshot"],
+        bump = validator_set_snapshot.bump,
+    )]
+    pub validator_set_snapshot: Option<Box<Account<'info, ValidatorSetSnapshot>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    // pass it to get the reference written as a memo
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    // both only needed when the attestation carries a native drop
+    #[account(
+        mut,
+        seeds = [b"sol_reserve"],
+        bump = sol_reserve.bump,
+    )]
+    pub sol_reserve: Option<Account<'info, SolReserve>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + NativeDropReceipt::INIT_SPACE,
+        seeds = [b"native_drop", recipient.as_ref()],
+        bump
+    )]
+    pub native_drop_receipt: Option<Account<'info, NativeDropReceipt>>,
+
+    // denylist entries for both ends of the withdrawal, they have to not exist
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_solana", recipient.as_ref()],
+        bump,
+        constraint = recipient_denylist_entry.data_is_empty() @ ErrorCode::AddressDenied,
+    )]
+    pub recipient_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_evm", args.sender_evm_address.as_ref()],
+        bump,
+        constraint = sender_denylist_entry.data_is_empty() @ ErrorCode::AddressDenied,
+    )]
+    pub sender_denylist_entry: UncheckedAccount<'info>,
+
+    // required in permissioned pools, ignored otherwise
+    #[account(
+        mut,
+        seeds = [b"allowlist", recipient.as_ref()],
+        bump = recipient_allowlist_entry.bump,
+    )]
+    pub recipient_allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+
+    // marks the attestation as used, a second submission fails right here
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + WithdrawalRecord::INIT_SPACE,
+        seeds = [b"withdrawal", args.source_chain_id.to_le_bytes().as_ref(), args.nonce.as_ref()],
+        bump
+    )]
+    pub withdrawal_record: Account<'info, WithdrawalRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DailyStats::INIT_SPACE,
+        seeds = [b"daily_stats"],
+        bump
+    )]
+    pub daily_stats: Account<'info, DailyStats>,
+
+    // only when the pool is short or withdrawals are already queued, must be the queue's tail
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + WithdrawalIOU::INIT_SPACE,
+        seeds = [b"withdrawal_iou", pool_state.iou_tail.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub withdrawal_iou: Option<Account<'info, WithdrawalIOU>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleWithdrawalIOUContext<'info> {
+    // anyone can settle the head of the queue once liquidity is back
+    #[account(mut)]
+    pub settler: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    // strictly FIFO, only the head of the queue can be settled. Closed once paid, kept if frozen.
+    #[account(
+        mut,
+        seeds = [b"withdrawal_iou", pool_state.iou_head.to_le_bytes().as_ref()],
+        bump = withdrawal_iou.bump,
+    )]
+    pub withdrawal_iou: Account<'info, WithdrawalIOU>,
+
+    /// CHECK: The relayer that queued the IOU, gets its rent back
+    #[account(
+        mut,
+        address = withdrawal_iou.payer,
+    )]
+    pub payer: AccountInfo<'info>,
+
+    /// CHECK: The IOU's recipient wallet, only used to derive its associated token account
+    #[account(
+        address = withdrawal_iou.recipient,
+    )]
+    pub recipient_wallet: AccountInfo<'info>,
+
+    // a recipient denied after the IOU was queued gets its IOU frozen instead of paid
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_solana", withdrawal_iou.recipient.as_ref()],
+        bump,
+    )]
+    pub recipient_denylist_entry: UncheckedAccount<'info>,
+
+    // recreated if the recipient closed it in the meantime, so nobody can stall the queue
+    #[account(
+        init_if_needed,
+        payer = settler,
+        associated_token::mint = mint_account,
+        associated_token::authority = recipient_wallet,
+    )]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    // only needed when the IOU carries a relayer fee, same reasoning as `recipient_ata`
+    #[account(
+        init_if_needed,
+        payer = settler,
+        associated_token::mint = mint_account,
+        associated_token::authority = payer,
+    )]
+    pub payer_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(sequence: u64)]
+pub struct ReleaseFrozenIOUContext<'info> {
+    // anyone can release a frozen IOU once the recipient is off the denylist
+    #[account(mut)]
+    pub settler: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    // already out of the queue, so any frozen IOU can go, in any order
+    #[account(
+        mut,
+        seeds = [b"withdrawal_iou", sequence.to_le_bytes().as_ref()],
+        bump = withdrawal_iou.bump,
+        constraint = withdrawal_iou.frozen @ ErrorCode::IouNotFrozen,
+        close = payer,
+    )]
+    pub withdrawal_iou: Account<'info, WithdrawalIOU>,
+
+    /// CHECK: The relayer that queued the IOU, gets its rent back
+    #[account(
+        mut,
+        address = withdrawal_iou.payer,
+    )]
+    pub payer: AccountInfo<'info>,
+
+    /// CHECK: The IOU's recipient wallet, only used to derive its associated token account
+    #[account(
+        address = withdrawal_iou.recipient,
+    )]
+    pub recipient_wallet: AccountInfo<'info>,
+
+    /// CHECK: only checked for being empty, the seeds pin the address
+    #[account(
+        seeds = [b"denylist_solana", withdrawal_iou.recipient.as_ref()],
+        bump,
+        constraint = recipient_denylist_entry.data_is_empty() @ ErrorCode::AddressDenied,
+    )]
+    pub recipient_denylist_entry: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = settler,
+        associated_token::mint = mint_account,
+        associated_token::authority = recipient_wallet,
+    )]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    // only needed when the IOU carries a relayer fee
+    #[account(
+        init_if_needed,
+        payer = settler,
+        associated_token::mint = mint_account,
+        associated_token::authority = payer,
+    )]
+    pub payer_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SnapshotReservesContext<'info> {
+    // permissionless, whoever wants a fresh proof pays for it
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ReservesHistory::INIT_SPACE,
+        seeds = [b"reserves_history"],
+        bump
+    )]
+    pub reserves_history: Box<Account<'info, ReservesHistory>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct SetAllowlistEntryContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // the allowlist is the compliance authority's alone, the owner only appoints it
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = pool_state.compliance_authority == authority.key() @ ErrorCode::NotComplianceAuthority,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [b"allowlist", wallet.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveAllowlistEntryContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = pool_state.compliance_authority == authority.key() @ ErrorCode::NotComplianceAuthority,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"allowlist", wallet.as_ref()],
+        bump = allowlist_entry.bump,
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct DenySolanaAddressContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = is_compliance_signer(&pool_state, &authority.key()) @ ErrorCode::UnauthorizedCompliance,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DenylistEntry::INIT_SPACE,
+        seeds = [b"denylist_solana", address.as_ref()],
+        bump
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct UndenySolanaAddressContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = is_compliance_signer(&pool_state, &authority.key()) @ ErrorCode::UnauthorizedCompliance,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"denylist_solana", address.as_ref()],
+        bump = denylist_entry.bump,
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 20])]
+pub struct DenyEvmAddressContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = is_compliance_signer(&pool_state, &authority.key()) @ ErrorCode::UnauthorizedCompliance,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DenylistEntry::INIT_SPACE,
+        seeds = [b"denylist_evm", address.as_ref()],
+        bump
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 20])]
+pub struct UndenyEvmAddressContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = is_compliance_signer(&pool_state, &authority.key()) @ ErrorCode::UnauthorizedCompliance,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"denylist_evm", address.as_ref()],
+        bump = denylist_entry.bump,
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+}
+
+#[derive(Accounts)]
+pub struct FundSolReserveContext<'info> {
+    // the owner tops it up, but nobody is stopped from donating
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + SolReserve::INIT_SPACE,
+        seeds = [b"sol_reserve"],
+        bump
+    )]
+    pub sol_reserve: Account<'info, SolReserve>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidityContext<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    // burn/mint pools never hold bridged funds, so there is nothing to provide liquidity for
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+        constraint = pool_state.mode == BridgeMode::LockUnlock @ ErrorCode::WrongBridgeMode,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = provider_ata.mint == mint_account.key(),
+        constraint = provider_ata.owner == provider.key(),
+    )]
+    pub provider_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    // the first LP creates the share mint, same decimals as USDC so a share starts at 1:1
+    #[account(
+        init_if_needed,
+        payer = provider,
+        seeds = [b"lp_mint"],
+        bump,
+        mint::decimals = mint_account.decimals,
+        mint::authority = pool_state,
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_lp_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveLiquidityContext<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    #[account(
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = provider_ata.mint == mint_account.key(),
+        constraint = provider_ata.owner == provider.key(),
+    )]
+    pub provider_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"lp_mint"],
+        bump,
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = provider_lp_ata.mint == lp_mint.key(),
+        constraint = provider_lp_ata.owner == provider.key(),
+    )]
+    pub provider_lp_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFeesContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state"],
+        bump = pool_state.bump,
+        constraint = !pool_state.paused @ ErrorCode::PoolPaused,
+        constraint = pool_state.owner == owner.key()
+    )]
+    pub pool_state: Account<'info, PoolState>,
+
+    /// CHECK: The treasury
+    pub treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = pool_state.usdc_mint == mint_account.key() @ ErrorCode::WrongToken,
+    )]
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = treasury_ata.mint == mint_account.key(),
+        constraint = treasury_ata.owner == treasury.key(),
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = pool_state,
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+
+// ================================================================================================================================  //
+// Arg Structs - Function Argument Definitions                                                                                       //
+//   ▄████████    ▄████████    ▄██████▄          ▄████████     ███        ▄████████ ███    █▄   ▄████████     ███        ▄████████   // 
+//   ███    ███   ███    ███   ███    ███        ███    ███ ▀█████████▄   ███    ███ ███    ███ ███    ███ ▀█████████▄   ███    ███  // 
+//   ███    ███   ███    ███   ███    █▀         ███    █▀     ▀███▀▀██   ███    ███ ███    ███ ███    █▀     ▀███▀▀██   ███    █▀   // 
+//   ███    ███  ▄███▄▄▄▄██▀  ▄███               ███            ███   ▀  ▄███▄▄▄▄██▀ ███    ███ ███            ███   ▀   ███         // 
+// ▀███████████ ▀▀███▀▀▀▀▀   ▀▀███ ████▄       ▀███████████     ███     ▀▀███▀▀▀▀▀   ███    ███ ███            ███     ▀███████████  // 
+//   ███    ███ ▀███████████   ███    ███               ███     ███     ▀███████████ ███    ███ ███    █▄      ███              ███  // 
+//   ███    ███   ███    ███   ███    ███         ▄█    ███     ███       ███    ███ ███    ███ ███    ███     ███        ▄█    ███  // 
+//   ███    █▀    ███    ███   ████████▀        ▄████████▀     ▄████▀     ███    ███ ████████▀  ████████▀     ▄████▀    ▄████████▀   // 
+//                ███    ███                                              ███    ███                                                 // 
+// ================================================================================================================================  //
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeArgs {
+    pub tax: u16,                                   // basis point tax on each transaction
+    pub max_tax: u16,                               // owner fee ceiling in bps, at most MAX_TAX_BPS
+    pub validators: Vec<Pubkey>,                    // the initial validator set, at most MAX_VALIDATORS
+    pub required_signatures: u8,                    // how many of `validators` must sign a withdrawal
+    pub ownership_transfer_window: i64,             // seconds a proposed owner has to accept
+    pub max_deposit_amount: u64,                    // per-deposit cap, 0 for no cap
+    pub max_withdrawal_amount: u64,                 // per-withdrawal cap, 0 for no cap
+    pub mode: BridgeMode,                           // lock/unlock an existing token or burn/mint a wrapped one, fixed for the pool's lifetime
+    pub permissioned: bool,                         // only allowlisted wallets can bridge, can be changed later
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AllowlistEntryArgs {
+    pub daily_limit: u64, // most the wallet can deposit and withdraw per day combined, 0 for no limit
+    pub expires_at: i64, // unix timestamp the entry stops working at, 0 for never
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigUpdateArgs {
+    pub treasury: Option<Pubkey>,
+    pub owner: Option<Pubkey>,
+    pub tax: Option<u16>,
+    pub max_tax: Option<u16>, // can only be lowered
+    pub paused: Option<bool>,
+    pub rotation_requires_approval: Option<bool>, // whether the outgoing validators must sign off on a rotation, can only be turned on
+    pub validator_set_grace_period: Option<i64>, // seconds the previous validator set can still attest
+    pub heartbeat_stale_slots: Option<u64>, // slots without a heartbeat before a validator can be flagged stale
+    pub bond_unbonding_period: Option<i64>, // seconds between starting to unbond and withdrawing the bond
+    pub deposit_processing_window: Option<i64>, // seconds the EVM side has to process a deposit
+    pub reclaim_buffer: Option<i64>, // seconds past the deadline before a deposit can be reclaimed
+    pub refund_fees: Option<bool>, // whether refunds and reclaims also return the fee
+    pub ownership_transfer_window: Option<i64>, // seconds a proposed owner has to accept
+    pub max_deposit_amount: Option<u64>, // per-deposit cap, 0 for no cap
+    pub max_withdrawal_amount: Option<u64>, // per-withdrawal cap, 0 for no cap
+    pub max_native_drop: Option<u64>, // lamports a single withdrawal can drop, 0 disables drops
+    pub max_native_drop_per_recipient: Option<u64>, // lamports a recipient can receive in drops, ever
+    pub lp_fee_share_bps: Option<u16>, // bps of each deposit's tax credited to LPs, at most MAX_LP_FEE_SHARE_BPS
+    pub cctp_token_messenger: Option<Pubkey>, // CCTP program burning deposits, default pubkey disables CCTP deposits
+    pub cctp_message_transmitter: Option<Pubkey>, // CCTP program minting withdrawals, default pubkey disables CCTP withdrawals
+    pub compliance_authority: Option<Pubkey>, // manages the denylist alongside the owner and the allowlist alone, default pubkey for none
+    pub permissioned: Option<bool>, // whether only allowlisted wallets can bridge
+    pub net_flow_threshold: Option<u64>, // net outflow per window that pauses the pool, 0 disables the breaker
+    pub net_flow_window: Option<i64>, // seconds per net flow window
+    pub reserves_snapshot_interval: Option<u64>, // slots between two reserve snapshots
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RotateValidatorSetArgs {
+    pub validators: Vec<Pubkey>,                    // the full replacement set, at most MAX_VALIDATORS
+    pub required_signatures: u8,                    // the new threshold
+    pub r: [[u8; 32]; MAX_VALIDATORS],              // outgoing validator r values, only checked if approval is required
+    pub s: [[u8; 32]; MAX_VALIDATORS],              // outgoing validator s values
+    pub v: [u8; MAX_VALIDATORS],                    // outgoing validator v values
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BondValidatorArgs {
+    pub validator: Pubkey,                          // the validator the bond backs
+    pub amount: u64,                                // how much to add
+    pub r: [u8; 32],                                // validator's signature naming the signer as bond authority
+    pub s: [u8; 32],
+    pub v: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ValidatorHeartbeatArgs {
+    pub validator: Pubkey,                          // the validator checking in
+    pub slot: u64,                                  // the slot it signed for, at most MAX_HEARTBEAT_AGE_SLOTS old
+    pub r: [u8; 32],                                // validator r value
+    pub s: [u8; 32],                                // validator s value
+    pub v: u8,                                      // validator v value
+}
+
+/// One validator's signature over a withdrawal, as it would have been submitted to `withdraw_usdc`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SignedAttestation {
+    pub nonce: [u8; 32],
+    pub amount: u64,
+    pub sender_evm_address: [u8; 20],
+    pub recipient: Pubkey,
+    pub validator_set_epoch: u64,
+    pub reference: Option<[u8; 32]>,
+    pub source_chain_id: u64,
+    pub relayer_fee: u64,
+    pub native_drop: u64,
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub v: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SlashValidatorArgs {
+    pub first: SignedAttestation,                   // one side of the equivocation
+    pub second: SignedAttestation,                  // same nonce, different amount or recipient
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DepositUSDCArgs {
+    pub amount: u64, // total amount to deposit
+    pub recipient_evm_address: [u8; 20], // who the money goes to
+    pub destination_chain_id: u64, // EVM chain id the money goes to
+    pub target_contract: Option<[u8; 20]>, // EVM contract the executor calls with the minted funds
+    pub payload: Vec<u8>, // calldata for `target_contract`, at most MAX_PAYLOAD_LEN bytes, empty for a plain transfer
+    pub reference: Option<[u8; 32]>, // integrator reference id, e.g. an exchange order id
+    pub client_nonce: u64, // picked by the depositor, seeds the DepositRecord together with their key
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawCallArgs {
+    pub target_program: Pubkey,                     // program to CPI into once the tokens have landed
+    pub data: Vec<u8>,                              // instruction data for it, at most MAX_PAYLOAD_LEN bytes
+}
+
+/// Mirrors CCTP's `DepositForBurnParams`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CctpDepositForBurnParams {
+    pub amount: u64,                                // how much to burn out of `pool_ata`
+    pub destination_domain: u32,                    // CCTP domain of the destination chain
+    pub mint_recipient: Pubkey,                     // EVM recipient, left-padded to 32 bytes
+}
+
+/// Mirrors CCTP's `ReceiveMessageParams`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CctpReceiveMessageParams {
+    pub message: Vec<u8>,                           // the burn message from the source chain, minting to `pool_ata`
+    pub attestation: Vec<u8>,                       // Circle's attestation over `message`
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DepositAttestationArgs {
+    pub validator_set_epoch: u64,                   // epoch of the validator set that signed
+    pub r: [[u8; 32]; MAX_VALIDATORS],              // validator r values
+    pub s: [[u8; 32]; MAX_VALIDATORS],              // validator s values
+    pub v: [u8; MAX_VALIDATORS],                    // validator v values
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawUSDCArgs {
+    pub amount: u64,                                // how much to withdraw
+    pub sender_evm_address: [u8; 20],               // who sent the stuff. Note that recipient is missing - it's in a separate val for access by the instruction macro
+    pub nonce: [u8; 32],                            // identifying nonce (bytes32 generated at evm side);
+    pub validator_set_epoch: u64,                   // epoch of the validator set that signed, current or still-valid previous
+    pub reference: Option<[u8; 32]>,                // integrator reference id carried over from the EVM side
+    pub source_chain_id: u64,                       // EVM chain id the deposit was made on
+    pub relayer_fee: u64,                           // part of `amount` paid to whoever submits this, 0 for self-relay
+    pub native_drop: u64,                           // lamports sent to the recipient wallet from the SOL reserve, 0 for none
+    pub r: [[u8; 32]; MAX_VALIDATORS],              // validator r values         
+    pub s: [[u8; 32]; MAX_VALIDATORS],              // validator s values
+    pub v: [u8; MAX_VALIDATORS],                    // validator v values
+}
+
+// =========================================================================================  //
+// Helper Functions                                                                           //
+//   ▄█    █▄       ▄████████  ▄█          ▄███████▄    ▄████████    ▄████████    ▄████████   //
+//   ███    ███     ███    ███ ███         ███    ███   ███    ███   ███    ███   ███    ███  // 
+//   ███    ███     ███    █▀  ███         ███    ███   ███    █▀    ███    ███   ███    █▀   // 
+//  ▄███▄▄▄▄███▄▄  ▄███▄▄▄     ███         ███    ███  ▄███▄▄▄      ▄███▄▄▄▄██▀   ███         // 
+// ▀▀███▀▀▀▀███▀  ▀▀███▀▀▀     ███       ▀█████████▀  ▀▀███▀▀▀     ▀▀███▀▀▀▀▀   ▀███████████  // 
+//   ███    ███     ███    █▄  ███         ███          ███    █▄  ▀███████████          ███  // 
+//   ███    ███     ███    ███ ███▌    ▄   ███          ███    ███   ███    ███    ▄█    ███  // 
+//   ███    █▀      ██████████ █████▄▄██  ▄████▀        ██████████   ███    ███  ▄████████▀   // 
+//                             ▀                                     ███    ███               // 
+// =========================================================================================  //  
+
+/// Builds a standardized message that should match the EVM side.
+/**
+1. User deposits USDC on EVM chain, emitting an event with details
+2. Validators observe this event and sign this exact message format
+3. User submits withdrawal request to Solana with validator Signature
+4. This function recreates the same message hash to verify signatures
+
+Message format is [1-byte chain ID][32 byte nonce][32 byte `amount`(padded)][32 byte `source_address`(padded)][32-byte dest_address (padded)][8 byte validator set epoch (big-endian)][32 byte reference, zeros if none][8 byte source chain id (big-endian)][8 byte relayer fee (big-endian)][8 byte native drop in lamports (big-endian)]
+
+The epoch ties a signature to the validator set that produced it, so attestations don't survive a rotation.
+
+IMPORTANT: This exact format must match what validators use when signing.
+Changes to this function require corresponding changes in the validator implementation.
+*/
+/// This ensures consistent signature verification across chains.
+#[allow(clippy::too_many_arguments)]
+pub fn build_message(
+    nonce: &[u8; 32],               // Unique transaction nonce from EVM event
+    amount: &u64,                   // Amount to withdraw
+    source_address: &[u8; 20],      // EVM source address (20 bytes)
+    dest_address: &Pubkey,          // Solana destination address (32 bytes)
+    validator_set_epoch: u64,       // Epoch of the validator set that signed
+    reference: &Option<[u8; 32]>,   // Integrator reference id, zeros if none
+    source_chain_id: u64,           // EVM chain id the deposit was made on
+    relayer_fee: u64,               // Part of `amount` paid to the submitter
+    native_drop: u64,               // Lamports dropped to the recipient wallet
+) -> [u8; 32] {
+    // Create a buffer to hold all the message components
+    let mut message = Vec::with_capacity(1 + 32 + 32 + 32 + 32 + 8 + 32 + 8 + 8 + 8);
+    
+    // Add chain identifier (1 byte)
+    message.push(1);
+    
+    // Add nonce (32 bytes)
+    message.extend_from_slice(nonce);
+    
+    // Add amount (convert to standard 32 bytes big-endian)
+    let mut amount_bytes = [0u8; 32];
+    let amount_be = amount.to_be_bytes();
+    amount_bytes[32 - amount_be.len()..].copy_from_slice(&amount_be);
+    message.extend_from_slice(&amount_bytes);
+    
+    // Add source address (padded to 32 bytes)
+    let mut source_address_padded = [0u8; 32];
+    let copy_len = std::cmp::min(source_address.len(), 32);
+    source_address_padded[32 - copy_len..].copy_from_slice(&source_address[..copy_len]);
+    message.extend_from_slice(&source_address_padded);
+    
+    // Add destination address (padded to 32 bytes)
+    let mut dest_address_padded = [0u8; 32];
+    let copy_len = std::cmp::min(dest_address.as_ref().len(), 32);
+    dest_address_padded[32 - copy_len..].copy_from_slice(&dest_address.as_ref()[..copy_len]);
+    message.extend_from_slice(&dest_address_padded);
+
+    // Add validator set epoch (8 bytes)
+    message.extend_from_slice(&validator_set_epoch.to_be_bytes());
+
+    // Add reference (32 bytes)
+    message.extend_from_slice(&reference.unwrap_or([0u8; 32]));
+
+    // Add source chain id (8 bytes)
+    message.extend_from_slice(&source_chain_id.to_be_bytes());
+
+    // Add relayer fee (8 bytes)
+    message.extend_from_slice(&relayer_fee.to_be_bytes());
+
+    // Add native drop (8 bytes)
+    message.extend_from_slice(&native_drop.to_be_bytes());
+    
+    // Hash the message using SHA-256
+    let mut hasher = Sha256::new();
+    hasher.update(&message);
+    let hash_result = hasher.finalize();
+    
+    // Convert to fixed-size array
+    let mut hash_array = [0u8; 32];
+    hash_array.copy_from_slice(&hash_result);
+    hash_array
+}
+
+/// Drops `validator` from the live set, keeping the remaining keys packed at the front.
+/// The threshold is left to the caller.
+pub fn remove_validator_from_set(state: &mut PoolState, validator: &Pubkey) -> Result<()> {
+    // find the index of the old validator
+    let index = state
+        .validators
+        .iter()
+        .position(|v| v == validator && *v != Pubkey::default())
+        .ok_or_else(|| error!(ErrorCode::ValidatorDoesNotExist))?;
+    // oh god, shuffling an index array smdh
+    // Shift all validators above the found index down by one
+    for i in index..(MAX_VALIDATORS - 1) {
+        state.validators[i] = state.validators[i + 1];
+        state.validator_joined_slots[i] = state.validator_joined_slots[i + 1];
+    }
+    // Set the last index to the default value
+    state.validators[MAX_VALIDATORS - 1] = Pubkey::default();
+    state.validator_joined_slots[MAX_VALIDATORS - 1] = 0;
+    state.validator_set_epoch = state
+        .validator_set_epoch
+        .checked_add(1)
+        .ok_or_else(|| error!(ErrorCode::EpochOverflow))?;
+    Ok(())
+}
+
+/// Copies the live validator set into the snapshot before it gets changed, so attestations
+/// signed by it stay verifiable for `validator_set_grace_period` seconds. It goes into an
+/// empty or expired slot, or failing that evicts the set closest to expiring.
+/// Returns when the copied set expires.
+pub fn snapshot_validator_set(
+    snapshot: &mut ValidatorSetSnapshot,
+    state: &PoolState,
+    bump: u8,
+    now: i64,
+) -> i64 {
+    // expired and empty slots go first, then whichever set expires soonest
+    let index = (0..VALIDATOR_SET_HISTORY_LEN)
+        .min_by_key(|&i| match snapshot.sets[i].expires_at {
+            expires_at if expires_at < now => i64::MIN,
+            expires_at => expires_at,
+        })
+        .unwrap_or(0);
+    let slot = &mut snapshot.sets[index];
+    slot.validators = state.validators;
+    slot.required_signatures = state.required_signatures;
+    slot.validator_set_epoch = state.validator_set_epoch;
+    slot.expires_at = now.saturating_add(state.validator_set_grace_period);
+    snapshot.bump = bump;
+    slot.expires_at
+}
+
+/// Blanks `validator` out of every previous set in place, so the other keys keep their index
+/// and their signatures still line up. The thresholds stay as they were.
+pub fn scrub_validator_from_snapshot(snapshot: &mut ValidatorSetSnapshot, validator: &Pubkey) {
+    for set in snapshot.sets.iter_mut() {
+        for key in set.validators.iter_mut() {
+            if key == validator {
+                *key = Pubkey::default();
+            }
+        }
+    }
+}
+
+/// Picks the validator set an attestation tagged with `epoch` must be checked against:
+/// the live set, or the snapshotted previous set while it is inside its grace period.
+pub fn attesting_validator_set(
+    state: &PoolState,
+    snapshot: Option<&ValidatorSetSnapshot>,
+    epoch: u64,
+    now: i64,
+) -> Result<([Pubkey; MAX_VALIDATORS], u8)> {
+    if epoch == state.validator_set_epoch {
+        return Ok((state.validators, state.required_signatures));
+    }
+    let previous = snapshot.and_then(|snapshot| {
+        snapshot
+            .sets
+            .iter()
+            .find(|set| set.required_signatures > 0 && set.validator_set_epoch == epoch)
+    });
+    match previous {
+        Some(set) => {
+            require!(now <= set.expires_at, ErrorCode::ValidatorSetExpired);
+            Ok((set.validators, set.required_signatures))
+        }
+        None => Err(error!(ErrorCode::UnknownValidatorSetEpoch)),
+    }
+}
+
+/// Writes the reference id as a hex SPL Memo so it shows up in explorers. Skipped when
+/// there is no reference or the caller didn't pass the memo program.
+pub fn write_reference_memo(
+    memo_program: &Option<Program<Memo>>,
+    reference: &Option<[u8; 32]>,
+) -> Result<()> {
+    if let (Some(memo_program), Some(reference)) = (memo_program, reference) {
+        let memo: String = reference.iter().map(|b| format!("{:02x}", b)).collect();
+        build_memo(
+            CpiContext::new(memo_program.to_account_info(), BuildMemo {}),
+            memo.as_bytes(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Builds the message validators sign for a withdraw-and-call. It wraps the plain withdrawal
+/// message so a signature over one can never be replayed as the other.
+/// Format is sha256 of [b"call"][32 byte `build_message` hash][32 byte target program][32 byte instruction data hash][32 byte account metas hash]
+pub fn build_call_message(
+    withdrawal_message: &[u8; 32],
+    target_program: &Pubkey,
+    data_hash: &[u8; 32],
+    accounts_hash: &[u8; 32],
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"call");
+    hasher.update(withdrawal_message);
+    hasher.update(target_program.as_ref());
+    hasher.update(data_hash);
+    hasher.update(accounts_hash);
+    hasher.finalize().into()
+}
+
+/// Builds the message validators sign for a CCTP withdrawal. It wraps the plain withdrawal
+/// message with the burn message Circle mints from, which carries its source domain and nonce.
+/// Format is sha256 of [b"cctp"][32 byte `build_message` hash][32 byte sha256 of the CCTP message]
+pub fn build_cctp_message(withdrawal_message: &[u8; 32], cctp_message_hash: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"cctp");
+    hasher.update(withdrawal_message);
+    hasher.update(cctp_message_hash);
+    hasher.finalize().into()
+}
+
+/// Hashes the accounts a withdraw-and-call hands its target, in order.
+/// sha256 of [32 byte pubkey][1 byte is_signer][1 byte is_writable] for each account
+pub fn hash_account_metas(metas: &[AccountMeta]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for meta in metas {
+        hasher.update(meta.pubkey.as_ref());
+        hasher.update([meta.is_signer as u8, meta.is_writable as u8]);
+    }
+    hasher.finalize().into()
+}
+
+/// Verifies a withdrawal attestation over `message` and pays `args.amount` out of the pool to
+/// `recipient_ata`, or queues a `WithdrawalIOU` for it when the pool is short. `funded` means the
+/// tokens were just put in `pool_ata` for this withdrawal, so it is paid without ever queueing.
+/// Returns whether it was paid right away. Shared by the plain, CCTP and withdraw-and-call paths.
+pub fn execute_withdrawal(
+    accounts: &mut WithdrawUSDCContext,
+    bumps: &WithdrawUSDCContextBumps,
+    recipient: Pubkey,
+    args: &WithdrawUSDCArgs,
+    message: &[u8; 32],
+    funded: bool,
+) -> Result<bool> {
+    let pool_state = &mut accounts.pool_state;
+    check_transfer_limit(args.amount, pool_state.max_withdrawal_amount)?;
+    // charged when attested, an IOU that pays out later doesn't count a second time
+    use_allowlist_entry(
+        pool_state.permissioned,
+        accounts.recipient_allowlist_entry.as_deref_mut(),
+        args.amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+    // the relayer is paid out of the transfer, never on top of it
+    let recipient_amount = args
+        .amount
+        .checked_sub(args.relayer_fee)
+        .ok_or_else(|| error!(ErrorCode::RelayerFeeTooHigh))?;
+    let (validators, required_signatures) = attesting_validator_set(
+        pool_state,
+        accounts.validator_set_snapshot.as_deref().map(|snapshot| &**snapshot),
+        args.validator_set_epoch,
+        Clock::get()?.unix_timestamp,
+    )?;
+    let signatures = collect_signatures(&args.r, &args.s, &args.v);
+
+    // **I GUESS!!!** validators[i] == signatures[i], maybe.
+    // This is kind of fucked
+
+    let signer_bitmap = verify_signatures_bitmap(
+        &signatures,
+        &validators,
+        message,
+        required_signatures
+    )?;
+    if signer_bitmap.count_ones() >= required_signatures.into() {
+        let clock = Clock::get()?;
+        // the record is `init`, so this attestation can never be submitted again,
+        // whether it pays out now or through an IOU later
+        let record = &mut accounts.withdrawal_record;
+        record.nonce = args.nonce;
+        record.source_chain_id = args.source_chain_id;
+        record.recipient = recipient;
+        record.amount = args.amount;
+        record.slot = clock.slot;
+        record.bump = bumps.withdrawal_record;
+
+        if args.relayer_fee > 0 && accounts.payer_ata.is_none() {
+            return Err(error!(ErrorCode::MissingRelayerAccount));
+        }
+        // anything already queued goes first, and accumulated fees are never paid out as liquidity.
+        // burn/mint pools mint on demand and never run short.
+        let queued = !funded
+            && pool_state.mode == BridgeMode::LockUnlock
+            && (pool_state.iou_head < pool_state.iou_tail
+                || available_liquidity(accounts.pool_ata.amount, pool_state.accumulated_fees)
+                    < args.amount);
+        if queued {
+            let iou = accounts
+                .withdrawal_iou
+                .as_mut()
+                .ok_or_else(|| error!(ErrorCode::InsufficientLiquidity))?;
+            iou.sequence = pool_state.iou_tail;
+            iou.recipient = recipient;
+            iou.amount = recipient_amount;
+            iou.relayer_fee = args.relayer_fee;
+            iou.payer = accounts.payer.key();
+            iou.nonce = args.nonce;
+            iou.source_chain_id = args.source_chain_id;
+            iou.created_at = clock.unix_timestamp;
+            iou.frozen = false;
+            iou.bump = bumps.withdrawal_iou.unwrap_or_default();
+            pool_state.iou_tail = pool_state
+                .iou_tail
+                .checked_add(1)
+                .ok_or_else(|| error!(ErrorCode::IouSequenceOverflow))?;
+            pool_state.pending_iou_liabilities =
+                pool_state.pending_iou_liabilities.saturating_add(args.amount);
+        } else {
+            // an IOU account here would be created for nothing
+            require!(accounts.withdrawal_iou.is_none(), ErrorCode::WithdrawalIouNotNeeded);
+            // NOTE: Transfers the amount **less tax** paid on the opposing chain
+            // NOTE: transfers *from* EVM have already paid the tax to treasury
+            release_from_pool(
+                &accounts.token_program,
+                pool_state,
+                &accounts.pool_ata,
+                &accounts.mint_account,
+                accounts.recipient_ata.to_account_info(),
+                recipient_amount,
+            )?;
+            if let Some(payer_ata) = accounts.payer_ata.as_ref().filter(|_| args.relayer_fee > 0) {
+                release_from_pool(
+                    &accounts.token_program,
+                    pool_state,
+                    &accounts.pool_ata,
+                    &accounts.mint_account,
+                    payer_ata.to_account_info(),
+                    args.relayer_fee,
+                )?;
+            }
+        }
+
+        let native_drop = if args.native_drop > 0 {
+            deliver_native_drop(accounts, bumps, recipient, args.native_drop)?
+        } else {
+            0
+        };
+        let pool_state = &mut accounts.pool_state;
+
+        // flex the transfer, queued ones count too since the attestation is spent
+        accounts.daily_stats.bump = bumps.daily_stats;
+        record_withdrawal_stats(pool_state, &mut accounts.daily_stats, args.amount, clock.unix_timestamp);
+        let address = pool_state.key();
+        record_withdrawal_flow(pool_state, address, args.amount, clock.unix_timestamp);
+
+        if queued {
+            emit!(WithdrawalQueued {
+                address: pool_state.key(),
+                sequence: pool_state.iou_tail - 1,
+                recipient,
+                amount: recipient_amount,
+                relayer_fee: args.relayer_fee,
+                reference: args.reference,
+                nonce: args.nonce,
+                sender_evm_address: args.sender_evm_address,
+                source_chain_id: args.source_chain_id,
+                validator_set_epoch: args.validator_set_epoch,
+                signer_bitmap,
+                payer: accounts.payer.key(),
+                native_drop,
+                timestamp: clock.unix_timestamp,
+            });
+        } else {
+            emit!(USDCWithdrawn {
+                address: pool_state.key(),
+                recipient: recipient.key(),
+                amount: recipient_amount, // Amount after fee
+                relayer_fee: args.relayer_fee,
+                reference: args.reference,
+                nonce: args.nonce,
+                sender_evm_address: args.sender_evm_address,
+                source_chain_id: args.source_chain_id,
+                validator_set_epoch: args.validator_set_epoch,
+                signer_bitmap,
+                payer: accounts.payer.key(),
+                native_drop,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        write_reference_memo(&accounts.memo_program, &args.reference)?;
+        Ok(!queued)
+    } else {
+        Err(error!(ErrorCode::FailedToValidate))
+    }
+}
+
+/// Sends `amount` out of `pool_ata`, or any other token account the pool PDA owns, signed for by the pool PDA.
+pub fn transfer_from_pool<'info>(
+    token_program: &Program<'info, Token>,
+    pool_state: &Account<'info, PoolState>,
+    pool_ata: &Account<'info, TokenAccount>,
+    to: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let bump = pool_state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"pool_state", &[bump]]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+            from: pool_ata.to_account_info(),
+            to,
+            authority: pool_state.to_account_info(),
+        },
+        signer_seeds,
+    );
+    transfer(transfer_ctx, amount)
+}
+
+/// Pays a withdrawal IOU out of the pool to its recipient and relayer and takes it off the
+/// pool's liabilities. Shared by settling the head of the queue and releasing a frozen IOU.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_withdrawal_iou<'info>(
+    token_program: &Program<'info, Token>,
+    pool_state: &mut Account<'info, PoolState>,
+    pool_ata: &Account<'info, TokenAccount>,
+    recipient_ata: &Account<'info, TokenAccount>,
+    payer_ata: Option<&Account<'info, TokenAccount>>,
+    iou: &WithdrawalIOU,
+    settler: Pubkey,
+    now: i64,
+) -> Result<()> {
+    let owed = iou
+        .amount
+        .checked_add(iou.relayer_fee)
+        .ok_or_else(|| error!(ErrorCode::TaxFailed))?;
+    require!(
+        available_liquidity(pool_ata.amount, pool_state.accumulated_fees) >= owed,
+        ErrorCode::InsufficientLiquidity
+    );
+    transfer_from_pool(
+        token_program,
+        pool_state,
+        pool_ata,
+        recipient_ata.to_account_info(),
+        iou.amount,
+    )?;
+    if iou.relayer_fee > 0 {
+        let payer_ata = payer_ata.ok_or_else(|| error!(ErrorCode::MissingRelayerAccount))?;
+        transfer_from_pool(
+            token_program,
+            pool_state,
+            pool_ata,
+            payer_ata.to_account_info(),
+            iou.relayer_fee,
+        )?;
+    }
+    // frozen IOUs stay in here too, they are still owed
+    pool_state.pending_iou_liabilities = pool_state.pending_iou_liabilities.saturating_sub(owed);
+    emit!(WithdrawalIOUSettled {
+        address: pool_state.key(),
+        sequence: iou.sequence,
+        recipient: iou.recipient,
+        amount: iou.amount,
+        relayer_fee: iou.relayer_fee,
+        nonce: iou.nonce,
+        settler,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Pays `amount` of bridged funds out to `to`. Lock/unlock pools unlock it from `pool_ata`,
+/// burn/mint pools mint it fresh, since the locked side lives on the EVM chain.
+pub fn release_from_pool<'info>(
+    token_program: &Program<'info, Token>,
+    pool_state: &Account<'info, PoolState>,
+    pool_ata: &Account<'info, TokenAccount>,
+    mint_account: &Account<'info, Mint>,
+    to: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    match pool_state.mode {
+        BridgeMode::LockUnlock => transfer_from_pool(token_program, pool_state, pool_ata, to, amount),
+        BridgeMode::BurnMint => {
+            let bump = pool_state.bump;
+            let signer_seeds: &[&[&[u8]]] = &[&[b"pool_state", &[bump]]];
+            let mint_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: mint_account.to_account_info(),
+                    to,
+                    authority: pool_state.to_account_info(),
+                },
+                signer_seeds,
+            );
+            mint_to(mint_ctx, amount)
+        }
+    }
+}
+
+/// CCTP domain of an EVM chain id, mainnets and their testnets, None where Circle doesn't run.
+pub fn cctp_domain(chain_id: u64) -> Option<u32> {
+    match chain_id {
+        1 | 11155111 => Some(0),    // Ethereum, Sepolia
+        43114 | 43113 => Some(1),   // Avalanche, Fuji
+        10 | 11155420 => Some(2),   // OP Mainnet, OP Sepolia
+        42161 | 421614 => Some(3),  // Arbitrum One, Arbitrum Sepolia
+        8453 | 84532 => Some(6),    // Base, Base Sepolia
+        137 | 80002 => Some(7),     // Polygon PoS, Amoy
+        _ => None,
+    }
+}
+
+/// CPIs into the CCTP program `program_id`, calling the Anchor instruction `name` with `params`.
+/// `accounts[0]` is the program, the rest are passed through in order and the pool PDA signs
+/// wherever it shows up, as the burn owner or the message caller. Since it signs, `pool_ata` is
+/// the only token account of the pool's that may be handed over writable, the bond vault and
+/// insurance fund never are.
+pub fn invoke_cctp<'info>(
+    program_id: Pubkey,
+    name: &str,
+    params: &impl AnchorSerialize,
+    accounts: &[AccountInfo<'info>],
+    pool_state: &Account<'info, PoolState>,
+    pool_ata: &Pubkey,
+) -> Result<()> {
+    require!(program_id != Pubkey::default(), ErrorCode::CctpNotConfigured);
+    let (program, cpi_accounts) = accounts
+        .split_first()
+        .ok_or_else(|| error!(ErrorCode::InvalidCctpProgram))?;
+    require!(
+        program.key() == program_id && program.executable,
+        ErrorCode::InvalidCctpProgram
+    );
+    for account in cpi_accounts.iter().filter(|account| account.is_writable && account.key() != *pool_ata) {
+        if account.owner != &anchor_spl::token::ID {
+            continue;
+        }
+        let owned_by_pool = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])
+            .map(|token_account| token_account.owner == pool_state.key())
+            .unwrap_or(false);
+        require!(!owned_by_pool, ErrorCode::InvalidCctpTokenAccount);
+    }
+    let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+    params.serialize(&mut data)?;
+    let instruction = Instruction {
+        program_id,
+        accounts: cpi_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == pool_state.key(),
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data,
+    };
+    let mut account_infos = cpi_accounts.to_vec();
+    account_infos.push(program.clone());
+    let bump = pool_state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"pool_state", &[bump]]];
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+    Ok(())
+}
+
+/// Tokens in the pool that can go out to withdrawals. Accumulated fees belong to the
+/// treasury and are held back.
+pub fn available_liquidity(pool_balance: u64, accumulated_fees: u64) -> u64 {
+    pool_balance.saturating_sub(accumulated_fees)
+}
+
+/// Moves up to `requested` lamports from the SOL reserve to the recipient wallet and returns
+/// how much actually went out. The drop shrinks to fit the per-withdrawal cap, what is left of
+/// the recipient's lifetime cap and what the reserve holds above rent, so a dry reserve or a
+/// farmed-out recipient never blocks the USDC itself.
+pub fn deliver_native_drop(
+    accounts: &mut WithdrawUSDCContext,
+    bumps: &WithdrawUSDCContextBumps,
+    recipient: Pubkey,
+    requested: u64,
+) -> Result<u64> {
+    let pool_state = &accounts.pool_state;
+    let (Some(reserve), Some(receipt)) = (
+        accounts.sol_reserve.as_mut(),
+        accounts.native_drop_receipt.as_mut(),
+    ) else {
+        return Err(error!(ErrorCode::MissingNativeDropAccounts));
+    };
+    receipt.recipient = recipient;
+    receipt.bump = bumps.native_drop_receipt.unwrap_or_default();
+
+    let recipient_allowance = pool_state
+        .max_native_drop_per_recipient
+        .saturating_sub(receipt.total_dropped);
+    let reserve_info = reserve.to_account_info();
+    let amount = requested
+        .min(pool_state.max_native_drop)
+        .min(recipient_allowance)
+        .min(available_reserve_lamports(&reserve_info)?);
+    if amount == 0 {
+        return Ok(0);
+    }
+    // a drop that leaves the wallet short of rent exemption would fail the whole transaction,
+    // so it is skipped and the withdrawal still goes through
+    let recipient_wallet = &accounts.recipient_wallet;
+    let rent_exempt = Rent::get()?.minimum_balance(recipient_wallet.data_len());
+    if recipient_wallet.lamports().saturating_add(amount) < rent_exempt {
+        return Ok(0);
+    }
+
+    // the reserve is program-owned, so lamports can be moved without a CPI
+    **reserve_info.try_borrow_mut_lamports()? -= amount;
+    **accounts.recipient_wallet.try_borrow_mut_lamports()? += amount;
+    reserve.total_dropped = reserve.total_dropped.saturating_add(amount);
+    receipt.total_dropped = receipt.total_dropped.saturating_add(amount);
+    Ok(amount)
+}
+
+/// Lamports in the SOL reserve above its rent-exempt minimum.
+pub fn available_reserve_lamports(reserve: &AccountInfo) -> Result<u64> {
+    let rent_exempt = Rent::get()?.minimum_balance(reserve.data_len());
+    Ok(reserve.lamports().saturating_sub(rent_exempt))
+}
+
+/// The LPs' cut of `tax`. Nothing goes to LPs while there is no liquidity to own it.
+pub fn lp_fee_for(state: &PoolState, tax: u64) -> u64 {
+    if state.total_liquidity == 0 {
+        return 0;
+    }
+    (tax as u128 * state.lp_fee_share_bps as u128 / 10_000) as u64
+}
+
+/// Shares minted for `amount` of USDC, rounded down so existing holders never lose value.
+/// Priced off `total_liquidity` rather than the pool balance, so donating to the pool
+/// can't move the share price.
+pub fn shares_for_liquidity(amount: u64, total_liquidity: u64, total_shares: u64) -> Result<u64> {
+    if total_shares == 0 || total_liquidity == 0 {
+        return Ok(amount);
+    }
+    u64::try_from(amount as u128 * total_shares as u128 / total_liquidity as u128)
+        .map_err(|_| error!(ErrorCode::LiquidityOverflow))
+}
+
+/// USDC owed for burning `shares`, rounded down so the remaining holders never lose value.
+pub fn liquidity_for_shares(shares: u64, total_liquidity: u64, total_shares: u64) -> Result<u64> {
+    require!(shares <= total_shares, ErrorCode::InsufficientShares);
+    if shares == total_shares {
+        // the last one out takes the rounding dust with them
+        return Ok(total_liquidity);
+    }
+    Ok((shares as u128 * total_liquidity as u128 / total_shares as u128) as u64)
+}
+
+/// Takes a deposit into the pool, records it and emits `USDCDeposited`. Shared by the plain
+/// and the CCTP deposit paths, `via_cctp` leaves the event to the CCTP path so validators
+/// never see a deposit Circle already delivers.
+pub fn execute_deposit(
+    accounts: &mut DepositUSDCContext,
+    bumps: &DepositUSDCContextBumps,
+    args: DepositUSDCArgs,
+    via_cctp: bool,
+) -> Result<()> {
+    let state = &mut accounts.pool_state;
+    let depositor = &mut accounts.depositor;
+    let state_ata = &mut accounts.pool_ata;
+    let depositor_ata = &mut accounts.depositor_ata;
+    let amount = args.amount;
+    let recipient_evm_address = args.recipient_evm_address;
+    check_transfer_limit(amount, state.max_deposit_amount)?;
+    use_allowlist_entry(
+        state.permissioned,
+        accounts.depositor_allowlist_entry.as_deref_mut(),
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+    require!(args.payload.len() <= MAX_PAYLOAD_LEN, ErrorCode::PayloadTooLarge);
+    // calldata without a contract to call has nowhere to go
+    require!(
+        args.payload.is_empty() || args.target_contract.is_some(),
+        ErrorCode::PayloadWithoutTarget
+    );
+    let payload_hash = hash_payload(&args.payload);
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    depositor.key().hash(&mut hasher);
+    state_ata.key().hash(&mut hasher);
+    Clock::get()?.unix_timestamp.hash(&mut hasher);
+    recipient_evm_address.hash(&mut hasher);
+    amount.hash(&mut hasher);
+    let nonce: u64 = hasher.finish();
+    // calculate the tax from state.tax, a basis point fee
+    let tax_amount = amount
+        .checked_mul(state.tax as u64)
+        .and_then(|result| result.checked_div(10000))
+        .ok_or_else(|| error!(ErrorCode::TaxFailed))?;
+    let deposit_amount = amount.checked_sub(tax_amount).ok_or_else(|| error!(ErrorCode::TaxFailed))?;
+    let clock = Clock::get()?;
+    let sequence = state.deposit_sequence;
+    // past this the EVM side must not mint and the depositor can take the funds back
+    let deadline = clock
+        .unix_timestamp
+        .saturating_add(state.deposit_processing_window);
+    let deposit_id = build_deposit_id(
+        &state.key(),
+        sequence,
+        &depositor.key(),
+        deposit_amount,
+        tax_amount,
+        &recipient_evm_address,
+        args.destination_chain_id,
+        deadline,
+        &args.target_contract,
+        &payload_hash,
+        &args.reference,
+    );
+    let transfer_ctx = CpiContext::new(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: depositor_ata.to_account_info(),
+            to: state_ata.to_account_info(),
+            authority: depositor.to_account_info(),
+        },
+    );
+    match state.mode {
+        // NOTE: Transfers the original `amount` value - leaves the `tax` **inside** the pool!
+        // tax can be recovered by accumulating the event emissions
+        BridgeMode::LockUnlock => transfer(transfer_ctx, amount)?,
+        // only the tax stays behind, the bridged part stops existing on this side
+        BridgeMode::BurnMint => {
+            if tax_amount > 0 {
+                transfer(transfer_ctx, tax_amount)?;
+            }
+            let burn_ctx = CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Burn {
+                    mint: accounts.mint_account.to_account_info(),
+                    from: depositor_ata.to_account_info(),
+                    authority: depositor.to_account_info(),
+                },
+            );
+            burn(burn_ctx, deposit_amount)?;
+        }
+    }
+    // LPs' cut of the tax is theirs from here on, only the rest is protocol fees
+    let lp_fee = lp_fee_for(state, tax_amount);
+    state.accumulated_fees += tax_amount - lp_fee;
+    state.total_liquidity = state
+        .total_liquidity
+        .checked_add(lp_fee)
+        .ok_or_else(|| error!(ErrorCode::LiquidityOverflow))?;
+    accounts.daily_stats.bump = bumps.daily_stats;
+    record_deposit_stats(state, &mut accounts.daily_stats, amount, tax_amount, clock.unix_timestamp);
+    record_deposit_flow(state, amount, clock.unix_timestamp);
+    state.deposit_sequence = sequence
+        .checked_add(1)
+        .ok_or_else(|| error!(ErrorCode::DepositSequenceOverflow))?;
+    state.pending_deposit_liabilities = state
+        .pending_deposit_liabilities
+        .saturating_add(deposit_amount);
+
+    let record = &mut accounts.deposit_record;
+    record.deposit_id = deposit_id;
+    record.sequence = sequence;
+    record.client_nonce = args.client_nonce;
+    record.depositor = depositor.key();
+    record.amount = deposit_amount;
+    record.tax = tax_amount;
+    record.lp_fee = lp_fee;
+    record.recipient_evm_address = recipient_evm_address;
+    record.destination_chain_id = args.destination_chain_id;
+    record.target_contract = args.target_contract;
+    record.payload_hash = payload_hash;
+    record.reference = args.reference;
+    record.status = DepositStatus::Pending;
+    record.slot = clock.slot;
+    record.deadline = deadline;
+    record.bump = bumps.deposit_record;
+
+    if !via_cctp {
+        emit!(USDCDeposited {
+            address: state.key(),
+            depositor: depositor.key(),
+            amount: deposit_amount, // Amount after fee
+            recipient_evm_address,
+            tax: tax_amount,
+            nonce,
+            deposit_id,
+            deadline,
+            target_contract: args.target_contract,
+            payload: args.payload,
+            reference: args.reference,
+            sequence,
+            client_nonce: args.client_nonce,
+            destination_chain_id: args.destination_chain_id,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    write_reference_memo(&accounts.memo_program, &args.reference)?;
+    Ok(())
+}
+
+/// Today's bucket in the daily stats ring buffer, cleared first if it still holds an older day.
+pub fn daily_stats_entry(stats: &mut DailyStats, now: i64) -> &mut DailyStatsEntry {
+    let day = now.div_euclid(SECONDS_PER_DAY);
+    let entry = &mut stats.days[day.rem_euclid(DAILY_STATS_LEN as i64) as usize];
+    if entry.day != day {
+        *entry = DailyStatsEntry {
+            day,
+            ..Default::default()
+        };
+    }
+    entry
+}
+
+/// Counts a deposit of `amount`, tax included, in the lifetime and daily stats.
+pub fn record_deposit_stats(state: &mut PoolState, stats: &mut DailyStats, amount: u64, fee: u64, now: i64) {
+    // u128 sums of u64 amounts won't overflow in practice, saturate rather than fail a deposit
+    state.total_deposited = state.total_deposited.saturating_add(amount.into());
+    state.total_fees_collected = state.total_fees_collected.saturating_add(fee.into());
+    state.deposit_count = state.deposit_count.saturating_add(1);
+    let entry = daily_stats_entry(stats, now);
+    entry.deposited = entry.deposited.saturating_add(amount.into());
+    entry.fees_collected = entry.fees_collected.saturating_add(fee.into());
+    entry.deposit_count = entry.deposit_count.saturating_add(1);
+}
+
+/// Counts a withdrawal of `amount`, relayer fee included, in the lifetime and daily stats.
+pub fn record_withdrawal_stats(state: &mut PoolState, stats: &mut DailyStats, amount: u64, now: i64) {
+    state.total_withdrawn = state.total_withdrawn.saturating_add(amount.into());
+    state.withdrawal_count = state.withdrawal_count.saturating_add(1);
+    let entry = daily_stats_entry(stats, now);
+    entry.withdrawn = entry.withdrawn.saturating_add(amount.into());
+    entry.withdrawal_count = entry.withdrawal_count.saturating_add(1);
+}
+
+/// The owner can always act on the denylist, the compliance authority only if one is set.
+pub fn is_compliance_signer(state: &PoolState, signer: &Pubkey) -> bool {
+    *signer == state.owner
+        || (state.compliance_authority != Pubkey::default() && *signer == state.compliance_authority)
+}
+
+/// Starts a fresh net flow window at `now`.
+pub fn reset_net_flow_window(state: &mut PoolState, now: i64) {
+    state.net_flow_window_start = now;
+    state.net_flow_deposited = 0;
+    state.net_flow_withdrawn = 0;
+}
+
+/// Rolls the net flow window over once it has run its course. These are back to back windows,
+/// not a true rolling sum, so an outflow split across a boundary can reach twice the threshold.
+pub fn roll_net_flow_window(state: &mut PoolState, now: i64) {
+    if now >= state.net_flow_window_start.saturating_add(state.net_flow_window) {
+        reset_net_flow_window(state, now);
+    }
+}
+
+pub fn record_deposit_flow(state: &mut PoolState, amount: u64, now: i64) {
+    roll_net_flow_window(state, now);
+    state.net_flow_deposited = state.net_flow_deposited.saturating_add(amount);
+}
+
+/// Counts a withdrawal, refund or reclaim against the current window and pauses the pool if the net outflow is now
+/// over the threshold. The withdrawal that trips the breaker still lands, the ones after it don't.
+pub fn record_withdrawal_flow(state: &mut PoolState, address: Pubkey, amount: u64, now: i64) {
+    roll_net_flow_window(state, now);
+    state.net_flow_withdrawn = state.net_flow_withdrawn.saturating_add(amount);
+    let net_flow = state.net_flow_withdrawn.saturating_sub(state.net_flow_deposited);
+    if state.net_flow_threshold == 0 || net_flow <= state.net_flow_threshold || state.circuit_breaker_tripped {
+        return;
+    }
+    state.paused = true;
+    state.circuit_breaker_tripped = true;
+    emit!(CircuitBreakerTripped {
+        address,
+        window_start: state.net_flow_window_start,
+        deposited: state.net_flow_deposited,
+        withdrawn: state.net_flow_withdrawn,
+        net_flow,
+        threshold: state.net_flow_threshold,
+        timestamp: now,
+    });
+}
+
+/// Takes a deposit that just left `Pending` off the pool's outstanding liabilities.
+pub fn settle_deposit_liability(state: &mut PoolState, record: &DepositRecord) {
+    state.pending_deposit_liabilities = state
+        .pending_deposit_liabilities
+        .saturating_sub(record.amount);
+}
+
+/// Links a reserves snapshot to the one before it.
+/// sha256 of [32 byte previous hash][8 byte sequence][8 byte slot][8 byte timestamp][8 byte pool balance][8 byte accumulated fees][8 byte pending deposit liabilities][8 byte total liquidity][8 byte pending IOU liabilities][16 byte total deposited][16 byte total withdrawn], integers big-endian
+pub fn build_reserves_snapshot_hash(previous_hash: &[u8; 32], snapshot: &ReservesSnapshotEntry) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(previous_hash);
+    hasher.update(snapshot.sequence.to_be_bytes());
+    hasher.update(snapshot.slot.to_be_bytes());
+    hasher.update(snapshot.timestamp.to_be_bytes());
+    hasher.update(snapshot.pool_balance.to_be_bytes());
+    hasher.update(snapshot.accumulated_fees.to_be_bytes());
+    hasher.update(snapshot.pending_deposit_liabilities.to_be_bytes());
+    hasher.update(snapshot.total_liquidity.to_be_bytes());
+    hasher.update(snapshot.pending_iou_liabilities.to_be_bytes());
+    hasher.update(snapshot.total_deposited.to_be_bytes());
+    hasher.update(snapshot.total_withdrawn.to_be_bytes());
+    hasher.finalize().into()
+}
+
+/// Derives the id a deposit is tracked by on both chains.
+/// sha256 of [b"deposit"][32 byte pool state][8 byte sequence][32 byte depositor][8 byte amount][8 byte tax][20 byte recipient][8 byte destination chain id][8 byte deadline][20 byte target contract, zero if none][32 byte payload hash][32 byte reference, zero if none], integers big-endian
+#[allow(clippy::too_many_arguments)]
+pub fn build_deposit_id(
+    pool_state: &Pubkey,
+    sequence: u64,
+    depositor: &Pubkey,
+    amount: u64,
+    tax: u64,
+    recipient_evm_address: &[u8; 20],
+    destination_chain_id: u64,
+    deadline: i64,
+    target_contract: &Option<[u8; 20]>,
+    payload_hash: &[u8; 32],
+    reference: &Option<[u8; 32]>,
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"deposit");
+    hasher.update(pool_state.as_ref());
+    hasher.update(sequence.to_be_bytes());
+    hasher.update(depositor.as_ref());
+    hasher.update(amount.to_be_bytes());
+    hasher.update(tax.to_be_bytes());
+    hasher.update(recipient_evm_address);
+    hasher.update(destination_chain_id.to_be_bytes());
+    hasher.update(deadline.to_be_bytes());
+    // a plain transfer commits to a zero target
+    hasher.update(target_contract.unwrap_or([0u8; 20]));
+    hasher.update(payload_hash);
+    hasher.update(reference.unwrap_or([0u8; 32]));
+    hasher.finalize().into()
+}
+
+/// sha256 of the bridge-and-call payload, all zeros when there is none.
+pub fn hash_payload(payload: &[u8]) -> [u8; 32] {
+    if payload.is_empty() {
+        return [0u8; 32];
+    }
+    Sha256::digest(payload).into()
+}
+
+/// Builds the message validators sign to settle a deposit, either as processed on the EVM side
+/// or as refundable. Format is [b"deposit_status"][32 byte deposit id][1 byte status][8 byte validator set epoch]
+pub fn build_deposit_attestation_message(
+    deposit_id: &[u8; 32],
+    status: DepositStatus,
+    validator_set_epoch: u64,
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"deposit_status");
+    hasher.update(deposit_id);
+    hasher.update([status as u8]);
+    hasher.update(validator_set_epoch.to_be_bytes());
+    hasher.finalize().into()
+}
+
+/// What a refunded or reclaimed deposit pays back: always the principal, plus the fee when
+/// `refund_fees` is on and it hasn't already been swept to the treasury.
+pub fn take_refund_amount(state: &mut PoolState, record: &DepositRecord) -> Result<(u64, u64)> {
+    let mut fee_refunded = 0;
+    // the LP cut was already credited to share holders, clawing it back would dilute them
+    let protocol_fee = record.tax.saturating_sub(record.lp_fee);
+    if state.refund_fees && state.accumulated_fees >= protocol_fee {
+        state.accumulated_fees -= protocol_fee;
+        fee_refunded = protocol_fee;
+    }
+    let amount = record
+        .amount
+        .checked_add(fee_refunded)
+        .ok_or_else(|| error!(ErrorCode::TaxFailed))?;
+    Ok((amount, fee_refunded))
+}
+
+/// Checks a threshold of the validator set for `args.validator_set_epoch` signed `message`.
+pub fn verify_attestation(
+    state: &PoolState,
+    snapshot: Option<&ValidatorSetSnapshot>,
+    args: &DepositAttestationArgs,
+    message: &[u8; 32],
+    now: i64,
+) -> Result<()> {
+    let (validators, required_signatures) =
+        attesting_validator_set(state, snapshot, args.validator_set_epoch, now)?;
+    let signatures = collect_signatures(&args.r, &args.s, &args.v);
+    let verified = verify_signatures(&signatures, &validators, message, required_signatures)?;
+    require!(verified, ErrorCode::FailedToValidate);
+    Ok(())
+}
+
+/// Builds the message the outgoing validator set signs to approve a rotation.
+/// Format is [ROTATION_DOMAIN][32 byte program id][32 byte pool state][8 byte new epoch (big-endian)]
+/// [1 byte threshold][32 bytes per new validator]
+pub fn build_rotation_message(
+    pool_state: &Pubkey,
+    new_epoch: u64,
+    validators: &[Pubkey],
+    required_signatures: u8,
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(ROTATION_DOMAIN);
+    hasher.update(crate::ID.as_ref());
+    hasher.update(pool_state.as_ref());
+    hasher.update(new_epoch.to_be_bytes());
+    hasher.update([required_signatures]);
+    for validator in validators {
+        hasher.update(validator.as_ref());
+    }
+    hasher.finalize().into()
+}
+
+/// Builds the message a validator signs to prove it is alive.
+/// Format is [HEARTBEAT_DOMAIN][32 byte program id][32 byte pool state][32 byte validator][8 byte slot (big-endian)]
+pub fn build_heartbeat_message(pool_state: &Pubkey, validator: &Pubkey, slot: u64) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(HEARTBEAT_DOMAIN);
+    hasher.update(crate::ID.as_ref());
+    hasher.update(pool_state.as_ref());
+    hasher.update(validator.as_ref());
+    hasher.update(slot.to_be_bytes());
+    hasher.finalize().into()
+}
+
+/// Builds the message a validator signs to name the Solana wallet that owns its bond.
+/// Format is [BOND_DOMAIN][32 byte program id][32 byte pool state][32 byte validator][32 byte authority]
+pub fn build_bond_authority_message(pool_state: &Pubkey, validator: &Pubkey, authority: &Pubkey) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(BOND_DOMAIN);
+    hasher.update(crate::ID.as_ref());
+    hasher.update(pool_state.as_ref());
+    hasher.update(validator.as_ref());
+    hasher.update(authority.as_ref());
+    hasher.finalize().into()
+}
+
+/// args.r, args.s, args.v need to be converted into &[Signatures], which is a struct with fields r, s, v.
+/// the index of args.r, etc should match the index of &[Signatures].
+pub fn collect_signatures(
+    r: &[[u8; 32]; MAX_VALIDATORS],
+    s: &[[u8; 32]; MAX_VALIDATORS],
+    v: &[u8; MAX_VALIDATORS],
+) -> [Signature; MAX_VALIDATORS] {
+    let mut signatures: [Signature; MAX_VALIDATORS] = [Signature {
+        r: [0u8; 32],
+        s: [0u8; 32],
+        v: 0,
+    }; MAX_VALIDATORS];
+    for i in 0..MAX_VALIDATORS {
+        signatures[i].r = r[i];
+        signatures[i].s = s[i];
+        signatures[i].v = v[i];
+    }
+    signatures
+}
+
+/// Tax rules shared by `initialize` and `update_state`.
+pub fn validate_tax(tax: u16, max_tax: u16) -> Result<()> {
+    // tax cannot be zero
+    require!(tax != 0, ErrorCode::ZeroTax);
+    require!(tax <= max_tax && tax <= MAX_TAX_BPS, ErrorCode::TaxTooHigh);
+    Ok(())
+}
+
+/// A validator set must be non-empty, fit in `MAX_VALIDATORS`, contain no default or
+/// duplicate keys, and have a threshold between 1 and the number of validators.
+pub fn validate_validator_set(validators: &[Pubkey], required_signatures: u8) -> Result<()> {
+    require!(!validators.is_empty(), ErrorCode::InvalidValidatorAccount);
+    require!(validators.len() <= MAX_VALIDATORS, ErrorCode::TooManyValidators);
+    for (i, validator) in validators.iter().enumerate() {
+        require!(*validator != Pubkey::default(), ErrorCode::InvalidValidatorAccount);
+        require!(!validators[..i].contains(validator), ErrorCode::DuplicateValidator);
+    }
+    require!(
+        required_signatures >= 1 && (required_signatures as usize) <= validators.len(),
+        ErrorCode::InvalidThreshold
+    );
+    Ok(())
+}
+
+/// In permissioned pools the wallet needs a live allowlist entry. IOUs are only checked when
+/// they are queued, so a lapsed entry at the head can't hold up everyone behind it.
+pub fn check_allowlist_entry(permissioned: bool, entry: Option<&AllowlistEntry>, now: i64) -> Result<()> {
+    if !permissioned {
+        return Ok(());
+    }
+    let entry = entry.ok_or_else(|| error!(ErrorCode::NotAllowlisted))?;
+    require!(
+        entry.expires_at == 0 || now < entry.expires_at,
+        ErrorCode::AllowlistEntryExpired
+    );
+    Ok(())
+}
+
+/// Checks the depositor's or recipient's allowlist entry, then takes `amount` out of what is
+/// left of its daily limit. Deposits and withdrawals share the one limit.
+pub fn use_allowlist_entry(
+    permissioned: bool,
+    entry: Option<&mut AllowlistEntry>,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    if !permissioned {
+        return Ok(());
+    }
+    let entry = entry.ok_or_else(|| error!(ErrorCode::NotAllowlisted))?;
+    check_allowlist_entry(permissioned, Some(entry), now)?;
+    let day = now.div_euclid(SECONDS_PER_DAY);
+    if entry.day != day {
+        entry.day = day;
+        entry.used_today = 0;
+    }
+    let used = entry
+        .used_today
+        .checked_add(amount)
+        .ok_or_else(|| error!(ErrorCode::AllowlistDailyLimitExceeded))?;
+    require!(
+        entry.daily_limit == 0 || used <= entry.daily_limit,
+        ErrorCode::AllowlistDailyLimitExceeded
+    );
+    entry.used_today = used;
+    Ok(())
+}
+
+/// Per-transfer caps, where a `limit` of zero means uncapped.
+pub fn check_transfer_limit(amount: u64, limit: u64) -> Result<()> {
+    require!(limit == 0 || amount <= limit, ErrorCode::AmountExceedsLimit);
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+pub struct Signature {
+    r: [u8; 32],
+    s: [u8; 32],
+    v: u8,
+}
+
+/// Verifies an ECDSA signature against a message with the secp256k1 recover syscall.
+/// Validators are stored as the x-coordinate of their secp256k1 public key, so the
+/// recovered key matches if its first 32 bytes equal `validator_pubkey`.
+pub fn verify_signature(
+    signature: &Signature,
+    validator_pubkey: &Pubkey,
+    message_hash: &[u8; 32]
+) -> Result<bool> {
+    // Empty validator slots never sign
+    if *validator_pubkey == Pubkey::default() {
+        return Ok(false);
+    }
+
+    // Ethereum style v is 27 or 28, the syscall wants 0 or 1
+    let recovery_id = match signature.v.checked_sub(27) {
+        Some(id) if id <= 1 => id,
+        _ => return Ok(false),
+    };
+
+    let compact = [&signature.r[..], &signature.s[..]].concat();
+    let recovered = match secp256k1_recover(message_hash, recovery_id, &compact) {
+        Ok(recovered) => recovered,
+        Err(_) => return Ok(false),
+    };
+
+    Ok(recovered.to_bytes()[..32] == validator_pubkey.to_bytes())
+}
+
+/// Verifies multiple signatures against the same message.
+/// Ensures that the required number of valid signatures from validators is met.
+pub fn verify_signatures(
+    signatures: &[Signature],
+    validator_pubkeys: &[Pubkey],
+    message_hash: &[u8; 32],
+    required_signatures: u8,
+) -> Result<bool> {
+    let signer_bitmap = verify_signatures_bitmap(
+        signatures,
+        validator_pubkeys,
+        message_hash,
+        required_signatures,
+    )?;
+    // Ensure we have enough valid signatures
+    Ok(signer_bitmap.count_ones() >= required_signatures.into())
+}
+
+/// Same checks as `verify_signatures`, but returns which validators signed: bit `i` is set
+/// when `validator_pubkeys[i]` produced a valid signature.
+pub fn verify_signatures_bitmap(
+    signatures: &[Signature],
+    validator_pubkeys: &[Pubkey],
+    message_hash: &[u8; 32],
+    required_signatures: u8,
+) -> Result<u16> {
+    // Ensure we have enough signatures
+    require!(
+        signatures.len() >= required_signatures.into(),
+        ErrorCode::NotEnoughSignatures
+    );
     
-    // Parse the provided public key
-    let pubkey = PublicKey::from_slice(validator_pubkey.as_ref())
-        .map_err(|_| error!(ErrorCode::InvalidPublicKey))?;
+    // Ensure we have the correct number of validator public keys
+    require!(
+        signatures.len() == validator_pubkeys.len(),
+        ErrorCode::MismatchedSignaturesAndKeys
+    );
+    
+    // Keep track of used validator indexes to prevent duplicates
+    let mut used_validators = vec![false; validator_pubkeys.len()];
+    let mut signer_bitmap: u16 = 0;
+    
+    for (i, signature) in signatures.iter().enumerate() {
+        // Verify this signature
+        let is_valid = verify_signature(
+            signature,
+            &validator_pubkeys[i],
+            message_hash
+        )?;
+        
+        // If valid, mark this validator as used and increment counter
+        if is_valid {
+            // Make sure this validator hasn't already been used
+            require!(
+                !used_validators[i],
+                ErrorCode::DuplicateValidator
+            );
+            
+            used_validators[i] = true;
+            signer_bitmap |= 1 << i;
+        }
+    }
     
-    // Verify the signature
-    Ok(secp.verify_ecdsa(&message, &standard_sig, &pubkey).is_ok())
+    Ok(signer_bitmap)
+}
+
+// ========================================================================================================== //
+// Account Definitions                                                                                        //
+//   ▄████████  ▄████████  ▄████████     ███          ████████▄     ▄████████    ▄████████    ▄████████       //
+//   ███    ███ ███    ███ ███    ███ ▀█████████▄      ███   ▀███   ███    ███   ███    ███   ███    ███      //
+//   ███    ███ ███    █▀  ███    █▀     ▀███▀▀██      ███    ███   ███    █▀    ███    █▀    ███    █▀       //
+//   ███    ███ ███        ███            ███   ▀      ███    ███  ▄███▄▄▄      ▄███▄▄▄       ███             //
+// ▀███████████ ███        ███            ███          ███    ███ ▀▀███▀▀▀     ▀▀███▀▀▀     ▀███████████      //
+//   ███    ███ ███    █▄  ███    █▄      ███          ███    ███   ███    █▄    ███                 ███      //
+//   ███    ███ ███    ███ ███    ███     ███          ███   ▄███   ███    ███   ███           ▄█    ███      //
+//   ███    █▀  ████████▀  ████████▀     ▄████▀        ████████▀    ██████████   ███         ▄████████▀       //
+// ========================================================================================================== //  
+
+#[account]
+#[derive(InitSpace)]
+pub struct PoolState {
+    pub owner: Pubkey,
+    pub proposed_owner: Pubkey,
+    pub ownership_proposed_at: i64, // unix timestamp the pending proposal was made, 0 if none
+    pub ownership_transfer_window: i64, // seconds a proposal stays acceptable
+    pub usdc_mint: Pubkey, // the bridged token, a wrapped mint owned by this PDA in burn/mint mode
+    pub paused: bool,
+    pub mode: BridgeMode, // set at initialize, never changes
+    pub validators: [Pubkey; MAX_VALIDATORS],
+    pub validator_joined_slots: [u64; MAX_VALIDATORS], // slot each of `validators` joined the set, index-aligned
+    pub required_signatures: u8, // should match the initialized pubkeys in validators, used as iterator
+    pub validator_set_epoch: u64, // bumped on every validator set change, part of the signed withdrawal message
+    pub rotation_requires_approval: bool, // if set, the outgoing set's threshold must sign a rotation. Never unset again
+    pub validator_set_grace_period: i64, // seconds a replaced validator set can still attest withdrawals
+    pub heartbeat_stale_slots: u64, // slots without a heartbeat before a validator can be flagged stale
+    pub bond_unbonding_period: i64, // seconds an unbonding bond stays slashable before it can be withdrawn
+    pub tax: u16, // basis point tax on each transaction
+    pub max_tax: u16, // owner-set ceiling on `tax`, never above MAX_TAX_BPS and only ever lowered
+    pub total_deposited: u128, // every deposit ever, tax included
+    pub total_withdrawn: u128, // every attested withdrawal ever, relayer fee included
+    pub total_fees_collected: u128, // every deposit tax ever, LP cut included
+    pub deposit_count: u64,
+    pub withdrawal_count: u64,
+    pub accumulated_fees: u64,
+    pub deposit_sequence: u64, // next deposit's sequence number, part of its deposit id
+    pub pending_deposit_liabilities: u64, // principal of every deposit still `Pending`
+    pub deposit_processing_window: i64, // seconds the EVM side has to process a deposit
+    pub reclaim_buffer: i64, // seconds past a deposit's deadline before it can be reclaimed
+    pub refund_fees: bool, // whether refunds and reclaims also return the fee
+    pub max_deposit_amount: u64, // per-deposit cap, 0 for no cap
+    pub max_withdrawal_amount: u64, // per-withdrawal cap, 0 for no cap
+    pub max_native_drop: u64, // lamports a single withdrawal can drop, 0 disables drops
+    pub max_native_drop_per_recipient: u64, // lifetime lamports a single recipient can get from drops
+    pub total_liquidity: u64, // USDC in `pool_ata` owned by LP share holders, including their fee cut
+    pub lp_fee_share_bps: u16, // bps of each deposit's tax credited to `total_liquidity`
+    pub iou_head: u64, // sequence of the oldest unsettled WithdrawalIOU
+    pub iou_tail: u64, // sequence the next WithdrawalIOU gets, the queue is empty when head == tail
+    pub pending_iou_liabilities: u64, // owed by every unsettled WithdrawalIOU, queued or frozen, relayer fees included
+    pub cctp_token_messenger: Pubkey, // CCTP program deposits are burned through, default if disabled
+    pub cctp_message_transmitter: Pubkey, // CCTP program withdrawals are minted through, default if disabled
+    pub net_flow_threshold: u64, // net outflow within one window that trips the circuit breaker, 0 disables it
+    pub net_flow_window: i64, // seconds per net flow window
+    pub net_flow_window_start: i64, // unix timestamp the current window opened
+    pub net_flow_deposited: u64, // deposits in the current window, tax included
+    pub net_flow_withdrawn: u64, // withdrawals, refunds and reclaims in the current window, fees included
+    pub circuit_breaker_tripped: bool, // set alongside `paused` by the breaker, cleared when the owner unpauses
+    pub compliance_authority: Pubkey, // manages the denylist alongside the owner and the allowlist alone, default if there is none
+    pub permissioned: bool, // only wallets with a live AllowlistEntry can deposit or receive withdrawals
+    pub reserves_snapshot_interval: u64, // slots between two reserve snapshots
+    pub treasury: Pubkey,
+    pub bump: u8,
+}
+
+/// A validator set that was replaced, see `ValidatorSetSnapshot`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default)]
+pub struct PreviousValidatorSet {
+    pub validators: [Pubkey; MAX_VALIDATORS],
+    pub required_signatures: u8, // 0 for an empty or revoked slot
+    pub validator_set_epoch: u64, // the epoch these validators signed for
+    pub expires_at: i64, // unix timestamp after which their attestations are rejected
+}
+
+/// The last few validator sets that were live before a change, kept around so
+/// withdrawals they already signed can still land during the grace period.
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorSetSnapshot {
+    pub sets: [PreviousValidatorSet; VALIDATOR_SET_HISTORY_LEN],
+    pub bump: u8,
+}
+
+/// Per-validator liveness record, seeded by the validator key.
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorLiveness {
+    pub validator: Pubkey,
+    pub last_seen_slot: u64, // slot of the latest heartbeat, 0 if it never sent one
+    pub last_seen_timestamp: i64,
+    pub stale: bool, // set by `flag_stale_validator`, cleared by the next heartbeat
+    pub flagged_slot: u64, // slot it was last flagged stale at
+    pub bump: u8,
+}
+
+/// A validator's bonded stake, held in the shared `bond_vault` token account.
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorBond {
+    pub validator: Pubkey,
+    pub authority: Pubkey, // Solana wallet the validator named to fund, unbond and withdraw it
+    pub amount: u64, // how much of `bond_vault` belongs to this validator
+    pub unbonding_at: i64, // unix timestamp it can be withdrawn from, 0 if not unbonding
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BridgeMode {
+    LockUnlock, // deposits lock the token in `pool_ata`, withdrawals unlock it
+    BurnMint,   // deposits burn the wrapped token, withdrawals mint it
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DepositStatus {
+    Pending,   // waiting on the EVM side
+    Processed, // validators attested the mint happened
+    Refunded,  // principal went back to the depositor
+    Reclaimed, // depositor took it back after the deadline
+}
+
+/// On-chain trail of a single deposit, seeded by the depositor and their client nonce so
+/// deposits landing in the same slot never fight over one address.
+#[account]
+#[derive(InitSpace)]
+pub struct DepositRecord {
+    pub deposit_id: [u8; 32], // see `build_deposit_id`
+    pub sequence: u64,
+    pub client_nonce: u64, // the depositor's nonce from `DepositUSDCArgs`
+    pub depositor: Pubkey,
+    pub amount: u64, // after tax
+    pub tax: u64,
+    pub lp_fee: u64, // part of `tax` that went to LPs, never refunded
+    pub recipient_evm_address: [u8; 20],
+    pub destination_chain_id: u64,
+    pub target_contract: Option<[u8; 20]>, // EVM contract to call on arrival, if any
+    pub payload_hash: [u8; 32], // sha256 of the calldata, the calldata itself is only in the event
+    pub reference: Option<[u8; 32]>, // integrator reference id
+    pub status: DepositStatus,
+    pub slot: u64, // slot the deposit landed in
+    pub deadline: i64, // unix timestamp after which the EVM side won't process it, reclaimable `reclaim_buffer` later
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default)]
+pub struct ReservesSnapshotEntry {
+    pub sequence: u64, // how many snapshots came before this one
+    pub slot: u64,
+    pub timestamp: i64,
+    pub pool_balance: u64, // `pool_ata` balance
+    pub accumulated_fees: u64,
+    pub pending_deposit_liabilities: u64,
+    pub total_liquidity: u64, // part of the balance owned by LPs
+    pub pending_iou_liabilities: u64, // withdrawals attested but still queued or frozen
+    pub total_deposited: u128,
+    pub total_withdrawn: u128,
+    pub hash: [u8; 32], // see `build_reserves_snapshot_hash`
+}
+
+/// Ring buffer of the latest RESERVES_HISTORY_LEN reserve snapshots.
+#[account]
+#[derive(InitSpace)]
+pub struct ReservesHistory {
+    pub snapshots: [ReservesSnapshotEntry; RESERVES_HISTORY_LEN], // entry i is at `snapshots[i % RESERVES_HISTORY_LEN]`
+    pub count: u64, // snapshots ever taken
+    pub last_slot: u64,
+    pub last_hash: [u8; 32], // head of the hash chain, zeros before the first snapshot
+    pub bump: u8,
+}
+
+/// Lets one wallet bridge in a permissioned pool, seeded by the wallet.
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    pub wallet: Pubkey,
+    pub daily_limit: u64, // 0 for no limit
+    pub expires_at: i64, // unix timestamp, 0 for never
+    pub day: i64, // unix timestamp / SECONDS_PER_DAY of the last transfer
+    pub used_today: u64, // deposited and withdrawn on `day`
+    pub bump: u8,
+}
+
+/// Blocks one Solana or EVM address from bridging. Only its existence matters, deposits and
+/// withdrawals fail while the PDA for either end is initialized.
+#[account]
+#[derive(InitSpace)]
+pub struct DenylistEntry {
+    pub added_by: Pubkey, // owner or compliance authority that added it
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default)]
+pub struct DailyStatsEntry {
+    pub day: i64, // unix timestamp / SECONDS_PER_DAY
+    pub deposited: u128,
+    pub withdrawn: u128,
+    pub fees_collected: u128,
+    pub deposit_count: u64,
+    pub withdrawal_count: u64,
+}
+
+/// Per-day flow for the last DAILY_STATS_LEN days, so dashboards don't have to replay events.
+#[account]
+#[derive(InitSpace)]
+pub struct DailyStats {
+    pub days: [DailyStatsEntry; DAILY_STATS_LEN], // day d lives at `days[d % DAILY_STATS_LEN]`, check `day` before trusting it
+    pub bump: u8,
+}
+
+/// Marks a withdrawal attestation as consumed, seeded by its source chain and nonce.
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalRecord {
+    pub nonce: [u8; 32],
+    pub source_chain_id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64, // as signed, relayer fee included
+    pub slot: u64, // slot the attestation was submitted in
+    pub bump: u8,
 }
 
-/// Verifies multiple signatures against the same message.
-/// Ensures that the required number of valid signatures from validators is met.
-pub fn verify_signatures(
-    signatures: &[Signature],
-    validator_pubkeys: &[Pubkey],
-    message_hash: &[u8; 32],
-    required_signatures: u8,
-) -> Result<bool> {
-    // Ensure we have enough signatures
-    require!(
-        signatures.len() >= required_signatures.into(),
-        ErrorCode::NotEnoughSignatures
-    );
-    
-    // Ensure we have the correct number of validator public keys
-    require!(
-        signatures.len() == validator_pubkeys.len(),
-        ErrorCode::MismatchedSignaturesAndKeys
-    );
-    
-    // Keep track of used validator indexes to prevent duplicates
-    let mut used_validators = vec![false; validator_pubkeys.len()];
-    let mut valid_signatures = 0;
-    
-    for (i, signature) in signatures.iter().enumerate() {
-        // Verify this signature
-        let is_valid = verify_signature(
-            signature,
-            &validator_pubkeys[i],
-            message_hash
-        )?;
-        
-        // If valid, mark this validator as used and increment counter
-        if is_valid {
-            // Make sure this validator hasn't already been used
-            require!(
-                !used_validators[i],
-                ErrorCode::DuplicateValidator
-            );
-            
-            used_validators[i] = true;
-            valid_signatures += 1;
-        }
-    }
-    
-    // Ensure we have enough valid signatures
-    Ok(valid_signatures >= required_signatures)
+/// A verified withdrawal the pool couldn't pay yet, seeded by its place in the queue.
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalIOU {
+    pub sequence: u64, // position in the FIFO queue, see `iou_head`/`iou_tail`
+    pub recipient: Pubkey,
+    pub amount: u64, // owed to the recipient, relayer fee already taken out
+    pub relayer_fee: u64, // owed to `payer`
+    pub payer: Pubkey, // the relayer that submitted it, gets the fee and the rent back
+    pub nonce: [u8; 32],
+    pub source_chain_id: u64,
+    pub created_at: i64,
+    pub frozen: bool, // recipient was denied when it reached the head, set aside until released
+    pub bump: u8,
 }
 
-// ========================================================================================================== //
-// Account Definitions                                                                                        //
-//   ▄████████  ▄████████  ▄████████     ███          ████████▄     ▄████████    ▄████████    ▄████████       //
-//   ███    ███ ███    ███ ███    ███ ▀█████████▄      ███   ▀███   ███    ███   ███    ███   ███    ███      //
-//   ███    ███ ███    █▀  ███    █▀     ▀███▀▀██      ███    ███   ███    █▀    ███    █▀    ███    █▀       //
-//   ███    ███ ███        ███            ███   ▀      ███    ███  ▄███▄▄▄      ▄███▄▄▄       ███             //
-// ▀███████████ ███        ███            ███          ███    ███ ▀▀███▀▀▀     ▀▀███▀▀▀     ▀███████████      //
-//   ███    ███ ███    █▄  ███    █▄      ███          ███    ███   ███    █▄    ███                 ███      //
-//   ███    ███ ███    ███ ███    ███     ███          ███   ▄███   ███    ███   ███           ▄█    ███      //
-//   ███    █▀  ████████▀  ████████▀     ▄████▀        ████████▀    ██████████   ███         ▄████████▀       //
-// ========================================================================================================== //  
+/// Lamports set aside for native drops. Fees accrue in USDC, so the owner converts
+/// and tops this up through `fund_sol_reserve`, everything above rent can be dropped.
+#[account]
+#[derive(InitSpace)]
+pub struct SolReserve {
+    pub total_funded: u64, // lamports ever put in through `fund_sol_reserve`
+    pub total_dropped: u64, // lamports ever sent out to recipients
+    pub bump: u8,
+}
 
+/// How much a single recipient has received in native drops, seeded by the recipient.
 #[account]
 #[derive(InitSpace)]
-pub struct PoolState {
-    pub owner: Pubkey,
-    pub proposed_owner: Pubkey,
-    pub usdc_mint: Pubkey,
-    pub paused: bool,
-    pub validators: [Pubkey; MAX_VALIDATORS],
-    pub required_signatures: u8, // should match the initialized pubkeys in validators, used as iterator
-    pub tax: u16, // basis point tax on each transaction
-    pub total_volume: u64,
-    pub accumulated_fees: u64,
-    pub treasury: Pubkey,
+pub struct NativeDropReceipt {
+    pub recipient: Pubkey,
+    pub total_dropped: u64, // checked against `max_native_drop_per_recipient`
     pub bump: u8,
 }
 
@@ -780,7 +4537,14 @@ pub struct PoolState {
 pub struct PoolCreated {
     pub address: Pubkey, // the pool state/config address
     pub treasury: Pubkey, // the treasury address
+    pub usdc_mint: Pubkey, // the token this pool bridges
+    pub mode: BridgeMode, // lock/unlock or burn/mint
+    pub permissioned: bool, // whether only allowlisted wallets can bridge
     pub tax: u16, // bps fee for transfers
+    pub max_tax: u16, // bps ceiling on the fee
+    pub required_signatures: u8, // the initial validator threshold
+    pub max_deposit_amount: u64, // per-deposit cap, 0 for no cap
+    pub max_withdrawal_amount: u64, // per-withdrawal cap, 0 for no cap
     pub timestamp: i64, // when the shit happened
 }
 
@@ -800,13 +4564,175 @@ pub struct ValidatorRemoved {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ValidatorSetRotated {
+    pub pool_state: Pubkey, // the pool state whose validators were replaced
+    pub previous_validators: Vec<Pubkey>, // the outgoing set
+    pub previous_required_signatures: u8, // the outgoing threshold
+    pub validators: Vec<Pubkey>, // the incoming set
+    pub required_signatures: u8, // the incoming threshold
+    pub validator_set_epoch: u64, // the epoch withdrawals must now be signed for
+    pub previous_set_expires_at: i64, // when the outgoing set stops being accepted
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorSetSnapshotRevoked {
+    pub pool_state: Pubkey, // the pool state the set signed for
+    pub validator_set_epoch: u64, // the previous set that no longer verifies
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorHeartbeat {
+    pub pool_state: Pubkey, // the pool state the validator signs for
+    pub validator: Pubkey, // who checked in
+    pub slot: u64, // when they checked in
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorStale {
+    pub pool_state: Pubkey, // the pool state the validator signs for
+    pub validator: Pubkey, // who went quiet
+    pub last_seen_slot: u64, // their last heartbeat, 0 if never
+    pub slots_since_seen: u64, // how long they've been gone
+    pub flagged_by: Pubkey, // who noticed
+    pub removed: bool, // whether it was taken out of the live set, false if it was the last one
+    pub required_signatures: u8, // threshold after removal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorBonded {
+    pub pool_state: Pubkey, // the pool state the validator signs for
+    pub validator: Pubkey, // who the bond backs
+    pub authority: Pubkey, // who put it up
+    pub amount: u64, // how much was added
+    pub total_bond: u64, // the bond after this deposit
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorUnbonding {
+    pub pool_state: Pubkey, // the pool state the validator signed for
+    pub validator: Pubkey, // whose bond is unbonding
+    pub amount: u64, // the bond, still slashable until withdrawn
+    pub withdrawable_at: i64, // when the authority can take it back
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorBondWithdrawn {
+    pub pool_state: Pubkey, // the pool state the validator signed for
+    pub validator: Pubkey, // whose bond it was
+    pub authority: Pubkey, // who got it back
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorSlashed {
+    pub pool_state: Pubkey, // the pool state the validator signed for
+    pub validator: Pubkey, // who equivocated
+    pub amount: u64, // how much bond went to the insurance fund
+    pub nonce: [u8; 32], // the nonce they double-signed
+    pub reporter: Pubkey, // who submitted the proof
+    pub removed: bool, // whether it was in the live set and got dropped from it
+    pub required_signatures: u8, // the live threshold afterwards
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct PoolStateUpdated {
     pub address: Pubkey, // the pool state being updated
-    pub proposed_owner: Pubkey, // the new proposed_owner. If not default, a new owner has been proposed
     pub treasury: Pubkey, // the current treasury after the update. May not have changed.
     pub tax: u16, // the tax in bps after the update. May not have changed.
+    pub max_tax: u16, // the tax ceiling in bps after the update. May not have changed.
     pub paused: bool, // the pause state. May not have changed.
+    pub rotation_requires_approval: bool, // whether rotations need the outgoing set. May not have changed.
+    pub validator_set_grace_period: i64, // seconds the previous set stays valid. May not have changed.
+    pub heartbeat_stale_slots: u64, // slots before a validator counts as stale. May not have changed.
+    pub bond_unbonding_period: i64, // seconds before an unbonding bond can be withdrawn. May not have changed.
+    pub deposit_processing_window: i64, // seconds the EVM side has to process a deposit. May not have changed.
+    pub reclaim_buffer: i64, // seconds past the deadline before a reclaim. May not have changed.
+    pub refund_fees: bool, // whether refunds return the fee. May not have changed.
+    pub ownership_transfer_window: i64, // the acceptance window in seconds. May not have changed.
+    pub max_deposit_amount: u64, // the per-deposit cap. May not have changed.
+    pub max_withdrawal_amount: u64, // the per-withdrawal cap. May not have changed.
+    pub max_native_drop: u64, // the per-withdrawal native drop cap. May not have changed.
+    pub max_native_drop_per_recipient: u64, // the lifetime per-recipient drop cap. May not have changed.
+    pub lp_fee_share_bps: u16, // the LPs' share of the tax. May not have changed.
+    pub cctp_token_messenger: Pubkey, // the CCTP burn program. May not have changed.
+    pub cctp_message_transmitter: Pubkey, // the CCTP mint program. May not have changed.
+    pub net_flow_threshold: u64, // the circuit breaker threshold. May not have changed.
+    pub net_flow_window: i64, // the circuit breaker window in seconds. May not have changed.
+    pub compliance_authority: Pubkey, // the denylist and allowlist manager. May not have changed.
+    pub permissioned: bool, // whether only allowlisted wallets can bridge. May not have changed.
+    pub reserves_snapshot_interval: u64, // slots between reserve snapshots. May not have changed.
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowlistEntrySet {
+    pub address: Pubkey, // the pool state
+    pub wallet: Pubkey, // the wallet that can now bridge
+    pub daily_limit: u64, // 0 for no limit
+    pub expires_at: i64, // 0 for never
+    pub authority: Pubkey, // the compliance authority that set it
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowlistEntryRemoved {
+    pub address: Pubkey, // the pool state
+    pub wallet: Pubkey, // the wallet that can no longer bridge
+    pub authority: Pubkey, // the compliance authority that removed it
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DenylistEntryAdded {
+    pub address: Pubkey, // the pool state
+    pub solana_address: Option<Pubkey>, // set if a Solana address was denied
+    pub evm_address: Option<[u8; 20]>, // set if an EVM address was denied
+    pub authority: Pubkey, // owner or compliance authority that added it
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DenylistEntryRemoved {
+    pub address: Pubkey, // the pool state
+    pub solana_address: Option<Pubkey>, // set if a Solana address was let back in
+    pub evm_address: Option<[u8; 20]>, // set if an EVM address was let back in
+    pub authority: Pubkey, // owner or compliance authority that removed it
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub address: Pubkey, // the pool state that was paused
+    pub window_start: i64, // unix timestamp the window that tripped it opened
+    pub deposited: u64, // deposits in that window
+    pub withdrawn: u64, // withdrawals in that window, including the one that tripped it
+    pub net_flow: u64, // withdrawn minus deposited
+    pub threshold: u64, // the threshold it went over
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferProposed {
+    pub address: Pubkey, // the pool state being handed over
+    pub current_owner: Pubkey, // who proposed it
+    pub proposed_owner: Pubkey, // who has to accept it
+    pub expires_at: i64, // after this the proposal can no longer be accepted
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferCancelled {
+    pub address: Pubkey, // the pool state that is no longer being handed over
+    pub cancelled_owner: Pubkey, // the proposed owner that got dropped
     pub timestamp: i64,
 }
 
@@ -825,14 +4751,180 @@ pub struct USDCDeposited {
     pub amount: u64, // the amount of USDC deposited,
     pub tax: u64, // the amount of USDC taxed,
     pub nonce: u64, // a unique hash for this transaction
+    pub deposit_id: [u8; 32], // the id of the DepositRecord tracking this deposit
+    pub deadline: i64, // the EVM side must not process it after this
+    pub target_contract: Option<[u8; 20]>, // EVM contract the executor calls on arrival
+    pub payload: Vec<u8>, // calldata for `target_contract`
+    pub reference: Option<[u8; 32]>, // integrator reference id
+    pub sequence: u64, // the deposit's sequence number, global deposit order
+    pub client_nonce: u64, // seeds the DepositRecord together with the depositor
+    pub destination_chain_id: u64, // which EVM chain it's headed to
+    pub slot: u64, // slot the deposit landed in
     pub timestamp: i64
 }
 
+#[event]
+pub struct CctpDepositBurned {
+    pub address: Pubkey, // the state account the deposit was made to
+    pub depositor: Pubkey, // the Solana account that deposited
+    pub recipient_evm_address: [u8; 20], // who Circle mints to
+    pub amount: u64, // the amount burned, after tax
+    pub tax: u64, // the amount kept in the pool
+    pub deposit_id: [u8; 32], // the id of the DepositRecord tracking this deposit
+    pub reference: Option<[u8; 32]>, // integrator reference id
+    pub sequence: u64, // the deposit's sequence number, global deposit order
+    pub client_nonce: u64, // seeds the DepositRecord together with the depositor
+    pub destination_chain_id: u64, // which EVM chain it's headed to
+    pub destination_domain: u32, // the CCTP domain of that chain
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DepositProcessed {
+    pub address: Pubkey, // the state account the deposit was made to
+    pub deposit_id: [u8; 32], // which deposit got bridged
+    pub sequence: u64, // its DepositRecord sequence
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DepositRefunded {
+    pub address: Pubkey, // the state account the deposit was made to
+    pub deposit_id: [u8; 32], // which deposit got refunded
+    pub sequence: u64, // its DepositRecord sequence
+    pub depositor: Pubkey, // who got their money back
+    pub amount: u64, // how much they got back
+    pub fee_refunded: u64, // how much of that was the fee
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DepositReclaimed {
+    pub address: Pubkey, // the state account the deposit was made to
+    pub deposit_id: [u8; 32], // which deposit expired
+    pub sequence: u64, // its DepositRecord sequence
+    pub depositor: Pubkey, // who took their money back
+    pub amount: u64, // how much they got back
+    pub fee_refunded: u64, // how much of that was the fee
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct USDCWithdrawn {
     pub address: Pubkey, // the state account the USDC ATA is derived from,
     pub recipient: Pubkey, // who got they money
     pub amount: u64, // how much they got paid
+    pub relayer_fee: u64, // how much the payer got for relaying it
+    pub reference: Option<[u8; 32]>, // integrator reference id
+    pub nonce: [u8; 32], // the EVM-side nonce, matches the deposit over there
+    pub sender_evm_address: [u8; 20], // who sent it on the EVM side
+    pub source_chain_id: u64, // which EVM chain it came from
+    pub validator_set_epoch: u64, // which validator set attested
+    pub signer_bitmap: u16, // bit i set if validators[i] of that set signed
+    pub payer: Pubkey, // the relayer that submitted it
+    pub native_drop: u64, // lamports actually dropped, can be under the signed amount once caps kick in
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub pool_state: Pubkey,
+    pub provider: Pubkey, // the LP
+    pub amount: u64, // USDC put in
+    pub shares: u64, // LP shares minted for it
+    pub total_liquidity: u64, // LP-owned USDC after the deposit
+    pub total_shares: u64, // LP share supply after the mint
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub pool_state: Pubkey,
+    pub provider: Pubkey, // the LP
+    pub amount: u64, // USDC taken out
+    pub shares: u64, // LP shares burned for it
+    pub total_liquidity: u64, // LP-owned USDC after the withdrawal
+    pub total_shares: u64, // LP share supply after the burn
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReservesSnapshot {
+    pub pool_state: Pubkey,
+    pub sequence: u64, // position in the hash chain
+    pub slot: u64, // slot the figures were read at
+    pub pool_balance: u64, // `pool_ata` balance
+    pub accumulated_fees: u64, // part of the balance owed to the treasury
+    pub pending_deposit_liabilities: u64, // deposits not yet processed, refunded or reclaimed
+    pub total_liquidity: u64, // part of the balance owned by LPs
+    pub pending_iou_liabilities: u64, // withdrawals attested but still queued or frozen
+    pub total_deposited: u128, // lifetime deposits, tax included
+    pub total_withdrawn: u128, // lifetime withdrawals, relayer fee included
+    pub previous_hash: [u8; 32], // hash of the snapshot before, zeros for the first
+    pub hash: [u8; 32], // this snapshot's hash, see `build_reserves_snapshot_hash`
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SolReserveFunded {
+    pub pool_state: Pubkey,
+    pub funder: Pubkey, // who topped it up
+    pub amount: u64, // lamports added
+    pub balance: u64, // lamports now available for drops
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalQueued {
+    pub address: Pubkey, // the state account the USDC ATA is derived from,
+    pub sequence: u64, // the IOU's place in the queue
+    pub recipient: Pubkey, // who is owed the money
+    pub amount: u64, // how much they will get paid
+    pub relayer_fee: u64, // how much the payer will get for relaying it
+    pub reference: Option<[u8; 32]>, // integrator reference id
+    pub nonce: [u8; 32], // the EVM-side nonce, matches the deposit over there
+    pub sender_evm_address: [u8; 20], // who sent it on the EVM side
+    pub source_chain_id: u64, // which EVM chain it came from
+    pub validator_set_epoch: u64, // which validator set attested
+    pub signer_bitmap: u16, // bit i set if validators[i] of that set signed
+    pub payer: Pubkey, // the relayer that submitted it
+    pub native_drop: u64, // lamports dropped, these don't wait for liquidity
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalIOUSettled {
+    pub address: Pubkey, // the state account the USDC ATA is derived from,
+    pub sequence: u64, // the IOU's place in the queue
+    pub recipient: Pubkey, // who got they money
+    pub amount: u64, // how much they got paid
+    pub relayer_fee: u64, // how much the original relayer got
+    pub nonce: [u8; 32], // the EVM-side nonce of the withdrawal
+    pub settler: Pubkey, // who pushed it through
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalIOUFrozen {
+    pub address: Pubkey, // the state account the USDC ATA is derived from,
+    pub sequence: u64, // the IOU's place in the queue
+    pub recipient: Pubkey, // the denied recipient
+    pub amount: u64, // still owed to the recipient
+    pub relayer_fee: u64, // still owed to the original relayer
+    pub nonce: [u8; 32], // the EVM-side nonce of the withdrawal
+    pub settler: Pubkey, // who moved the queue past it
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalCallExecuted {
+    pub address: Pubkey, // the state account the USDC ATA is derived from,
+    pub recipient: Pubkey, // who got the tokens before the call
+    pub nonce: [u8; 32], // the EVM-side nonce of the withdrawal
+    pub target_program: Pubkey, // the program that got called
+    pub data_hash: [u8; 32], // sha256 of the instruction data it got
+    pub accounts_hash: [u8; 32], // see `hash_account_metas`
     pub timestamp: i64,
 }
 
@@ -903,4 +4995,217 @@ pub enum ErrorCode {
 
     #[msg("Failed to validate withdrawal")]
     FailedToValidate,
+
+    #[msg("No ownership transfer is pending.")]
+    NoPendingOwnershipTransfer,
+
+    #[msg("The ownership transfer proposal has expired.")]
+    OwnershipTransferExpired,
+
+    #[msg("Ownership transfer window must be greater than zero.")]
+    InvalidOwnershipTransferWindow,
+
+    #[msg("Only the upgrade authority or the authorized launcher can initialize the pool.")]
+    UnauthorizedLauncher,
+
+    #[msg("Treasury cannot be the default pubkey.")]
+    InvalidTreasury,
+
+    #[msg("Required signatures must be between 1 and the number of validators.")]
+    InvalidThreshold,
+
+    #[msg("Amount exceeds the configured per-transfer limit.")]
+    AmountExceedsLimit,
+
+    #[msg("Validator set epoch overflowed.")]
+    EpochOverflow,
+
+    #[msg("Validator set grace period cannot be negative.")]
+    InvalidGracePeriod,
+
+    #[msg("Attestation is for a validator set epoch that is neither current nor previous.")]
+    UnknownValidatorSetEpoch,
+
+    #[msg("Attestation is for a previous validator set whose grace period has ended.")]
+    ValidatorSetExpired,
+
+    #[msg("Heartbeat stale window must be greater than zero.")]
+    InvalidStaleWindow,
+
+    #[msg("Validator has sent a heartbeat within the stale window.")]
+    ValidatorNotStale,
+
+    #[msg("Bond amount must be greater than zero.")]
+    ZeroBond,
+
+    #[msg("Validator bond overflowed.")]
+    BondOverflow,
+
+    #[msg("Attestations do not prove equivocation by this validator.")]
+    InvalidEquivocationProof,
+
+    #[msg("Deposit sequence overflowed.")]
+    DepositSequenceOverflow,
+
+    #[msg("Deposit has already been processed or refunded.")]
+    DepositNotPending,
+
+    #[msg("Deposit processing window must be greater than zero.")]
+    InvalidProcessingWindow,
+
+    #[msg("Deposit has not passed its processing deadline plus the reclaim buffer.")]
+    DepositNotExpired,
+
+    #[msg("Only the depositor can reclaim this deposit.")]
+    NotDepositor,
+
+    #[msg("Deposit payload is too large.")]
+    PayloadTooLarge,
+
+    #[msg("Deposit payload needs a target contract.")]
+    PayloadWithoutTarget,
+
+    #[msg("Withdraw-and-call target program is missing, not executable, or not allowed.")]
+    InvalidCallTarget,
+
+    #[msg("Relayer fee is larger than the withdrawal.")]
+    RelayerFeeTooHigh,
+
+    #[msg("Relayer fee needs the payer's token account.")]
+    MissingRelayerAccount,
+
+    #[msg("Recipient wallet does not match the signed recipient.")]
+    InvalidRecipient,
+
+    #[msg("Native drop needs the SOL reserve and the recipient's drop receipt.")]
+    MissingNativeDropAccounts,
+
+    #[msg("SOL reserve funding must be greater than zero.")]
+    ZeroReserveFunding,
+
+    #[msg("Liquidity amount is zero or too small for a single share.")]
+    ZeroLiquidity,
+
+    #[msg("Pool liquidity overflowed.")]
+    LiquidityOverflow,
+
+    #[msg("LP fee share cannot exceed 10000 bps.")]
+    InvalidLpFeeShare,
+
+    #[msg("Pool does not have enough free liquidity.")]
+    InsufficientLiquidity,
+
+    #[msg("Withdrawal can be paid right away and does not need an IOU.")]
+    WithdrawalIouNotNeeded,
+
+    #[msg("Queued withdrawals have to be settled first.")]
+    WithdrawalsQueued,
+
+    #[msg("Withdrawal IOU sequence overflowed.")]
+    IouSequenceOverflow,
+
+    #[msg("Burn/mint pools need the pool to be the mint authority of the wrapped token.")]
+    InvalidMintAuthority,
+
+    #[msg("Not available in this pool's bridge mode.")]
+    WrongBridgeMode,
+
+    #[msg("CCTP is not configured for this pool.")]
+    CctpNotConfigured,
+
+    #[msg("CCTP program is missing, not executable, or not the configured one.")]
+    InvalidCctpProgram,
+
+    #[msg("CCTP deposits can't carry a contract call.")]
+    CctpCallNotSupported,
+
+    #[msg("CCTP minted a different amount than the attested withdrawal.")]
+    CctpMintMismatch,
+
+    #[msg("Reserves were snapshotted less than the snapshot interval ago.")]
+    SnapshotTooSoon,
+
+    #[msg("Net flow window must be positive.")]
+    InvalidNetFlowWindow,
+
+    #[msg("Address is on the denylist.")]
+    AddressDenied,
+
+    #[msg("Only the owner or the compliance authority can do this.")]
+    UnauthorizedCompliance,
+
+    #[msg("Only the compliance authority can do this.")]
+    NotComplianceAuthority,
+
+    #[msg("Wallet is not on this permissioned pool's allowlist.")]
+    NotAllowlisted,
+
+    #[msg("Wallet's allowlist entry has expired.")]
+    AllowlistEntryExpired,
+
+    #[msg("Transfer is over the wallet's daily allowlist limit.")]
+    AllowlistDailyLimitExceeded,
+
+    #[msg("Allowlist expiry must be in the future, or 0 for never.")]
+    InvalidAllowlistExpiry,
+
+    #[msg("Rotation approval can't be switched off once it is on.")]
+    RotationApprovalLocked,
+
+    #[msg("Heartbeat slot must be newer than the last one and not in the future.")]
+    InvalidHeartbeatSlot,
+
+    #[msg("Heartbeat was signed too many slots ago.")]
+    HeartbeatTooOld,
+
+    #[msg("Bond unbonding period must be positive.")]
+    InvalidUnbondingPeriod,
+
+    #[msg("Signer is not this bond's authority.")]
+    NotBondAuthority,
+
+    #[msg("Bond is already unbonding.")]
+    BondUnbonding,
+
+    #[msg("Validator is still in the live set.")]
+    ValidatorStillActive,
+
+    #[msg("Bond isn't unbonding or its unbonding period isn't over.")]
+    BondStillLocked,
+
+    #[msg("Validator has no bond and is not in the live set.")]
+    NothingToSlash,
+
+    #[msg("Reclaim buffer must be greater than zero.")]
+    InvalidReclaimBuffer,
+
+    #[msg("Cannot redeem more LP shares than exist.")]
+    InsufficientShares,
+
+    #[msg("Destination chain has no CCTP domain.")]
+    CctpUnsupportedChain,
+
+    #[msg("Reserves snapshot interval must be greater than zero.")]
+    InvalidSnapshotInterval,
+
+    #[msg("Compliance authority and owner must be different keys.")]
+    ComplianceAuthorityIsOwner,
+
+    #[msg("Tax is above the fee ceiling.")]
+    TaxTooHigh,
+
+    #[msg("Fee ceiling can only be lowered, and never above the protocol maximum.")]
+    TaxCeilingTooHigh,
+
+    #[msg("Only the pool's own token account can be handed to the CCTP program.")]
+    InvalidCctpTokenAccount,
+
+    #[msg("CCTP burned a different amount than the deposit.")]
+    CctpBurnMismatch,
+
+    #[msg("Withdrawal IOU is not frozen.")]
+    IouNotFrozen,
+
+    #[msg("Validator has already been flagged stale.")]
+    ValidatorAlreadyStale,
 }
\ No newline at end of file
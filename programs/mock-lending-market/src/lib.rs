@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("E2XX1RciS9FvHRVKk8dq88TF3cBSMVx1uw7XYsTB4qaK");
+
+/// Stands in for a lending market that takes bridge arrivals on localnet. Withdrawals are paid to
+/// its `custody` PDA, and the call that follows moves them into the market's vault and credits
+/// the beneficiary's position. Never deploy this anywhere that matters.
+#[program]
+pub mod mock_lending_market {
+    use super::*;
+
+    pub fn deposit(ctx: Context<Deposit>, params: DepositParams) -> Result<()> {
+        // the market signs for its own custody, the bridge never has to
+        let bump = ctx.bumps.custody;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"custody", &[bump]]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.custody_ata.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.custody.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(transfer_ctx, params.amount)?;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = params.beneficiary;
+        position.deposited = position
+            .deposited
+            .checked_add(params.amount)
+            .ok_or_else(|| error!(MockError::Overflow))?;
+        position.bump = ctx.bumps.position;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(params: DepositParams)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: PDA the bridge pays arrivals to
+    #[account(
+        seeds = [b"custody"],
+        bump,
+    )]
+    pub custody: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = custody,
+    )]
+    pub custody_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"vault"],
+        bump,
+        token::mint = mint,
+        token::authority = custody,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Position::INIT_SPACE,
+        seeds = [b"position", params.beneficiary.as_ref()],
+        bump
+    )]
+    pub position: Account<'info, Position>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DepositParams {
+    pub amount: u64,
+    pub beneficiary: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Position {
+    pub owner: Pubkey,
+    pub deposited: u64,
+    pub bump: u8,
+}
+
+#[error_code]
+pub enum MockError {
+    #[msg("Position overflowed.")]
+    Overflow,
+}
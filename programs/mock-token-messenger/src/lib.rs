@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, mint_to, Burn, Mint, MintTo, Token, TokenAccount};
+
+declare_id!("3HFsi8AnhzfJsaeETu7gNdJghp1AUEvcv5tQ1fYp1uFL");
+
+// CCTP v1 message layout, a 116 byte header followed by the burn message body
+pub const SOURCE_DOMAIN_OFFSET: usize = 4;
+pub const NONCE_OFFSET: usize = 12;
+pub const MINT_RECIPIENT_OFFSET: usize = 116 + 4 + 32;
+pub const AMOUNT_OFFSET: usize = MINT_RECIPIENT_OFFSET + 32;
+pub const MESSAGE_LEN: usize = AMOUNT_OFFSET + 32 + 32;
+
+/// Stands in for CCTP's token messenger and message transmitter on localnet. Same instruction
+/// names and params, so the bridge's CPI works unchanged, but the accounts are trimmed down and
+/// attestations are not checked. Never deploy this anywhere that matters.
+#[program]
+pub mod mock_token_messenger {
+    use super::*;
+
+    pub fn deposit_for_burn(ctx: Context<DepositForBurn>, params: DepositForBurnParams) -> Result<()> {
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.burn_token_mint.to_account_info(),
+                from: ctx.accounts.burn_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        );
+        burn(burn_ctx, params.amount)?;
+        emit!(DepositForBurnEvent {
+            burn_token: ctx.accounts.burn_token_mint.key(),
+            amount: params.amount,
+            depositor: ctx.accounts.owner.key(),
+            mint_recipient: params.mint_recipient,
+            destination_domain: params.destination_domain,
+        });
+        Ok(())
+    }
+
+    pub fn receive_message(ctx: Context<ReceiveMessage>, params: ReceiveMessageParams) -> Result<()> {
+        let message = &params.message;
+        require!(message.len() == MESSAGE_LEN, MockError::MalformedMessage);
+        let mint_recipient = Pubkey::try_from(&message[MINT_RECIPIENT_OFFSET..AMOUNT_OFFSET])
+            .map_err(|_| error!(MockError::MalformedMessage))?;
+        require!(
+            mint_recipient == ctx.accounts.mint_recipient.key(),
+            MockError::WrongMintRecipient
+        );
+        // the amount is a uint256, anything that doesn't fit the low 8 bytes is rejected
+        require!(
+            message[AMOUNT_OFFSET..AMOUNT_OFFSET + 24].iter().all(|b| *b == 0),
+            MockError::MalformedMessage
+        );
+        let mut amount_bytes = [0u8; 8];
+        amount_bytes.copy_from_slice(&message[AMOUNT_OFFSET + 24..AMOUNT_OFFSET + 32]);
+        let amount = u64::from_be_bytes(amount_bytes);
+
+        let bump = ctx.bumps.minter;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"minter", &[bump]]];
+        let mint_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.mint_recipient.to_account_info(),
+                authority: ctx.accounts.minter.to_account_info(),
+            },
+            signer_seeds,
+        );
+        mint_to(mint_ctx, amount)?;
+        ctx.accounts.used_nonce.bump = ctx.bumps.used_nonce;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct DepositForBurn<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = burn_token_mint,
+        token::authority = owner,
+    )]
+    pub burn_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub burn_token_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ReceiveMessageParams)]
+pub struct ReceiveMessage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // whoever relays the message, the bridge's pool PDA in practice
+    pub caller: Signer<'info>,
+
+    // replays fail here, like CCTP's used nonces
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UsedNonce::INIT_SPACE,
+        seeds = [
+            b"used_nonce",
+            params.message.get(SOURCE_DOMAIN_OFFSET..SOURCE_DOMAIN_OFFSET + 4).unwrap_or_default(),
+            params.message.get(NONCE_OFFSET..NONCE_OFFSET + 8).unwrap_or_default(),
+        ],
+        bump
+    )]
+    pub used_nonce: Account<'info, UsedNonce>,
+
+    /// CHECK: PDA the test mint's authority is handed to
+    #[account(
+        seeds = [b"minter"],
+        bump,
+    )]
+    pub minter: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub mint_recipient: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DepositForBurnParams {
+    pub amount: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReceiveMessageParams {
+    pub message: Vec<u8>,
+    pub attestation: Vec<u8>, // ignored by the mock
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UsedNonce {
+    pub bump: u8,
+}
+
+#[event]
+pub struct DepositForBurnEvent {
+    pub burn_token: Pubkey,
+    pub amount: u64,
+    pub depositor: Pubkey,
+    pub mint_recipient: Pubkey,
+    pub destination_domain: u32,
+}
+
+#[error_code]
+pub enum MockError {
+    #[msg("Message is not a CCTP v1 burn message.")]
+    MalformedMessage,
+
+    #[msg("Message mints to a different token account.")]
+    WrongMintRecipient,
+}
@@ -67,6 +67,12 @@ export class BridgeEventListener {
 									amount: new BN(data.amount.toString()),
 									tax: new BN(data.tax.toString()),
 									nonce: new BN(data.nonce.toString()),
+									sequence: new BN(data.sequence.toString()),
+									clientNonce: new BN(data.client_nonce.toString()),
+									destinationChainId: new BN(
+										data.destination_chain_id.toString()
+									),
+									slot: new BN(data.slot.toString()),
 									timestamp: data.timestamp,
 								},
 								signature: logs.signature,
@@ -115,6 +121,15 @@ export class BridgeEventListener {
 									address: data.address.toString(),
 									recipient: data.recipient.toString(),
 									amount: new BN(data.amount.toString()),
+									relayerFee: new BN(data.relayer_fee.toString()),
+									nonce: "0x" + Buffer.from(data.nonce).toString("hex"),
+									senderEvmAddress:
+										"0x" +
+										Buffer.from(data.sender_evm_address).toString("hex"),
+									sourceChainId: new BN(data.source_chain_id.toString()),
+									signerBitmap: data.signer_bitmap,
+									payer: data.payer.toString(),
+									nativeDrop: new BN(data.native_drop.toString()),
 									timestamp: data.timestamp,
 								},
 								signature: logs.signature,
@@ -221,6 +236,12 @@ export class BridgeEventListener {
 								amount: new BN(data.amount.toString()),
 								tax: new BN(data.tax.toString()),
 								nonce: new BN(data.nonce.toString()),
+								sequence: new BN(data.sequence.toString()),
+								clientNonce: new BN(data.client_nonce.toString()),
+								destinationChainId: new BN(
+									data.destination_chain_id.toString()
+								),
+								slot: new BN(data.slot.toString()),
 								timestamp: data.timestamp,
 							},
 							signature: signatureInfo.signature,
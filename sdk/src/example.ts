@@ -34,7 +34,11 @@ const exampleUsage = async () => {
 			"0x742d35Cc6634C0532925a3b844Bc454e4438f44e"
 		);
 
-		const depositTx = await bridgeSDK.depositUSDC(amount, evmAddress);
+		const depositTx = await bridgeSDK.depositUSDC(
+			amount,
+			evmAddress,
+			new BN(1) // Ethereum mainnet
+		);
 		console.log("Deposit transaction successful:", depositTx);
 	} catch (error) {
 		console.error("Deposit failed:", error);
@@ -77,6 +81,10 @@ const exampleUsage = async () => {
 			senderEvmAddress,
 			recipient,
 			nonce,
+			new BN(0), // validator set epoch the signatures were made for
+			new BN(1), // source chain, Ethereum mainnet
+			new BN(0), // relayer fee, self-relayed
+			new BN(0), // native drop, recipient already has SOL
 			signatures
 		);
 		console.log("Withdrawal transaction successful:", withdrawTx);
@@ -86,7 +94,8 @@ const exampleUsage = async () => {
 
 	// Get pool statistics
 	const stats = await bridgeSDK.getPoolStatistics();
-	console.log("Total volume:", stats.totalVolume.toString());
+	console.log("Total deposited:", stats.totalDeposited.toString());
+	console.log("Total withdrawn:", stats.totalWithdrawn.toString());
 	console.log("Accumulated fees:", stats.accumulatedFees.toString());
 };
 
@@ -36,11 +36,11 @@ export const IDL = {
 			args: [],
 		},
 		{
-			name: "add_validator",
-			discriminator: [250, 113, 53, 54, 141, 117, 215, 185],
+			name: "add_liquidity",
+			discriminator: [181, 157, 89, 67, 143, 182, 52, 72],
 			accounts: [
 				{
-					name: "owner",
+					name: "provider",
 					writable: true,
 					signer: true,
 				},
@@ -57,52 +57,64 @@ export const IDL = {
 					},
 				},
 				{
-					name: "new_validator",
-				},
-				{
-					name: "system_program",
-					address: "11111111111111111111111111111111",
+					name: "mint_account",
 				},
-			],
-			args: [],
-		},
-		{
-			name: "deposit_usdc",
-			discriminator: [184, 148, 250, 169, 224, 213, 34, 126],
-			accounts: [
 				{
-					name: "depositor",
+					name: "provider_ata",
 					writable: true,
-					signer: true,
 				},
 				{
-					name: "pool_state",
+					name: "pool_ata",
 					writable: true,
 					pda: {
 						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
 							{
 								kind: "const",
-								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
 							},
 						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
 					},
 				},
 				{
-					name: "mint_account",
-					writable: true,
-				},
-				{
-					name: "depositor_ata",
+					name: "lp_mint",
 					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [108, 112, 95, 109, 105, 110, 116],
+							},
+						],
+					},
 				},
 				{
-					name: "pool_ata",
+					name: "provider_lp_ata",
 					writable: true,
 					pda: {
 						seeds: [
 							{
 								kind: "account",
-								path: "pool_state",
+								path: "provider",
 							},
 							{
 								kind: "const",
@@ -114,7 +126,7 @@ export const IDL = {
 							},
 							{
 								kind: "account",
-								path: "mint_account",
+								path: "lp_mint",
 							},
 						],
 						program: {
@@ -142,18 +154,14 @@ export const IDL = {
 			],
 			args: [
 				{
-					name: "args",
-					type: {
-						defined: {
-							name: "DepositUSDCArgs",
-						},
-					},
+					name: "amount",
+					type: "u64",
 				},
 			],
 		},
 		{
-			name: "initialize",
-			discriminator: [175, 175, 109, 31, 13, 152, 155, 237],
+			name: "add_validator",
+			discriminator: [250, 113, 53, 54, 141, 117, 215, 185],
 			accounts: [
 				{
 					name: "owner",
@@ -173,35 +181,45 @@ export const IDL = {
 					},
 				},
 				{
-					name: "treasury",
+					name: "new_validator",
 				},
 				{
-					name: "usdc_mint",
+					name: "validator_set_snapshot",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
 				},
 				{
 					name: "system_program",
 					address: "11111111111111111111111111111111",
 				},
 			],
-			args: [
-				{
-					name: "tax",
-					type: "u16",
-				},
-			],
+			args: [],
 		},
 		{
-			name: "remove_validator",
-			discriminator: [25, 96, 211, 155, 161, 14, 168, 188],
+			name: "bond_validator",
+			docs: [
+				"Adds to a validator's bond. Validators only have secp256k1 keys, so the bond belongs to",
+				"a Solana `authority` the validator names by signing `build_bond_authority_message`.",
+			],
+			discriminator: [105, 49, 132, 19, 75, 127, 85, 115],
 			accounts: [
 				{
-					name: "owner",
+					name: "authority",
 					writable: true,
 					signer: true,
 				},
 				{
 					name: "pool_state",
-					writable: true,
 					pda: {
 						seeds: [
 							{
@@ -212,36 +230,47 @@ export const IDL = {
 					},
 				},
 				{
-					name: "old_validator",
+					name: "mint_account",
 				},
 				{
-					name: "system_program",
-					address: "11111111111111111111111111111111",
+					name: "authority_ata",
+					writable: true,
 				},
-			],
-			args: [],
-		},
-		{
-			name: "update_state",
-			discriminator: [135, 112, 215, 75, 247, 185, 53, 176],
-			accounts: [
 				{
-					name: "owner",
+					name: "validator_bond",
 					writable: true,
-					signer: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 98, 111, 110,
+									100,
+								],
+							},
+							{
+								kind: "arg",
+								path: "args.validator",
+							},
+						],
+					},
 				},
 				{
-					name: "pool_state",
+					name: "bond_vault",
 					writable: true,
 					pda: {
 						seeds: [
 							{
 								kind: "const",
-								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+								value: [98, 111, 110, 100, 95, 118, 97, 117, 108, 116],
 							},
 						],
 					},
 				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
 				{
 					name: "system_program",
 					address: "11111111111111111111111111111111",
@@ -252,15 +281,15 @@ export const IDL = {
 					name: "args",
 					type: {
 						defined: {
-							name: "ConfigUpdateArgs",
+							name: "BondValidatorArgs",
 						},
 					},
 				},
 			],
 		},
 		{
-			name: "withdraw_fees",
-			discriminator: [198, 212, 171, 109, 144, 215, 174, 89],
+			name: "cancel_ownership_transfer",
+			discriminator: [2, 184, 195, 105, 138, 142, 154, 75],
 			accounts: [
 				{
 					name: "owner",
@@ -280,68 +309,121 @@ export const IDL = {
 					},
 				},
 				{
-					name: "treasury",
-				},
-				{
-					name: "mint_account",
-					writable: true,
+					name: "system_program",
+					address: "11111111111111111111111111111111",
 				},
+			],
+			args: [],
+		},
+		{
+			name: "deny_evm_address",
+			discriminator: [233, 193, 208, 116, 236, 14, 208, 164],
+			accounts: [
 				{
-					name: "treasury_ata",
+					name: "authority",
 					writable: true,
+					signer: true,
 				},
 				{
-					name: "pool_ata",
+					name: "pool_state",
 					pda: {
 						seeds: [
 							{
-								kind: "account",
-								path: "pool_state",
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
 							},
+						],
+					},
+				},
+				{
+					name: "denylist_entry",
+					writable: true,
+					pda: {
+						seeds: [
 							{
 								kind: "const",
 								value: [
-									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
-									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
-									245, 133, 126, 255, 0, 169,
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 101, 118, 109,
 								],
 							},
 							{
-								kind: "account",
-								path: "mint_account",
+								kind: "arg",
+								path: "address",
 							},
 						],
-						program: {
-							kind: "const",
-							value: [
-								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
-								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
-								219, 233, 248, 89,
-							],
-						},
 					},
 				},
 				{
-					name: "token_program",
-					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					name: "system_program",
+					address: "11111111111111111111111111111111",
 				},
+			],
+			args: [
 				{
-					name: "associated_token_program",
-					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					name: "address",
+					type: {
+						array: ["u8", 20],
+					},
+				},
+			],
+		},
+		{
+			name: "deny_solana_address",
+			discriminator: [81, 8, 55, 215, 191, 240, 54, 74],
+			accounts: [
+				{
+					name: "authority",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "denylist_entry",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "arg",
+								path: "address",
+							},
+						],
+					},
 				},
 				{
 					name: "system_program",
 					address: "11111111111111111111111111111111",
 				},
 			],
-			args: [],
+			args: [
+				{
+					name: "address",
+					type: "pubkey",
+				},
+			],
 		},
 		{
-			name: "withdraw_usdc",
-			discriminator: [114, 49, 72, 184, 27, 156, 243, 155],
+			name: "deposit_usdc",
+			discriminator: [184, 148, 250, 169, 224, 213, 34, 126],
 			accounts: [
 				{
-					name: "payer",
+					name: "depositor",
 					writable: true,
 					signer: true,
 				},
@@ -362,11 +444,12 @@ export const IDL = {
 					writable: true,
 				},
 				{
-					name: "recipient_ata",
+					name: "depositor_ata",
 					writable: true,
 				},
 				{
 					name: "pool_ata",
+					writable: true,
 					pda: {
 						seeds: [
 							{
@@ -397,228 +480,6126 @@ export const IDL = {
 					},
 				},
 				{
-					name: "token_program",
-					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					name: "deposit_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 112, 111, 115, 105, 116, 95, 114, 101, 99, 111, 114,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "depositor",
+							},
+							{
+								kind: "arg",
+								path: "args.client_nonce",
+							},
+						],
+					},
 				},
 				{
-					name: "associated_token_program",
-					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					name: "depositor_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "account",
+								path: "depositor",
+							},
+						],
+					},
 				},
 				{
-					name: "system_program",
-					address: "11111111111111111111111111111111",
-				},
+					name: "recipient_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 101, 118, 109,
+								],
+							},
+							{
+								kind: "arg",
+								path: "args.recipient_evm_address",
+							},
+						],
+					},
+				},
+				{
+					name: "depositor_allowlist_entry",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [97, 108, 108, 111, 119, 108, 105, 115, 116],
+							},
+							{
+								kind: "account",
+								path: "depositor",
+							},
+						],
+					},
+				},
+				{
+					name: "daily_stats",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [100, 97, 105, 108, 121, 95, 115, 116, 97, 116, 115],
+							},
+						],
+					},
+				},
+				{
+					name: "memo_program",
+					optional: true,
+					address: "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
 			],
 			args: [
 				{
-					name: "recipient",
-					type: "pubkey",
+					name: "args",
+					type: {
+						defined: {
+							name: "DepositUSDCArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "deposit_usdc_cctp",
+			discriminator: [62, 118, 24, 12, 3, 244, 137, 183],
+			accounts: [
+				{
+					name: "depositor",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+					writable: true,
+				},
+				{
+					name: "depositor_ata",
+					writable: true,
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "deposit_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 112, 111, 115, 105, 116, 95, 114, 101, 99, 111, 114,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "depositor",
+							},
+							{
+								kind: "arg",
+								path: "args.client_nonce",
+							},
+						],
+					},
+				},
+				{
+					name: "depositor_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "account",
+								path: "depositor",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 101, 118, 109,
+								],
+							},
+							{
+								kind: "arg",
+								path: "args.recipient_evm_address",
+							},
+						],
+					},
+				},
+				{
+					name: "depositor_allowlist_entry",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [97, 108, 108, 111, 119, 108, 105, 115, 116],
+							},
+							{
+								kind: "account",
+								path: "depositor",
+							},
+						],
+					},
+				},
+				{
+					name: "daily_stats",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [100, 97, 105, 108, 121, 95, 115, 116, 97, 116, 115],
+							},
+						],
+					},
+				},
+				{
+					name: "memo_program",
+					optional: true,
+					address: "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
 				},
+			],
+			args: [
 				{
 					name: "args",
 					type: {
 						defined: {
-							name: "WithdrawUSDCArgs",
+							name: "DepositUSDCArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "flag_stale_validator",
+			docs: [
+				"Flags a validator that has not sent a heartbeat within `heartbeat_stale_slots` and takes",
+				"it out of the live set, bringing the threshold down if it can no longer be met. The last",
+				"validator is flagged but never removed. Previous sets keep it, it was quiet, not malicious.",
+			],
+			discriminator: [55, 47, 141, 157, 226, 104, 230, 175],
+			accounts: [
+				{
+					name: "flagger",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "validator",
+				},
+				{
+					name: "validator_liveness",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 108, 105, 118,
+									101, 110, 101, 115, 115,
+								],
+							},
+							{
+								kind: "account",
+								path: "validator",
+							},
+						],
+					},
+				},
+				{
+					name: "validator_set_snapshot",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [],
+		},
+		{
+			name: "fund_sol_reserve",
+			discriminator: [172, 84, 117, 102, 17, 221, 214, 161],
+			accounts: [
+				{
+					name: "funder",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "sol_reserve",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [115, 111, 108, 95, 114, 101, 115, 101, 114, 118, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "amount",
+					type: "u64",
+				},
+			],
+		},
+		{
+			name: "initialize",
+			discriminator: [175, 175, 109, 31, 13, 152, 155, 237],
+			accounts: [
+				{
+					name: "owner",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "treasury",
+				},
+				{
+					name: "usdc_mint",
+				},
+				{
+					name: "program",
+					address: "qbuMdeYxYJXBjU6C6qFKjZKjXmrU83eDQomHdrch826",
+				},
+				{
+					name: "program_data",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "InitializeArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "mark_deposit_processed",
+			discriminator: [45, 105, 142, 228, 192, 212, 190, 16],
+			accounts: [
+				{
+					name: "payer",
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "deposit_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 112, 111, 115, 105, 116, 95, 114, 101, 99, 111, 114,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "deposit_record.depositor",
+								account: "DepositRecord",
+							},
+							{
+								kind: "account",
+								path: "deposit_record.client_nonce",
+								account: "DepositRecord",
+							},
+						],
+					},
+				},
+				{
+					name: "validator_set_snapshot",
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+			],
+			args: [
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "DepositAttestationArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "reclaim_expired_deposit",
+			discriminator: [64, 24, 23, 48, 127, 241, 17, 150],
+			accounts: [
+				{
+					name: "depositor",
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "deposit_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 112, 111, 115, 105, 116, 95, 114, 101, 99, 111, 114,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "deposit_record.depositor",
+								account: "DepositRecord",
+							},
+							{
+								kind: "account",
+								path: "deposit_record.client_nonce",
+								account: "DepositRecord",
+							},
+						],
+					},
+				},
+				{
+					name: "depositor_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "account",
+								path: "deposit_record.depositor",
+								account: "DepositRecord",
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+					writable: true,
+				},
+				{
+					name: "depositor_ata",
+					writable: true,
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+			],
+			args: [],
+		},
+		{
+			name: "refund_deposit",
+			discriminator: [19, 19, 78, 50, 187, 10, 162, 229],
+			accounts: [
+				{
+					name: "payer",
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "deposit_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 112, 111, 115, 105, 116, 95, 114, 101, 99, 111, 114,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "deposit_record.depositor",
+								account: "DepositRecord",
+							},
+							{
+								kind: "account",
+								path: "deposit_record.client_nonce",
+								account: "DepositRecord",
+							},
+						],
+					},
+				},
+				{
+					name: "depositor_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "account",
+								path: "deposit_record.depositor",
+								account: "DepositRecord",
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+					writable: true,
+				},
+				{
+					name: "depositor_ata",
+					writable: true,
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "validator_set_snapshot",
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+			],
+			args: [
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "DepositAttestationArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "release_frozen_iou",
+			docs: [
+				"Pays out an IOU frozen at the head of the queue once its recipient is off the denylist.",
+			],
+			discriminator: [141, 177, 188, 69, 57, 73, 44, 207],
+			accounts: [
+				{
+					name: "settler",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "withdrawal_iou",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 105, 111,
+									117,
+								],
+							},
+							{
+								kind: "arg",
+								path: "sequence",
+							},
+						],
+					},
+				},
+				{
+					name: "payer",
+					writable: true,
+				},
+				{
+					name: "recipient_wallet",
+				},
+				{
+					name: "recipient_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "account",
+								path: "withdrawal_iou.recipient",
+								account: "WithdrawalIOU",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "recipient_wallet",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "payer_ata",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "payer",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "_sequence",
+					type: "u64",
+				},
+			],
+		},
+		{
+			name: "remove_allowlist_entry",
+			discriminator: [90, 45, 105, 22, 131, 188, 49, 94],
+			accounts: [
+				{
+					name: "authority",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "allowlist_entry",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [97, 108, 108, 111, 119, 108, 105, 115, 116],
+							},
+							{
+								kind: "arg",
+								path: "wallet",
+							},
+						],
+					},
+				},
+			],
+			args: [
+				{
+					name: "wallet",
+					type: "pubkey",
+				},
+			],
+		},
+		{
+			name: "remove_liquidity",
+			discriminator: [80, 85, 209, 72, 24, 206, 177, 108],
+			accounts: [
+				{
+					name: "provider",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+				},
+				{
+					name: "provider_ata",
+					writable: true,
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "lp_mint",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [108, 112, 95, 109, 105, 110, 116],
+							},
+						],
+					},
+				},
+				{
+					name: "provider_lp_ata",
+					writable: true,
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+			],
+			args: [
+				{
+					name: "shares",
+					type: "u64",
+				},
+			],
+		},
+		{
+			name: "remove_validator",
+			discriminator: [25, 96, 211, 155, 161, 14, 168, 188],
+			accounts: [
+				{
+					name: "owner",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "old_validator",
+				},
+				{
+					name: "validator_set_snapshot",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [],
+		},
+		{
+			name: "revoke_validator_set_snapshot",
+			docs: [
+				"Drops a previous validator set before its grace period is up, e.g. once its keys are",
+				"known to be compromised. Attestations it signed stop verifying right away.",
+			],
+			discriminator: [123, 44, 195, 60, 209, 198, 61, 68],
+			accounts: [
+				{
+					name: "owner",
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "validator_set_snapshot",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+			],
+			args: [
+				{
+					name: "validator_set_epoch",
+					type: "u64",
+				},
+			],
+		},
+		{
+			name: "rotate_validator_set",
+			discriminator: [185, 121, 174, 238, 152, 184, 203, 148],
+			accounts: [
+				{
+					name: "owner",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "validator_set_snapshot",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "RotateValidatorSetArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "set_allowlist_entry",
+			discriminator: [98, 101, 176, 119, 3, 99, 198, 202],
+			accounts: [
+				{
+					name: "authority",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "allowlist_entry",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [97, 108, 108, 111, 119, 108, 105, 115, 116],
+							},
+							{
+								kind: "arg",
+								path: "wallet",
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "wallet",
+					type: "pubkey",
+				},
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "AllowlistEntryArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "settle_withdrawal_iou",
+			docs: [
+				"Settles the head of the IOU queue. A recipient denied while queued doesn't hold up",
+				"everyone behind it, its IOU is frozen and set aside, still owed, for",
+				"`release_frozen_iou` once the entry is lifted.",
+			],
+			discriminator: [147, 119, 205, 42, 130, 132, 63, 212],
+			accounts: [
+				{
+					name: "settler",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "withdrawal_iou",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 105, 111,
+									117,
+								],
+							},
+							{
+								kind: "account",
+								path: "pool_state.iou_head",
+								account: "PoolState",
+							},
+						],
+					},
+				},
+				{
+					name: "payer",
+					writable: true,
+				},
+				{
+					name: "recipient_wallet",
+				},
+				{
+					name: "recipient_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "account",
+								path: "withdrawal_iou.recipient",
+								account: "WithdrawalIOU",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "recipient_wallet",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "payer_ata",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "payer",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [],
+		},
+		{
+			name: "slash_validator",
+			discriminator: [238, 57, 244, 40, 132, 82, 78, 5],
+			accounts: [
+				{
+					name: "reporter",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "validator",
+				},
+				{
+					name: "validator_bond",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 98, 111, 110,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "validator",
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+				},
+				{
+					name: "bond_vault",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [98, 111, 110, 100, 95, 118, 97, 117, 108, 116],
+							},
+						],
+					},
+				},
+				{
+					name: "insurance_fund",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									105, 110, 115, 117, 114, 97, 110, 99, 101, 95, 102, 117, 110,
+									100,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "validator_set_snapshot",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "SlashValidatorArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "snapshot_reserves",
+			discriminator: [44, 81, 87, 161, 5, 224, 129, 108],
+			accounts: [
+				{
+					name: "payer",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+				},
+				{
+					name: "pool_ata",
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "reserves_history",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									114, 101, 115, 101, 114, 118, 101, 115, 95, 104, 105, 115,
+									116, 111, 114, 121,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [],
+		},
+		{
+			name: "start_unbonding",
+			docs: [
+				"Starts the unbonding clock once the validator has left the live set. The bond can",
+				"still be slashed until it is withdrawn.",
+			],
+			discriminator: [100, 193, 16, 123, 187, 169, 163, 67],
+			accounts: [
+				{
+					name: "authority",
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "validator_bond",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 98, 111, 110,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "validator_bond.validator",
+								account: "ValidatorBond",
+							},
+						],
+					},
+				},
+			],
+			args: [],
+		},
+		{
+			name: "undeny_evm_address",
+			discriminator: [238, 2, 70, 234, 225, 141, 219, 92],
+			accounts: [
+				{
+					name: "authority",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "denylist_entry",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 101, 118, 109,
+								],
+							},
+							{
+								kind: "arg",
+								path: "address",
+							},
+						],
+					},
+				},
+			],
+			args: [
+				{
+					name: "address",
+					type: {
+						array: ["u8", 20],
+					},
+				},
+			],
+		},
+		{
+			name: "undeny_solana_address",
+			discriminator: [172, 206, 102, 25, 53, 179, 38, 186],
+			accounts: [
+				{
+					name: "authority",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "denylist_entry",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "arg",
+								path: "address",
+							},
+						],
+					},
+				},
+			],
+			args: [
+				{
+					name: "address",
+					type: "pubkey",
+				},
+			],
+		},
+		{
+			name: "update_state",
+			discriminator: [135, 112, 215, 75, 247, 185, 53, 176],
+			accounts: [
+				{
+					name: "owner",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "ConfigUpdateArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "validator_heartbeat",
+			docs: [
+				"Records that a validator was alive at `args.slot`. The validator signs the heartbeat with",
+				"its secp256k1 key and anyone can relay it, so validators don't need a funded Solana key.",
+			],
+			discriminator: [252, 244, 242, 186, 125, 110, 55, 141],
+			accounts: [
+				{
+					name: "payer",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "validator_liveness",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 108, 105, 118,
+									101, 110, 101, 115, 115,
+								],
+							},
+							{
+								kind: "arg",
+								path: "args.validator",
+							},
+						],
+					},
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "ValidatorHeartbeatArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "withdraw_bond",
+			docs: ["Pays an unbonded bond back to its authority and closes it."],
+			discriminator: [222, 199, 141, 31, 188, 93, 155, 40],
+			accounts: [
+				{
+					name: "authority",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+				},
+				{
+					name: "authority_ata",
+					writable: true,
+				},
+				{
+					name: "validator_bond",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 98, 111, 110,
+									100,
+								],
+							},
+							{
+								kind: "account",
+								path: "validator_bond.validator",
+								account: "ValidatorBond",
+							},
+						],
+					},
+				},
+				{
+					name: "bond_vault",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [98, 111, 110, 100, 95, 118, 97, 117, 108, 116],
+							},
+						],
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+			],
+			args: [],
+		},
+		{
+			name: "withdraw_fees",
+			discriminator: [198, 212, 171, 109, 144, 215, 174, 89],
+			accounts: [
+				{
+					name: "owner",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "treasury",
+				},
+				{
+					name: "mint_account",
+					writable: true,
+				},
+				{
+					name: "treasury_ata",
+					writable: true,
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [],
+		},
+		{
+			name: "withdraw_usdc",
+			docs: [
+				"In permissioned pools the recipient needs a live allowlist entry, and the withdrawal is",
+				"charged against its daily limit alongside its deposits.",
+			],
+			discriminator: [114, 49, 72, 184, 27, 156, 243, 155],
+			accounts: [
+				{
+					name: "payer",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+					writable: true,
+				},
+				{
+					name: "recipient_wallet",
+					writable: true,
+				},
+				{
+					name: "recipient_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "recipient_wallet",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "payer_ata",
+					writable: true,
+					optional: true,
+				},
+				{
+					name: "validator_set_snapshot",
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "memo_program",
+					optional: true,
+					address: "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+				},
+				{
+					name: "sol_reserve",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [115, 111, 108, 95, 114, 101, 115, 101, 114, 118, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "native_drop_receipt",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [110, 97, 116, 105, 118, 101, 95, 100, 114, 111, 112],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "sender_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 101, 118, 109,
+								],
+							},
+							{
+								kind: "arg",
+								path: "args.sender_evm_address",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_allowlist_entry",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [97, 108, 108, 111, 119, 108, 105, 115, 116],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "withdrawal_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108],
+							},
+							{
+								kind: "arg",
+								path: "args.source_chain_id",
+							},
+							{
+								kind: "arg",
+								path: "args.nonce",
+							},
+						],
+					},
+				},
+				{
+					name: "daily_stats",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [100, 97, 105, 108, 121, 95, 115, 116, 97, 116, 115],
+							},
+						],
+					},
+				},
+				{
+					name: "withdrawal_iou",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 105, 111,
+									117,
+								],
+							},
+							{
+								kind: "account",
+								path: "pool_state.iou_tail",
+								account: "PoolState",
+							},
+						],
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "recipient",
+					type: "pubkey",
+				},
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "WithdrawUSDCArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "withdraw_usdc_and_call",
+			docs: [
+				"Pays a withdrawal like `withdraw_usdc`, then calls the signed target program with the",
+				"signed accounts. The pool PDA never signs for the target, so the call can only move the",
+				"tokens if the recipient is an account the target controls, such as one of its own PDAs.",
+				"Paid to a user's wallet they stay put, and the call can only act on the user's behalf.",
+			],
+			discriminator: [86, 159, 167, 207, 56, 102, 145, 193],
+			accounts: [
+				{
+					name: "payer",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+					writable: true,
+				},
+				{
+					name: "recipient_wallet",
+					writable: true,
+				},
+				{
+					name: "recipient_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "recipient_wallet",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "payer_ata",
+					writable: true,
+					optional: true,
+				},
+				{
+					name: "validator_set_snapshot",
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "memo_program",
+					optional: true,
+					address: "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+				},
+				{
+					name: "sol_reserve",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [115, 111, 108, 95, 114, 101, 115, 101, 114, 118, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "native_drop_receipt",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [110, 97, 116, 105, 118, 101, 95, 100, 114, 111, 112],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "sender_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 101, 118, 109,
+								],
+							},
+							{
+								kind: "arg",
+								path: "args.sender_evm_address",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_allowlist_entry",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [97, 108, 108, 111, 119, 108, 105, 115, 116],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "withdrawal_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108],
+							},
+							{
+								kind: "arg",
+								path: "args.source_chain_id",
+							},
+							{
+								kind: "arg",
+								path: "args.nonce",
+							},
+						],
+					},
+				},
+				{
+					name: "daily_stats",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [100, 97, 105, 108, 121, 95, 115, 116, 97, 116, 115],
+							},
+						],
+					},
+				},
+				{
+					name: "withdrawal_iou",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 105, 111,
+									117,
+								],
+							},
+							{
+								kind: "account",
+								path: "pool_state.iou_tail",
+								account: "PoolState",
+							},
+						],
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "recipient",
+					type: "pubkey",
+				},
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "WithdrawUSDCArgs",
+						},
+					},
+				},
+				{
+					name: "call",
+					type: {
+						defined: {
+							name: "WithdrawCallArgs",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "withdraw_usdc_cctp",
+			discriminator: [93, 105, 242, 108, 38, 206, 116, 197],
+			accounts: [
+				{
+					name: "payer",
+					writable: true,
+					signer: true,
+				},
+				{
+					name: "pool_state",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [112, 111, 111, 108, 95, 115, 116, 97, 116, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "mint_account",
+					writable: true,
+				},
+				{
+					name: "recipient_wallet",
+					writable: true,
+				},
+				{
+					name: "recipient_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "recipient_wallet",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "payer_ata",
+					writable: true,
+					optional: true,
+				},
+				{
+					name: "validator_set_snapshot",
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 115, 101, 116,
+									95, 115, 110, 97, 112, 115, 104, 111, 116,
+								],
+							},
+						],
+					},
+				},
+				{
+					name: "pool_ata",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "account",
+								path: "pool_state",
+							},
+							{
+								kind: "const",
+								value: [
+									6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206,
+									235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140,
+									245, 133, 126, 255, 0, 169,
+								],
+							},
+							{
+								kind: "account",
+								path: "mint_account",
+							},
+						],
+						program: {
+							kind: "const",
+							value: [
+								140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+								13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+								219, 233, 248, 89,
+							],
+						},
+					},
+				},
+				{
+					name: "memo_program",
+					optional: true,
+					address: "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+				},
+				{
+					name: "sol_reserve",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [115, 111, 108, 95, 114, 101, 115, 101, 114, 118, 101],
+							},
+						],
+					},
+				},
+				{
+					name: "native_drop_receipt",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [110, 97, 116, 105, 118, 101, 95, 100, 114, 111, 112],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 115, 111, 108, 97,
+									110, 97,
+								],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "sender_denylist_entry",
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									100, 101, 110, 121, 108, 105, 115, 116, 95, 101, 118, 109,
+								],
+							},
+							{
+								kind: "arg",
+								path: "args.sender_evm_address",
+							},
+						],
+					},
+				},
+				{
+					name: "recipient_allowlist_entry",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [97, 108, 108, 111, 119, 108, 105, 115, 116],
+							},
+							{
+								kind: "arg",
+								path: "recipient",
+							},
+						],
+					},
+				},
+				{
+					name: "withdrawal_record",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108],
+							},
+							{
+								kind: "arg",
+								path: "args.source_chain_id",
+							},
+							{
+								kind: "arg",
+								path: "args.nonce",
+							},
+						],
+					},
+				},
+				{
+					name: "daily_stats",
+					writable: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [100, 97, 105, 108, 121, 95, 115, 116, 97, 116, 115],
+							},
+						],
+					},
+				},
+				{
+					name: "withdrawal_iou",
+					writable: true,
+					optional: true,
+					pda: {
+						seeds: [
+							{
+								kind: "const",
+								value: [
+									119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 105, 111,
+									117,
+								],
+							},
+							{
+								kind: "account",
+								path: "pool_state.iou_tail",
+								account: "PoolState",
+							},
+						],
+					},
+				},
+				{
+					name: "token_program",
+					address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+				},
+				{
+					name: "associated_token_program",
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+				},
+				{
+					name: "system_program",
+					address: "11111111111111111111111111111111",
+				},
+			],
+			args: [
+				{
+					name: "recipient",
+					type: "pubkey",
+				},
+				{
+					name: "args",
+					type: {
+						defined: {
+							name: "WithdrawUSDCArgs",
+						},
+					},
+				},
+				{
+					name: "cctp",
+					type: {
+						defined: {
+							name: "CctpReceiveMessageParams",
+						},
+					},
+				},
+			],
+		},
+	],
+	accounts: [
+		{
+			name: "AllowlistEntry",
+			discriminator: [42, 59, 88, 1, 124, 138, 92, 236],
+		},
+		{
+			name: "DailyStats",
+			discriminator: [59, 80, 154, 206, 133, 223, 161, 59],
+		},
+		{
+			name: "DenylistEntry",
+			discriminator: [2, 44, 7, 103, 34, 229, 136, 179],
+		},
+		{
+			name: "DepositRecord",
+			discriminator: [83, 232, 10, 31, 251, 49, 189, 167],
+		},
+		{
+			name: "NativeDropReceipt",
+			discriminator: [242, 167, 19, 3, 26, 20, 136, 21],
+		},
+		{
+			name: "PoolState",
+			discriminator: [247, 237, 227, 245, 215, 195, 222, 70],
+		},
+		{
+			name: "ReservesHistory",
+			discriminator: [57, 53, 249, 255, 217, 178, 71, 199],
+		},
+		{
+			name: "SolReserve",
+			discriminator: [78, 170, 214, 85, 103, 74, 70, 180],
+		},
+		{
+			name: "ValidatorBond",
+			discriminator: [82, 127, 243, 208, 195, 42, 80, 35],
+		},
+		{
+			name: "ValidatorLiveness",
+			discriminator: [7, 38, 20, 208, 157, 43, 208, 214],
+		},
+		{
+			name: "ValidatorSetSnapshot",
+			discriminator: [52, 253, 242, 221, 249, 229, 150, 53],
+		},
+		{
+			name: "WithdrawalIOU",
+			discriminator: [218, 139, 253, 240, 158, 150, 183, 240],
+		},
+		{
+			name: "WithdrawalRecord",
+			discriminator: [88, 59, 154, 202, 216, 210, 211, 237],
+		},
+	],
+	events: [
+		{
+			name: "AllowlistEntryRemoved",
+			discriminator: [62, 82, 249, 76, 179, 253, 95, 61],
+		},
+		{
+			name: "AllowlistEntrySet",
+			discriminator: [99, 134, 226, 3, 203, 52, 176, 114],
+		},
+		{
+			name: "CctpDepositBurned",
+			discriminator: [114, 208, 237, 179, 64, 22, 52, 248],
+		},
+		{
+			name: "CircuitBreakerTripped",
+			discriminator: [188, 9, 111, 118, 136, 206, 199, 65],
+		},
+		{
+			name: "DenylistEntryAdded",
+			discriminator: [92, 102, 164, 230, 130, 85, 100, 69],
+		},
+		{
+			name: "DenylistEntryRemoved",
+			discriminator: [241, 26, 42, 6, 55, 165, 232, 88],
+		},
+		{
+			name: "DepositProcessed",
+			discriminator: [176, 74, 23, 61, 251, 42, 148, 15],
+		},
+		{
+			name: "DepositReclaimed",
+			discriminator: [0, 126, 199, 233, 195, 63, 18, 159],
+		},
+		{
+			name: "DepositRefunded",
+			discriminator: [182, 155, 48, 105, 176, 178, 212, 215],
+		},
+		{
+			name: "FeesWithdrawn",
+			discriminator: [234, 15, 0, 119, 148, 241, 40, 21],
+		},
+		{
+			name: "LiquidityAdded",
+			discriminator: [154, 26, 221, 108, 238, 64, 217, 161],
+		},
+		{
+			name: "LiquidityRemoved",
+			discriminator: [225, 105, 216, 39, 124, 116, 169, 189],
+		},
+		{
+			name: "OwnerChanged",
+			discriminator: [34, 223, 103, 225, 239, 231, 51, 53],
+		},
+		{
+			name: "OwnershipTransferCancelled",
+			discriminator: [120, 203, 162, 145, 180, 57, 253, 23],
+		},
+		{
+			name: "OwnershipTransferProposed",
+			discriminator: [150, 120, 242, 14, 83, 116, 104, 71],
+		},
+		{
+			name: "PoolCreated",
+			discriminator: [202, 44, 41, 88, 104, 220, 157, 82],
+		},
+		{
+			name: "PoolStateUpdated",
+			discriminator: [231, 22, 226, 177, 26, 215, 227, 97],
+		},
+		{
+			name: "ReservesSnapshot",
+			discriminator: [19, 130, 221, 47, 15, 64, 99, 40],
+		},
+		{
+			name: "SolReserveFunded",
+			discriminator: [169, 242, 159, 85, 19, 41, 95, 113],
+		},
+		{
+			name: "USDCDeposited",
+			discriminator: [75, 200, 113, 3, 12, 197, 106, 215],
+		},
+		{
+			name: "USDCWithdrawn",
+			discriminator: [58, 59, 209, 122, 222, 203, 160, 217],
+		},
+		{
+			name: "ValidatorAdded",
+			discriminator: [67, 26, 43, 25, 58, 219, 99, 48],
+		},
+		{
+			name: "ValidatorBondWithdrawn",
+			discriminator: [63, 133, 131, 255, 152, 198, 217, 126],
+		},
+		{
+			name: "ValidatorBonded",
+			discriminator: [33, 5, 195, 111, 47, 136, 117, 196],
+		},
+		{
+			name: "ValidatorHeartbeat",
+			discriminator: [251, 232, 50, 80, 148, 139, 54, 217],
+		},
+		{
+			name: "ValidatorRemoved",
+			discriminator: [133, 140, 80, 83, 7, 209, 70, 130],
+		},
+		{
+			name: "ValidatorSetRotated",
+			discriminator: [10, 181, 157, 65, 34, 53, 104, 135],
+		},
+		{
+			name: "ValidatorSetSnapshotRevoked",
+			discriminator: [169, 11, 199, 20, 116, 13, 31, 53],
+		},
+		{
+			name: "ValidatorSlashed",
+			discriminator: [1, 160, 99, 18, 25, 42, 5, 213],
+		},
+		{
+			name: "ValidatorStale",
+			discriminator: [14, 38, 13, 163, 227, 215, 224, 89],
+		},
+		{
+			name: "ValidatorUnbonding",
+			discriminator: [243, 32, 157, 218, 145, 60, 194, 223],
+		},
+		{
+			name: "WithdrawalCallExecuted",
+			discriminator: [209, 91, 230, 95, 43, 52, 113, 227],
+		},
+		{
+			name: "WithdrawalIOUFrozen",
+			discriminator: [249, 4, 181, 124, 232, 211, 110, 21],
+		},
+		{
+			name: "WithdrawalIOUSettled",
+			discriminator: [119, 240, 254, 20, 138, 86, 214, 105],
+		},
+		{
+			name: "WithdrawalQueued",
+			discriminator: [116, 223, 187, 38, 197, 80, 19, 250],
+		},
+	],
+	errors: [
+		{
+			code: 6000,
+			name: "TooManyValidators",
+			msg: "Too many validators. Max is 10. Remove one before adding another one.",
+		},
+		{
+			code: 6001,
+			name: "ValidatorDoesNotExist",
+			msg: "This validator key doesn't exist.",
+		},
+		{
+			code: 6002,
+			name: "InvalidOwnershipChange",
+			msg: "Invalid Ownership Change, you are not the proposed owner.",
+		},
+		{
+			code: 6003,
+			name: "PoolPaused",
+			msg: "Pool's Closed.",
+		},
+		{
+			code: 6004,
+			name: "WrongToken",
+			msg: "USDC Only.",
+		},
+		{
+			code: 6005,
+			name: "ZeroTax",
+			msg: "Tax must be at least 1 basis point.",
+		},
+		{
+			code: 6006,
+			name: "TaxFailed",
+			msg: "Tax could not be applied.",
+		},
+		{
+			code: 6007,
+			name: "InvalidMessageHash",
+			msg: "Verification failed - failed to hash message.",
+		},
+		{
+			code: 6008,
+			name: "InvalidSignature",
+			msg: "Verification failed - one tested signature failed.",
+		},
+		{
+			code: 6009,
+			name: "InvalidPublicKey",
+			msg: "Verification failed - one tested validator pubkey failed.",
+		},
+		{
+			code: 6010,
+			name: "NotEnoughSignatures",
+			msg: "Verification failed - not enough signatures present.",
+		},
+		{
+			code: 6011,
+			name: "MismatchedSignaturesAndKeys",
+			msg: "Verification failed - wrong number of sigs/validators.",
+		},
+		{
+			code: 6012,
+			name: "DuplicateValidator",
+			msg: "Verification failed - attempted to pass duplicate validators.",
+		},
+		{
+			code: 6013,
+			name: "InvalidValidatorAccount",
+			msg: "Invalid validator account supplied",
+		},
+		{
+			code: 6014,
+			name: "FailedToValidate",
+			msg: "Failed to validate withdrawal",
+		},
+		{
+			code: 6015,
+			name: "NoPendingOwnershipTransfer",
+			msg: "No ownership transfer is pending.",
+		},
+		{
+			code: 6016,
+			name: "OwnershipTransferExpired",
+			msg: "The ownership transfer proposal has expired.",
+		},
+		{
+			code: 6017,
+			name: "InvalidOwnershipTransferWindow",
+			msg: "Ownership transfer window must be greater than zero.",
+		},
+		{
+			code: 6018,
+			name: "UnauthorizedLauncher",
+			msg: "Only the upgrade authority or the authorized launcher can initialize the pool.",
+		},
+		{
+			code: 6019,
+			name: "InvalidTreasury",
+			msg: "Treasury cannot be the default pubkey.",
+		},
+		{
+			code: 6020,
+			name: "InvalidThreshold",
+			msg: "Required signatures must be between 1 and the number of validators.",
+		},
+		{
+			code: 6021,
+			name: "AmountExceedsLimit",
+			msg: "Amount exceeds the configured per-transfer limit.",
+		},
+		{
+			code: 6022,
+			name: "EpochOverflow",
+			msg: "Validator set epoch overflowed.",
+		},
+		{
+			code: 6023,
+			name: "InvalidGracePeriod",
+			msg: "Validator set grace period cannot be negative.",
+		},
+		{
+			code: 6024,
+			name: "UnknownValidatorSetEpoch",
+			msg: "Attestation is for a validator set epoch that is neither current nor previous.",
+		},
+		{
+			code: 6025,
+			name: "ValidatorSetExpired",
+			msg: "Attestation is for a previous validator set whose grace period has ended.",
+		},
+		{
+			code: 6026,
+			name: "InvalidStaleWindow",
+			msg: "Heartbeat stale window must be greater than zero.",
+		},
+		{
+			code: 6027,
+			name: "ValidatorNotStale",
+			msg: "Validator has sent a heartbeat within the stale window.",
+		},
+		{
+			code: 6028,
+			name: "ZeroBond",
+			msg: "Bond amount must be greater than zero.",
+		},
+		{
+			code: 6029,
+			name: "BondOverflow",
+			msg: "Validator bond overflowed.",
+		},
+		{
+			code: 6030,
+			name: "InvalidEquivocationProof",
+			msg: "Attestations do not prove equivocation by this validator.",
+		},
+		{
+			code: 6031,
+			name: "DepositSequenceOverflow",
+			msg: "Deposit sequence overflowed.",
+		},
+		{
+			code: 6032,
+			name: "DepositNotPending",
+			msg: "Deposit has already been processed or refunded.",
+		},
+		{
+			code: 6033,
+			name: "InvalidProcessingWindow",
+			msg: "Deposit processing window must be greater than zero.",
+		},
+		{
+			code: 6034,
+			name: "DepositNotExpired",
+			msg: "Deposit has not passed its processing deadline plus the reclaim buffer.",
+		},
+		{
+			code: 6035,
+			name: "NotDepositor",
+			msg: "Only the depositor can reclaim this deposit.",
+		},
+		{
+			code: 6036,
+			name: "PayloadTooLarge",
+			msg: "Deposit payload is too large.",
+		},
+		{
+			code: 6037,
+			name: "PayloadWithoutTarget",
+			msg: "Deposit payload needs a target contract.",
+		},
+		{
+			code: 6038,
+			name: "InvalidCallTarget",
+			msg: "Withdraw-and-call target program is missing, not executable, or not allowed.",
+		},
+		{
+			code: 6039,
+			name: "RelayerFeeTooHigh",
+			msg: "Relayer fee is larger than the withdrawal.",
+		},
+		{
+			code: 6040,
+			name: "MissingRelayerAccount",
+			msg: "Relayer fee needs the payer's token account.",
+		},
+		{
+			code: 6041,
+			name: "InvalidRecipient",
+			msg: "Recipient wallet does not match the signed recipient.",
+		},
+		{
+			code: 6042,
+			name: "MissingNativeDropAccounts",
+			msg: "Native drop needs the SOL reserve and the recipient's drop receipt.",
+		},
+		{
+			code: 6043,
+			name: "ZeroReserveFunding",
+			msg: "SOL reserve funding must be greater than zero.",
+		},
+		{
+			code: 6044,
+			name: "ZeroLiquidity",
+			msg: "Liquidity amount is zero or too small for a single share.",
+		},
+		{
+			code: 6045,
+			name: "LiquidityOverflow",
+			msg: "Pool liquidity overflowed.",
+		},
+		{
+			code: 6046,
+			name: "InvalidLpFeeShare",
+			msg: "LP fee share cannot exceed 10000 bps.",
+		},
+		{
+			code: 6047,
+			name: "InsufficientLiquidity",
+			msg: "Pool does not have enough free liquidity.",
+		},
+		{
+			code: 6048,
+			name: "WithdrawalIouNotNeeded",
+			msg: "Withdrawal can be paid right away and does not need an IOU.",
+		},
+		{
+			code: 6049,
+			name: "WithdrawalsQueued",
+			msg: "Queued withdrawals have to be settled first.",
+		},
+		{
+			code: 6050,
+			name: "IouSequenceOverflow",
+			msg: "Withdrawal IOU sequence overflowed.",
+		},
+		{
+			code: 6051,
+			name: "InvalidMintAuthority",
+			msg: "Burn/mint pools need the pool to be the mint authority of the wrapped token.",
+		},
+		{
+			code: 6052,
+			name: "WrongBridgeMode",
+			msg: "Not available in this pool's bridge mode.",
+		},
+		{
+			code: 6053,
+			name: "CctpNotConfigured",
+			msg: "CCTP is not configured for this pool.",
+		},
+		{
+			code: 6054,
+			name: "InvalidCctpProgram",
+			msg: "CCTP program is missing, not executable, or not the configured one.",
+		},
+		{
+			code: 6055,
+			name: "CctpCallNotSupported",
+			msg: "CCTP deposits can't carry a contract call.",
+		},
+		{
+			code: 6056,
+			name: "CctpMintMismatch",
+			msg: "CCTP minted a different amount than the attested withdrawal.",
+		},
+		{
+			code: 6057,
+			name: "SnapshotTooSoon",
+			msg: "Reserves were snapshotted less than the snapshot interval ago.",
+		},
+		{
+			code: 6058,
+			name: "InvalidNetFlowWindow",
+			msg: "Net flow window must be positive.",
+		},
+		{
+			code: 6059,
+			name: "AddressDenied",
+			msg: "Address is on the denylist.",
+		},
+		{
+			code: 6060,
+			name: "UnauthorizedCompliance",
+			msg: "Only the owner or the compliance authority can do this.",
+		},
+		{
+			code: 6061,
+			name: "NotComplianceAuthority",
+			msg: "Only the compliance authority can do this.",
+		},
+		{
+			code: 6062,
+			name: "NotAllowlisted",
+			msg: "Wallet is not on this permissioned pool's allowlist.",
+		},
+		{
+			code: 6063,
+			name: "AllowlistEntryExpired",
+			msg: "Wallet's allowlist entry has expired.",
+		},
+		{
+			code: 6064,
+			name: "AllowlistDailyLimitExceeded",
+			msg: "Transfer is over the wallet's daily allowlist limit.",
+		},
+		{
+			code: 6065,
+			name: "InvalidAllowlistExpiry",
+			msg: "Allowlist expiry must be in the future, or 0 for never.",
+		},
+		{
+			code: 6066,
+			name: "RotationApprovalLocked",
+			msg: "Rotation approval can't be switched off once it is on.",
+		},
+		{
+			code: 6067,
+			name: "InvalidHeartbeatSlot",
+			msg: "Heartbeat slot must be newer than the last one and not in the future.",
+		},
+		{
+			code: 6068,
+			name: "HeartbeatTooOld",
+			msg: "Heartbeat was signed too many slots ago.",
+		},
+		{
+			code: 6069,
+			name: "InvalidUnbondingPeriod",
+			msg: "Bond unbonding period must be positive.",
+		},
+		{
+			code: 6070,
+			name: "NotBondAuthority",
+			msg: "Signer is not this bond's authority.",
+		},
+		{
+			code: 6071,
+			name: "BondUnbonding",
+			msg: "Bond is already unbonding.",
+		},
+		{
+			code: 6072,
+			name: "ValidatorStillActive",
+			msg: "Validator is still in the live set.",
+		},
+		{
+			code: 6073,
+			name: "BondStillLocked",
+			msg: "Bond isn't unbonding or its unbonding period isn't over.",
+		},
+		{
+			code: 6074,
+			name: "NothingToSlash",
+			msg: "Validator has no bond and is not in the live set.",
+		},
+		{
+			code: 6075,
+			name: "InvalidReclaimBuffer",
+			msg: "Reclaim buffer must be greater than zero.",
+		},
+		{
+			code: 6076,
+			name: "InsufficientShares",
+			msg: "Cannot redeem more LP shares than exist.",
+		},
+		{
+			code: 6077,
+			name: "CctpUnsupportedChain",
+			msg: "Destination chain has no CCTP domain.",
+		},
+		{
+			code: 6078,
+			name: "InvalidSnapshotInterval",
+			msg: "Reserves snapshot interval must be greater than zero.",
+		},
+		{
+			code: 6079,
+			name: "ComplianceAuthorityIsOwner",
+			msg: "Compliance authority and owner must be different keys.",
+		},
+		{
+			code: 6080,
+			name: "TaxTooHigh",
+			msg: "Tax is above the fee ceiling.",
+		},
+		{
+			code: 6081,
+			name: "TaxCeilingTooHigh",
+			msg: "Fee ceiling can only be lowered, and never above the protocol maximum.",
+		},
+		{
+			code: 6082,
+			name: "InvalidCctpTokenAccount",
+			msg: "Only the pool's own token account can be handed to the CCTP program.",
+		},
+		{
+			code: 6083,
+			name: "CctpBurnMismatch",
+			msg: "CCTP burned a different amount than the deposit.",
+		},
+		{
+			code: 6084,
+			name: "IouNotFrozen",
+			msg: "Withdrawal IOU is not frozen.",
+		},
+		{
+			code: 6085,
+			name: "ValidatorAlreadyStale",
+			msg: "Validator has already been flagged stale.",
+		},
+	],
+	types: [
+		{
+			name: "AllowlistEntry",
+			docs: [
+				"Lets one wallet bridge in a permissioned pool, seeded by the wallet.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "wallet",
+						type: "pubkey",
+					},
+					{
+						name: "daily_limit",
+						type: "u64",
+					},
+					{
+						name: "expires_at",
+						type: "i64",
+					},
+					{
+						name: "day",
+						type: "i64",
+					},
+					{
+						name: "used_today",
+						type: "u64",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "AllowlistEntryArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "daily_limit",
+						type: "u64",
+					},
+					{
+						name: "expires_at",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "AllowlistEntryRemoved",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "wallet",
+						type: "pubkey",
+					},
+					{
+						name: "authority",
+						type: "pubkey",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "AllowlistEntrySet",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "wallet",
+						type: "pubkey",
+					},
+					{
+						name: "daily_limit",
+						type: "u64",
+					},
+					{
+						name: "expires_at",
+						type: "i64",
+					},
+					{
+						name: "authority",
+						type: "pubkey",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "BondValidatorArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "r",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "s",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "v",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "BridgeMode",
+			type: {
+				kind: "enum",
+				variants: [
+					{
+						name: "LockUnlock",
+					},
+					{
+						name: "BurnMint",
+					},
+				],
+			},
+		},
+		{
+			name: "CctpDepositBurned",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "depositor",
+						type: "pubkey",
+					},
+					{
+						name: "recipient_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "tax",
+						type: "u64",
+					},
+					{
+						name: "deposit_id",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "reference",
+						type: {
+							option: {
+								array: ["u8", 32],
+							},
+						},
+					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "client_nonce",
+						type: "u64",
+					},
+					{
+						name: "destination_chain_id",
+						type: "u64",
+					},
+					{
+						name: "destination_domain",
+						type: "u32",
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "CctpReceiveMessageParams",
+			docs: ["Mirrors CCTP's `ReceiveMessageParams`."],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "message",
+						type: "bytes",
+					},
+					{
+						name: "attestation",
+						type: "bytes",
+					},
+				],
+			},
+		},
+		{
+			name: "CircuitBreakerTripped",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "window_start",
+						type: "i64",
+					},
+					{
+						name: "deposited",
+						type: "u64",
+					},
+					{
+						name: "withdrawn",
+						type: "u64",
+					},
+					{
+						name: "net_flow",
+						type: "u64",
+					},
+					{
+						name: "threshold",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "ConfigUpdateArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "treasury",
+						type: {
+							option: "pubkey",
+						},
+					},
+					{
+						name: "owner",
+						type: {
+							option: "pubkey",
+						},
+					},
+					{
+						name: "tax",
+						type: {
+							option: "u16",
+						},
+					},
+					{
+						name: "max_tax",
+						type: {
+							option: "u16",
+						},
+					},
+					{
+						name: "paused",
+						type: {
+							option: "bool",
+						},
+					},
+					{
+						name: "rotation_requires_approval",
+						type: {
+							option: "bool",
+						},
+					},
+					{
+						name: "validator_set_grace_period",
+						type: {
+							option: "i64",
+						},
+					},
+					{
+						name: "heartbeat_stale_slots",
+						type: {
+							option: "u64",
+						},
+					},
+					{
+						name: "bond_unbonding_period",
+						type: {
+							option: "i64",
+						},
+					},
+					{
+						name: "deposit_processing_window",
+						type: {
+							option: "i64",
+						},
+					},
+					{
+						name: "reclaim_buffer",
+						type: {
+							option: "i64",
+						},
+					},
+					{
+						name: "refund_fees",
+						type: {
+							option: "bool",
+						},
+					},
+					{
+						name: "ownership_transfer_window",
+						type: {
+							option: "i64",
+						},
+					},
+					{
+						name: "max_deposit_amount",
+						type: {
+							option: "u64",
+						},
+					},
+					{
+						name: "max_withdrawal_amount",
+						type: {
+							option: "u64",
+						},
+					},
+					{
+						name: "max_native_drop",
+						type: {
+							option: "u64",
+						},
+					},
+					{
+						name: "max_native_drop_per_recipient",
+						type: {
+							option: "u64",
+						},
+					},
+					{
+						name: "lp_fee_share_bps",
+						type: {
+							option: "u16",
+						},
+					},
+					{
+						name: "cctp_token_messenger",
+						type: {
+							option: "pubkey",
+						},
+					},
+					{
+						name: "cctp_message_transmitter",
+						type: {
+							option: "pubkey",
+						},
+					},
+					{
+						name: "compliance_authority",
+						type: {
+							option: "pubkey",
+						},
+					},
+					{
+						name: "permissioned",
+						type: {
+							option: "bool",
+						},
+					},
+					{
+						name: "net_flow_threshold",
+						type: {
+							option: "u64",
+						},
+					},
+					{
+						name: "net_flow_window",
+						type: {
+							option: "i64",
+						},
+					},
+					{
+						name: "reserves_snapshot_interval",
+						type: {
+							option: "u64",
+						},
+					},
+				],
+			},
+		},
+		{
+			name: "DailyStats",
+			docs: [
+				"Per-day flow for the last DAILY_STATS_LEN days, so dashboards don't have to replay events.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "days",
+						type: {
+							array: [
+								{
+									defined: {
+										name: "DailyStatsEntry",
+									},
+								},
+								30,
+							],
+						},
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "DailyStatsEntry",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "day",
+						type: "i64",
+					},
+					{
+						name: "deposited",
+						type: "u128",
+					},
+					{
+						name: "withdrawn",
+						type: "u128",
+					},
+					{
+						name: "fees_collected",
+						type: "u128",
+					},
+					{
+						name: "deposit_count",
+						type: "u64",
+					},
+					{
+						name: "withdrawal_count",
+						type: "u64",
+					},
+				],
+			},
+		},
+		{
+			name: "DenylistEntry",
+			docs: [
+				"Blocks one Solana or EVM address from bridging. Only its existence matters, deposits and",
+				"withdrawals fail while the PDA for either end is initialized.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "added_by",
+						type: "pubkey",
+					},
+					{
+						name: "added_at",
+						type: "i64",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "DenylistEntryAdded",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "solana_address",
+						type: {
+							option: "pubkey",
+						},
+					},
+					{
+						name: "evm_address",
+						type: {
+							option: {
+								array: ["u8", 20],
+							},
+						},
+					},
+					{
+						name: "authority",
+						type: "pubkey",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "DenylistEntryRemoved",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "solana_address",
+						type: {
+							option: "pubkey",
+						},
+					},
+					{
+						name: "evm_address",
+						type: {
+							option: {
+								array: ["u8", 20],
+							},
+						},
+					},
+					{
+						name: "authority",
+						type: "pubkey",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "DepositAttestationArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "validator_set_epoch",
+						type: "u64",
+					},
+					{
+						name: "r",
+						type: {
+							array: [
+								{
+									array: ["u8", 32],
+								},
+								16,
+							],
+						},
+					},
+					{
+						name: "s",
+						type: {
+							array: [
+								{
+									array: ["u8", 32],
+								},
+								16,
+							],
+						},
+					},
+					{
+						name: "v",
+						type: {
+							array: ["u8", 16],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: "DepositProcessed",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "deposit_id",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "DepositReclaimed",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "deposit_id",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "depositor",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "fee_refunded",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "DepositRecord",
+			docs: [
+				"On-chain trail of a single deposit, seeded by the depositor and their client nonce so",
+				"deposits landing in the same slot never fight over one address.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "deposit_id",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "client_nonce",
+						type: "u64",
+					},
+					{
+						name: "depositor",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "tax",
+						type: "u64",
+					},
+					{
+						name: "lp_fee",
+						type: "u64",
+					},
+					{
+						name: "recipient_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
+					},
+					{
+						name: "destination_chain_id",
+						type: "u64",
+					},
+					{
+						name: "target_contract",
+						type: {
+							option: {
+								array: ["u8", 20],
+							},
+						},
+					},
+					{
+						name: "payload_hash",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "reference",
+						type: {
+							option: {
+								array: ["u8", 32],
+							},
+						},
+					},
+					{
+						name: "status",
+						type: {
+							defined: {
+								name: "DepositStatus",
+							},
+						},
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "deadline",
+						type: "i64",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "DepositRefunded",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "deposit_id",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "depositor",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "fee_refunded",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "DepositStatus",
+			type: {
+				kind: "enum",
+				variants: [
+					{
+						name: "Pending",
+					},
+					{
+						name: "Processed",
+					},
+					{
+						name: "Refunded",
+					},
+					{
+						name: "Reclaimed",
+					},
+				],
+			},
+		},
+		{
+			name: "DepositUSDCArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "recipient_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
+					},
+					{
+						name: "destination_chain_id",
+						type: "u64",
+					},
+					{
+						name: "target_contract",
+						type: {
+							option: {
+								array: ["u8", 20],
+							},
+						},
+					},
+					{
+						name: "payload",
+						type: "bytes",
+					},
+					{
+						name: "reference",
+						type: {
+							option: {
+								array: ["u8", 32],
+							},
+						},
+					},
+					{
+						name: "client_nonce",
+						type: "u64",
+					},
+				],
+			},
+		},
+		{
+			name: "FeesWithdrawn",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "recipient",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "InitializeArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "tax",
+						type: "u16",
+					},
+					{
+						name: "max_tax",
+						type: "u16",
+					},
+					{
+						name: "validators",
+						type: {
+							vec: "pubkey",
+						},
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "ownership_transfer_window",
+						type: "i64",
+					},
+					{
+						name: "max_deposit_amount",
+						type: "u64",
+					},
+					{
+						name: "max_withdrawal_amount",
+						type: "u64",
+					},
+					{
+						name: "mode",
+						type: {
+							defined: {
+								name: "BridgeMode",
+							},
+						},
+					},
+					{
+						name: "permissioned",
+						type: "bool",
+					},
+				],
+			},
+		},
+		{
+			name: "LiquidityAdded",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "provider",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "shares",
+						type: "u64",
+					},
+					{
+						name: "total_liquidity",
+						type: "u64",
+					},
+					{
+						name: "total_shares",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "LiquidityRemoved",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "provider",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "shares",
+						type: "u64",
+					},
+					{
+						name: "total_liquidity",
+						type: "u64",
+					},
+					{
+						name: "total_shares",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "NativeDropReceipt",
+			docs: [
+				"How much a single recipient has received in native drops, seeded by the recipient.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "recipient",
+						type: "pubkey",
+					},
+					{
+						name: "total_dropped",
+						type: "u64",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "OwnerChanged",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "new_owner",
+						type: "pubkey",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "OwnershipTransferCancelled",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "cancelled_owner",
+						type: "pubkey",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "OwnershipTransferProposed",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "current_owner",
+						type: "pubkey",
+					},
+					{
+						name: "proposed_owner",
+						type: "pubkey",
+					},
+					{
+						name: "expires_at",
+						type: "i64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "PoolCreated",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "treasury",
+						type: "pubkey",
+					},
+					{
+						name: "usdc_mint",
+						type: "pubkey",
+					},
+					{
+						name: "mode",
+						type: {
+							defined: {
+								name: "BridgeMode",
+							},
+						},
+					},
+					{
+						name: "permissioned",
+						type: "bool",
+					},
+					{
+						name: "tax",
+						type: "u16",
+					},
+					{
+						name: "max_tax",
+						type: "u16",
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "max_deposit_amount",
+						type: "u64",
+					},
+					{
+						name: "max_withdrawal_amount",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "PoolState",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "owner",
+						type: "pubkey",
+					},
+					{
+						name: "proposed_owner",
+						type: "pubkey",
+					},
+					{
+						name: "ownership_proposed_at",
+						type: "i64",
+					},
+					{
+						name: "ownership_transfer_window",
+						type: "i64",
+					},
+					{
+						name: "usdc_mint",
+						type: "pubkey",
+					},
+					{
+						name: "paused",
+						type: "bool",
+					},
+					{
+						name: "mode",
+						type: {
+							defined: {
+								name: "BridgeMode",
+							},
+						},
+					},
+					{
+						name: "validators",
+						type: {
+							array: ["pubkey", 16],
+						},
+					},
+					{
+						name: "validator_joined_slots",
+						type: {
+							array: ["u64", 16],
+						},
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "validator_set_epoch",
+						type: "u64",
+					},
+					{
+						name: "rotation_requires_approval",
+						type: "bool",
+					},
+					{
+						name: "validator_set_grace_period",
+						type: "i64",
+					},
+					{
+						name: "heartbeat_stale_slots",
+						type: "u64",
+					},
+					{
+						name: "bond_unbonding_period",
+						type: "i64",
+					},
+					{
+						name: "tax",
+						type: "u16",
+					},
+					{
+						name: "max_tax",
+						type: "u16",
+					},
+					{
+						name: "total_deposited",
+						type: "u128",
+					},
+					{
+						name: "total_withdrawn",
+						type: "u128",
+					},
+					{
+						name: "total_fees_collected",
+						type: "u128",
+					},
+					{
+						name: "deposit_count",
+						type: "u64",
+					},
+					{
+						name: "withdrawal_count",
+						type: "u64",
+					},
+					{
+						name: "accumulated_fees",
+						type: "u64",
+					},
+					{
+						name: "deposit_sequence",
+						type: "u64",
+					},
+					{
+						name: "pending_deposit_liabilities",
+						type: "u64",
+					},
+					{
+						name: "deposit_processing_window",
+						type: "i64",
+					},
+					{
+						name: "reclaim_buffer",
+						type: "i64",
+					},
+					{
+						name: "refund_fees",
+						type: "bool",
+					},
+					{
+						name: "max_deposit_amount",
+						type: "u64",
+					},
+					{
+						name: "max_withdrawal_amount",
+						type: "u64",
+					},
+					{
+						name: "max_native_drop",
+						type: "u64",
+					},
+					{
+						name: "max_native_drop_per_recipient",
+						type: "u64",
+					},
+					{
+						name: "total_liquidity",
+						type: "u64",
+					},
+					{
+						name: "lp_fee_share_bps",
+						type: "u16",
+					},
+					{
+						name: "iou_head",
+						type: "u64",
+					},
+					{
+						name: "iou_tail",
+						type: "u64",
+					},
+					{
+						name: "pending_iou_liabilities",
+						type: "u64",
+					},
+					{
+						name: "cctp_token_messenger",
+						type: "pubkey",
+					},
+					{
+						name: "cctp_message_transmitter",
+						type: "pubkey",
+					},
+					{
+						name: "net_flow_threshold",
+						type: "u64",
+					},
+					{
+						name: "net_flow_window",
+						type: "i64",
+					},
+					{
+						name: "net_flow_window_start",
+						type: "i64",
+					},
+					{
+						name: "net_flow_deposited",
+						type: "u64",
+					},
+					{
+						name: "net_flow_withdrawn",
+						type: "u64",
+					},
+					{
+						name: "circuit_breaker_tripped",
+						type: "bool",
+					},
+					{
+						name: "compliance_authority",
+						type: "pubkey",
+					},
+					{
+						name: "permissioned",
+						type: "bool",
+					},
+					{
+						name: "reserves_snapshot_interval",
+						type: "u64",
+					},
+					{
+						name: "treasury",
+						type: "pubkey",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "PoolStateUpdated",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "treasury",
+						type: "pubkey",
+					},
+					{
+						name: "tax",
+						type: "u16",
+					},
+					{
+						name: "max_tax",
+						type: "u16",
+					},
+					{
+						name: "paused",
+						type: "bool",
+					},
+					{
+						name: "rotation_requires_approval",
+						type: "bool",
+					},
+					{
+						name: "validator_set_grace_period",
+						type: "i64",
+					},
+					{
+						name: "heartbeat_stale_slots",
+						type: "u64",
+					},
+					{
+						name: "bond_unbonding_period",
+						type: "i64",
+					},
+					{
+						name: "deposit_processing_window",
+						type: "i64",
+					},
+					{
+						name: "reclaim_buffer",
+						type: "i64",
+					},
+					{
+						name: "refund_fees",
+						type: "bool",
+					},
+					{
+						name: "ownership_transfer_window",
+						type: "i64",
+					},
+					{
+						name: "max_deposit_amount",
+						type: "u64",
+					},
+					{
+						name: "max_withdrawal_amount",
+						type: "u64",
+					},
+					{
+						name: "max_native_drop",
+						type: "u64",
+					},
+					{
+						name: "max_native_drop_per_recipient",
+						type: "u64",
+					},
+					{
+						name: "lp_fee_share_bps",
+						type: "u16",
+					},
+					{
+						name: "cctp_token_messenger",
+						type: "pubkey",
+					},
+					{
+						name: "cctp_message_transmitter",
+						type: "pubkey",
+					},
+					{
+						name: "net_flow_threshold",
+						type: "u64",
+					},
+					{
+						name: "net_flow_window",
+						type: "i64",
+					},
+					{
+						name: "compliance_authority",
+						type: "pubkey",
+					},
+					{
+						name: "permissioned",
+						type: "bool",
+					},
+					{
+						name: "reserves_snapshot_interval",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "PreviousValidatorSet",
+			docs: ["A validator set that was replaced, see `ValidatorSetSnapshot`."],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "validators",
+						type: {
+							array: ["pubkey", 16],
+						},
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "validator_set_epoch",
+						type: "u64",
+					},
+					{
+						name: "expires_at",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "ReservesHistory",
+			docs: [
+				"Ring buffer of the latest RESERVES_HISTORY_LEN reserve snapshots.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "snapshots",
+						type: {
+							array: [
+								{
+									defined: {
+										name: "ReservesSnapshotEntry",
+									},
+								},
+								32,
+							],
+						},
+					},
+					{
+						name: "count",
+						type: "u64",
+					},
+					{
+						name: "last_slot",
+						type: "u64",
+					},
+					{
+						name: "last_hash",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "ReservesSnapshot",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "pool_balance",
+						type: "u64",
+					},
+					{
+						name: "accumulated_fees",
+						type: "u64",
+					},
+					{
+						name: "pending_deposit_liabilities",
+						type: "u64",
+					},
+					{
+						name: "total_liquidity",
+						type: "u64",
+					},
+					{
+						name: "pending_iou_liabilities",
+						type: "u64",
+					},
+					{
+						name: "total_deposited",
+						type: "u128",
+					},
+					{
+						name: "total_withdrawn",
+						type: "u128",
+					},
+					{
+						name: "previous_hash",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "hash",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
+		},
+		{
+			name: "ReservesSnapshotEntry",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+					{
+						name: "pool_balance",
+						type: "u64",
+					},
+					{
+						name: "accumulated_fees",
+						type: "u64",
+					},
+					{
+						name: "pending_deposit_liabilities",
+						type: "u64",
+					},
+					{
+						name: "total_liquidity",
+						type: "u64",
+					},
+					{
+						name: "pending_iou_liabilities",
+						type: "u64",
+					},
+					{
+						name: "total_deposited",
+						type: "u128",
+					},
+					{
+						name: "total_withdrawn",
+						type: "u128",
+					},
+					{
+						name: "hash",
+						type: {
+							array: ["u8", 32],
 						},
 					},
-				},
-			],
+				],
+			},
 		},
-	],
-	accounts: [
 		{
-			name: "PoolState",
-			discriminator: [247, 237, 227, 245, 215, 195, 222, 70],
+			name: "RotateValidatorSetArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "validators",
+						type: {
+							vec: "pubkey",
+						},
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "r",
+						type: {
+							array: [
+								{
+									array: ["u8", 32],
+								},
+								16,
+							],
+						},
+					},
+					{
+						name: "s",
+						type: {
+							array: [
+								{
+									array: ["u8", 32],
+								},
+								16,
+							],
+						},
+					},
+					{
+						name: "v",
+						type: {
+							array: ["u8", 16],
+						},
+					},
+				],
+			},
 		},
-	],
-	events: [
 		{
-			name: "FeesWithdrawn",
-			discriminator: [234, 15, 0, 119, 148, 241, 40, 21],
+			name: "SignedAttestation",
+			docs: [
+				"One validator's signature over a withdrawal, as it would have been submitted to `withdraw_usdc`.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "sender_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
+					},
+					{
+						name: "recipient",
+						type: "pubkey",
+					},
+					{
+						name: "validator_set_epoch",
+						type: "u64",
+					},
+					{
+						name: "reference",
+						type: {
+							option: {
+								array: ["u8", 32],
+							},
+						},
+					},
+					{
+						name: "source_chain_id",
+						type: "u64",
+					},
+					{
+						name: "relayer_fee",
+						type: "u64",
+					},
+					{
+						name: "native_drop",
+						type: "u64",
+					},
+					{
+						name: "r",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "s",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "v",
+						type: "u8",
+					},
+				],
+			},
 		},
 		{
-			name: "OwnerChanged",
-			discriminator: [34, 223, 103, 225, 239, 231, 51, 53],
+			name: "SlashValidatorArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "first",
+						type: {
+							defined: {
+								name: "SignedAttestation",
+							},
+						},
+					},
+					{
+						name: "second",
+						type: {
+							defined: {
+								name: "SignedAttestation",
+							},
+						},
+					},
+				],
+			},
 		},
 		{
-			name: "PoolCreated",
-			discriminator: [202, 44, 41, 88, 104, 220, 157, 82],
+			name: "SolReserve",
+			docs: [
+				"Lamports set aside for native drops. Fees accrue in USDC, so the owner converts",
+				"and tops this up through `fund_sol_reserve`, everything above rent can be dropped.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "total_funded",
+						type: "u64",
+					},
+					{
+						name: "total_dropped",
+						type: "u64",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
 		},
 		{
-			name: "PoolStateUpdated",
-			discriminator: [231, 22, 226, 177, 26, 215, 227, 97],
+			name: "SolReserveFunded",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "funder",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "balance",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
 			name: "USDCDeposited",
-			discriminator: [75, 200, 113, 3, 12, 197, 106, 215],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "depositor",
+						type: "pubkey",
+					},
+					{
+						name: "recipient_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "tax",
+						type: "u64",
+					},
+					{
+						name: "nonce",
+						type: "u64",
+					},
+					{
+						name: "deposit_id",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "deadline",
+						type: "i64",
+					},
+					{
+						name: "target_contract",
+						type: {
+							option: {
+								array: ["u8", 20],
+							},
+						},
+					},
+					{
+						name: "payload",
+						type: "bytes",
+					},
+					{
+						name: "reference",
+						type: {
+							option: {
+								array: ["u8", 32],
+							},
+						},
+					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
+					{
+						name: "client_nonce",
+						type: "u64",
+					},
+					{
+						name: "destination_chain_id",
+						type: "u64",
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
 			name: "USDCWithdrawn",
-			discriminator: [58, 59, 209, 122, 222, 203, 160, 217],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "recipient",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "relayer_fee",
+						type: "u64",
+					},
+					{
+						name: "reference",
+						type: {
+							option: {
+								array: ["u8", 32],
+							},
+						},
+					},
+					{
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "sender_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
+					},
+					{
+						name: "source_chain_id",
+						type: "u64",
+					},
+					{
+						name: "validator_set_epoch",
+						type: "u64",
+					},
+					{
+						name: "signer_bitmap",
+						type: "u16",
+					},
+					{
+						name: "payer",
+						type: "pubkey",
+					},
+					{
+						name: "native_drop",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
 			name: "ValidatorAdded",
-			discriminator: [67, 26, 43, 25, 58, 219, 99, 48],
-		},
-		{
-			name: "ValidatorRemoved",
-			discriminator: [133, 140, 80, 83, 7, 209, 70, 130],
-		},
-	],
-	errors: [
-		{
-			code: 6000,
-			name: "TooManyValidators",
-			msg: "Too many validators. Max is 10. Remove one before adding another one.",
-		},
-		{
-			code: 6001,
-			name: "ValidatorDoesNotExist",
-			msg: "This validator key doesn't exist.",
-		},
-		{
-			code: 6002,
-			name: "InvalidOwnershipChange",
-			msg: "Invalid Ownership Change, you are not the proposed owner.",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "required_validators",
+						type: "u8",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6003,
-			name: "PoolPaused",
-			msg: "Pool's Closed.",
+			name: "ValidatorBond",
+			docs: [
+				"A validator's bonded stake, held in the shared `bond_vault` token account.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "authority",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "unbonding_at",
+						type: "i64",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
 		},
 		{
-			code: 6004,
-			name: "WrongToken",
-			msg: "USDC Only.",
+			name: "ValidatorBondWithdrawn",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "authority",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6005,
-			name: "ZeroTax",
-			msg: "Tax must be at least 1 basis point.",
+			name: "ValidatorBonded",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "authority",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "total_bond",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6006,
-			name: "TaxFailed",
-			msg: "Tax could not be applied.",
+			name: "ValidatorHeartbeat",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6007,
-			name: "InvalidMessageHash",
-			msg: "Verification failed - failed to hash message.",
+			name: "ValidatorHeartbeatArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "r",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "s",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "v",
+						type: "u8",
+					},
+				],
+			},
 		},
 		{
-			code: 6008,
-			name: "InvalidSignature",
-			msg: "Verification failed - one tested signature failed.",
+			name: "ValidatorLiveness",
+			docs: ["Per-validator liveness record, seeded by the validator key."],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "last_seen_slot",
+						type: "u64",
+					},
+					{
+						name: "last_seen_timestamp",
+						type: "i64",
+					},
+					{
+						name: "stale",
+						type: "bool",
+					},
+					{
+						name: "flagged_slot",
+						type: "u64",
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
 		},
 		{
-			code: 6009,
-			name: "InvalidPublicKey",
-			msg: "Verification failed - one tested validator pubkey failed.",
+			name: "ValidatorRemoved",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "address",
+						type: "pubkey",
+					},
+					{
+						name: "required_validators",
+						type: "u8",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6010,
-			name: "NotEnoughSignatures",
-			msg: "Verification failed - not enough signatures present.",
+			name: "ValidatorSetRotated",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "previous_validators",
+						type: {
+							vec: "pubkey",
+						},
+					},
+					{
+						name: "previous_required_signatures",
+						type: "u8",
+					},
+					{
+						name: "validators",
+						type: {
+							vec: "pubkey",
+						},
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "validator_set_epoch",
+						type: "u64",
+					},
+					{
+						name: "previous_set_expires_at",
+						type: "i64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6011,
-			name: "MismatchedSignaturesAndKeys",
-			msg: "Verification failed - wrong number of sigs/validators.",
+			name: "ValidatorSetSnapshot",
+			docs: [
+				"The last few validator sets that were live before a change, kept around so",
+				"withdrawals they already signed can still land during the grace period.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "sets",
+						type: {
+							array: [
+								{
+									defined: {
+										name: "PreviousValidatorSet",
+									},
+								},
+								4,
+							],
+						},
+					},
+					{
+						name: "bump",
+						type: "u8",
+					},
+				],
+			},
 		},
 		{
-			code: 6012,
-			name: "DuplicateValidator",
-			msg: "Verification failed - attempted to pass duplicate validators.",
+			name: "ValidatorSetSnapshotRevoked",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "validator_set_epoch",
+						type: "u64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6013,
-			name: "InvalidValidatorAccount",
-			msg: "Invalid validator account supplied",
+			name: "ValidatorSlashed",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "reporter",
+						type: "pubkey",
+					},
+					{
+						name: "removed",
+						type: "bool",
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
 		{
-			code: 6014,
-			name: "FailedToValidate",
-			msg: "Failed to validate withdrawal",
+			name: "ValidatorStale",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "pool_state",
+						type: "pubkey",
+					},
+					{
+						name: "validator",
+						type: "pubkey",
+					},
+					{
+						name: "last_seen_slot",
+						type: "u64",
+					},
+					{
+						name: "slots_since_seen",
+						type: "u64",
+					},
+					{
+						name: "flagged_by",
+						type: "pubkey",
+					},
+					{
+						name: "removed",
+						type: "bool",
+					},
+					{
+						name: "required_signatures",
+						type: "u8",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
+					},
+				],
+			},
 		},
-	],
-	types: [
 		{
-			name: "ConfigUpdateArgs",
+			name: "ValidatorUnbonding",
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "treasury",
-						type: {
-							option: "pubkey",
-						},
+						name: "pool_state",
+						type: "pubkey",
 					},
 					{
-						name: "owner",
-						type: {
-							option: "pubkey",
-						},
+						name: "validator",
+						type: "pubkey",
 					},
 					{
-						name: "tax",
-						type: {
-							option: "u16",
-						},
+						name: "amount",
+						type: "u64",
 					},
 					{
-						name: "paused",
-						type: {
-							option: "bool",
-						},
+						name: "withdrawable_at",
+						type: "i64",
+					},
+					{
+						name: "timestamp",
+						type: "i64",
 					},
 				],
 			},
 		},
 		{
-			name: "DepositUSDCArgs",
+			name: "WithdrawCallArgs",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "target_program",
+						type: "pubkey",
+					},
+					{
+						name: "data",
+						type: "bytes",
+					},
+				],
+			},
+		},
+		{
+			name: "WithdrawUSDCArgs",
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "amount",
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "sender_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
+					},
+					{
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "validator_set_epoch",
 						type: "u64",
 					},
 					{
-						name: "recipient_evm_address",
+						name: "reference",
 						type: {
-							array: ["u8", 20],
+							option: {
+								array: ["u8", 32],
+							},
 						},
 					},
-				],
-			},
-		},
-		{
-			name: "FeesWithdrawn",
-			type: {
-				kind: "struct",
-				fields: [
 					{
-						name: "address",
-						type: "pubkey",
+						name: "source_chain_id",
+						type: "u64",
 					},
 					{
-						name: "recipient",
-						type: "pubkey",
+						name: "relayer_fee",
+						type: "u64",
 					},
 					{
-						name: "amount",
+						name: "native_drop",
 						type: "u64",
 					},
 					{
-						name: "timestamp",
-						type: "i64",
+						name: "r",
+						type: {
+							array: [
+								{
+									array: ["u8", 32],
+								},
+								16,
+							],
+						},
+					},
+					{
+						name: "s",
+						type: {
+							array: [
+								{
+									array: ["u8", 32],
+								},
+								16,
+							],
+						},
+					},
+					{
+						name: "v",
+						type: {
+							array: ["u8", 16],
+						},
 					},
 				],
 			},
 		},
 		{
-			name: "OwnerChanged",
+			name: "WithdrawalCallExecuted",
 			type: {
 				kind: "struct",
 				fields: [
@@ -627,32 +6608,30 @@ export const IDL = {
 						type: "pubkey",
 					},
 					{
-						name: "new_owner",
+						name: "recipient",
 						type: "pubkey",
 					},
 					{
-						name: "timestamp",
-						type: "i64",
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
 					},
-				],
-			},
-		},
-		{
-			name: "PoolCreated",
-			type: {
-				kind: "struct",
-				fields: [
 					{
-						name: "address",
+						name: "target_program",
 						type: "pubkey",
 					},
 					{
-						name: "treasury",
-						type: "pubkey",
+						name: "data_hash",
+						type: {
+							array: ["u8", 32],
+						},
 					},
 					{
-						name: "tax",
-						type: "u16",
+						name: "accounts_hash",
+						type: {
+							array: ["u8", 32],
+						},
 					},
 					{
 						name: "timestamp",
@@ -662,51 +6641,50 @@ export const IDL = {
 			},
 		},
 		{
-			name: "PoolState",
+			name: "WithdrawalIOU",
+			docs: [
+				"A verified withdrawal the pool couldn't pay yet, seeded by its place in the queue.",
+			],
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "owner",
-						type: "pubkey",
-					},
-					{
-						name: "proposed_owner",
-						type: "pubkey",
+						name: "sequence",
+						type: "u64",
 					},
 					{
-						name: "usdc_mint",
+						name: "recipient",
 						type: "pubkey",
 					},
 					{
-						name: "paused",
-						type: "bool",
+						name: "amount",
+						type: "u64",
 					},
 					{
-						name: "validators",
-						type: {
-							array: ["pubkey", 16],
-						},
+						name: "relayer_fee",
+						type: "u64",
 					},
 					{
-						name: "required_signatures",
-						type: "u8",
+						name: "payer",
+						type: "pubkey",
 					},
 					{
-						name: "tax",
-						type: "u16",
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
 					},
 					{
-						name: "total_volume",
+						name: "source_chain_id",
 						type: "u64",
 					},
 					{
-						name: "accumulated_fees",
-						type: "u64",
+						name: "created_at",
+						type: "i64",
 					},
 					{
-						name: "treasury",
-						type: "pubkey",
+						name: "frozen",
+						type: "bool",
 					},
 					{
 						name: "bump",
@@ -716,7 +6694,7 @@ export const IDL = {
 			},
 		},
 		{
-			name: "PoolStateUpdated",
+			name: "WithdrawalIOUFrozen",
 			type: {
 				kind: "struct",
 				fields: [
@@ -725,20 +6703,30 @@ export const IDL = {
 						type: "pubkey",
 					},
 					{
-						name: "proposed_owner",
-						type: "pubkey",
+						name: "sequence",
+						type: "u64",
 					},
 					{
-						name: "treasury",
+						name: "recipient",
 						type: "pubkey",
 					},
 					{
-						name: "tax",
-						type: "u16",
+						name: "amount",
+						type: "u64",
 					},
 					{
-						name: "paused",
-						type: "bool",
+						name: "relayer_fee",
+						type: "u64",
+					},
+					{
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "settler",
+						type: "pubkey",
 					},
 					{
 						name: "timestamp",
@@ -748,7 +6736,7 @@ export const IDL = {
 			},
 		},
 		{
-			name: "USDCDeposited",
+			name: "WithdrawalIOUSettled",
 			type: {
 				kind: "struct",
 				fields: [
@@ -757,26 +6745,30 @@ export const IDL = {
 						type: "pubkey",
 					},
 					{
-						name: "depositor",
-						type: "pubkey",
+						name: "sequence",
+						type: "u64",
 					},
 					{
-						name: "recipient_evm_address",
-						type: {
-							array: ["u8", 20],
-						},
+						name: "recipient",
+						type: "pubkey",
 					},
 					{
 						name: "amount",
 						type: "u64",
 					},
 					{
-						name: "tax",
+						name: "relayer_fee",
 						type: "u64",
 					},
 					{
 						name: "nonce",
-						type: "u64",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+					{
+						name: "settler",
+						type: "pubkey",
 					},
 					{
 						name: "timestamp",
@@ -786,7 +6778,7 @@ export const IDL = {
 			},
 		},
 		{
-			name: "USDCWithdrawn",
+			name: "WithdrawalQueued",
 			type: {
 				kind: "struct",
 				fields: [
@@ -794,6 +6786,10 @@ export const IDL = {
 						name: "address",
 						type: "pubkey",
 					},
+					{
+						name: "sequence",
+						type: "u64",
+					},
 					{
 						name: "recipient",
 						type: "pubkey",
@@ -803,52 +6799,48 @@ export const IDL = {
 						type: "u64",
 					},
 					{
-						name: "timestamp",
-						type: "i64",
+						name: "relayer_fee",
+						type: "u64",
 					},
-				],
-			},
-		},
-		{
-			name: "ValidatorAdded",
-			type: {
-				kind: "struct",
-				fields: [
 					{
-						name: "pool_state",
-						type: "pubkey",
+						name: "reference",
+						type: {
+							option: {
+								array: ["u8", 32],
+							},
+						},
 					},
 					{
-						name: "address",
-						type: "pubkey",
+						name: "nonce",
+						type: {
+							array: ["u8", 32],
+						},
 					},
 					{
-						name: "required_validators",
-						type: "u8",
+						name: "sender_evm_address",
+						type: {
+							array: ["u8", 20],
+						},
 					},
 					{
-						name: "timestamp",
-						type: "i64",
+						name: "source_chain_id",
+						type: "u64",
 					},
-				],
-			},
-		},
-		{
-			name: "ValidatorRemoved",
-			type: {
-				kind: "struct",
-				fields: [
 					{
-						name: "pool_state",
-						type: "pubkey",
+						name: "validator_set_epoch",
+						type: "u64",
 					},
 					{
-						name: "address",
+						name: "signer_bitmap",
+						type: "u16",
+					},
+					{
+						name: "payer",
 						type: "pubkey",
 					},
 					{
-						name: "required_validators",
-						type: "u8",
+						name: "native_drop",
+						type: "u64",
 					},
 					{
 						name: "timestamp",
@@ -858,20 +6850,13 @@ export const IDL = {
 			},
 		},
 		{
-			name: "WithdrawUSDCArgs",
+			name: "WithdrawalRecord",
+			docs: [
+				"Marks a withdrawal attestation as consumed, seeded by its source chain and nonce.",
+			],
 			type: {
 				kind: "struct",
 				fields: [
-					{
-						name: "amount",
-						type: "u64",
-					},
-					{
-						name: "sender_evm_address",
-						type: {
-							array: ["u8", 20],
-						},
-					},
 					{
 						name: "nonce",
 						type: {
@@ -879,32 +6864,24 @@ export const IDL = {
 						},
 					},
 					{
-						name: "r",
-						type: {
-							array: [
-								{
-									array: ["u8", 32],
-								},
-								16,
-							],
-						},
+						name: "source_chain_id",
+						type: "u64",
 					},
 					{
-						name: "s",
-						type: {
-							array: [
-								{
-									array: ["u8", 32],
-								},
-								16,
-							],
-						},
+						name: "recipient",
+						type: "pubkey",
 					},
 					{
-						name: "v",
-						type: {
-							array: ["u8", 16],
-						},
+						name: "amount",
+						type: "u64",
+					},
+					{
+						name: "slot",
+						type: "u64",
+					},
+					{
+						name: "bump",
+						type: "u8",
 					},
 				],
 			},
@@ -19,17 +19,32 @@ import {
 	createAssociatedTokenAccountInstruction,
 } from "@solana/spl-token";
 import BN from "bn.js";
+import { randomBytes } from "crypto";
 
 type PoolState = {
 	owner: PublicKey;
 	proposedOwner: PublicKey;
 	usdcMint: PublicKey;
 	paused: boolean;
+	circuitBreakerTripped: boolean; // paused by the net flow breaker, the owner unpauses to reset it
+	netFlowThreshold: BN;
+	complianceAuthority: PublicKey; // manages the denylist alongside the owner, and the allowlist alone
+	permissioned: boolean; // only allowlisted wallets can deposit or receive withdrawals
+	mode: { lockUnlock?: {}; burnMint?: {} };
 	validators: PublicKey[];
 	requiredSignatures: number;
 	tax: number;
-	totalVolume: BN;
+	totalDeposited: BN;
+	totalWithdrawn: BN;
+	totalFeesCollected: BN;
+	depositCount: BN;
+	withdrawalCount: BN;
 	accumulatedFees: BN;
+	depositSequence: BN;
+	totalLiquidity: BN;
+	lpFeeShareBps: number;
+	iouHead: BN;
+	iouTail: BN;
 	treasury: PublicKey;
 	bump: number;
 };
@@ -122,7 +137,11 @@ export class HyperboreBridgeSDK {
 	async depositUSDC(
 		amount: BN,
 		recipientEvmAddress: Uint8Array, // 20-byte EVM address
-		payer = this.provider.wallet.publicKey
+		destinationChainId: BN, // EVM chain id the funds go to
+		call: { targetContract: Uint8Array; payload: Uint8Array } | null = null, // bridge-and-call, up to 512 bytes of calldata
+		reference: Uint8Array | null = null, // 32-byte integrator reference id
+		payer = this.provider.wallet.publicKey,
+		clientNonce = new BN(randomBytes(8)) // unique per depositor, a collision just fails the deposit and can be retried
 	): Promise<string> {
 		// Ensure recipient EVM address is 20 bytes
 		if (recipientEvmAddress.length !== 20) {
@@ -146,6 +165,10 @@ export class HyperboreBridgeSDK {
 			true
 		);
 
+		// Each deposit gets a record seeded by the depositor and their nonce
+		const poolState = await this.getPoolState();
+		const depositRecord = this.depositRecordAddress(payer, clientNonce);
+
 		// Check if pool ATA exists, if not we'll create it
 		const poolAtaInfo = await this.connection.getAccountInfo(poolUsdcAta);
 
@@ -169,6 +192,11 @@ export class HyperboreBridgeSDK {
 				.depositUsdc({
 					amount: amount,
 					recipientEvmAddress: evmAddressArray,
+					destinationChainId,
+					targetContract: call ? Array.from(call.targetContract) : null,
+					payload: call ? Buffer.from(call.payload) : Buffer.alloc(0),
+					reference: reference ? Array.from(reference) : null,
+					clientNonce,
 				})
 				.accounts({
 					depositor: payer,
@@ -176,6 +204,13 @@ export class HyperboreBridgeSDK {
 					mintAccount: this.usdcMint,
 					depositorAta: userUsdcAta,
 					poolAta: poolUsdcAta,
+					depositRecord,
+					depositorDenylistEntry: this.solanaDenylistAddress(payer),
+					recipientDenylistEntry: this.evmDenylistAddress(evmAddressArray),
+					depositorAllowlistEntry: poolState.permissioned
+						? this.allowlistAddress(payer)
+						: null,
+					dailyStats: this.dailyStatsAddress(),
 					tokenProgram: TOKEN_PROGRAM_ID,
 					associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
 					systemProgram: web3.SystemProgram.programId,
@@ -196,11 +231,16 @@ export class HyperboreBridgeSDK {
 		senderEvmAddress: Uint8Array, // 20-byte EVM address
 		recipient: PublicKey,
 		nonce: Uint8Array, // 32-byte nonce
+		validatorSetEpoch: BN, // epoch of the validator set that signed
+		sourceChainId: BN, // EVM chain id the deposit was made on
+		relayerFee: BN, // part of amount paid to the payer for relaying, signed by validators
+		nativeDrop: BN, // lamports for the recipient's gas from the SOL reserve, signed by validators
 		signatures: {
 			r: Uint8Array[];
 			s: Uint8Array[];
 			v: number[];
 		},
+		reference: Uint8Array | null = null, // 32-byte integrator reference id
 		payer = this.provider.wallet.publicKey
 	): Promise<string> {
 		// Validate inputs
@@ -226,24 +266,55 @@ export class HyperboreBridgeSDK {
 			true
 		);
 
-		// Check if recipient ATA exists, if not we'll create it
-		const recipientAtaInfo = await this.connection.getAccountInfo(
-			recipientUsdcAta
+		// The previous validator set snapshot only exists after the first rotation
+		const [validatorSetSnapshot] = PublicKey.findProgramAddressSync(
+			[Buffer.from("validator_set_snapshot")],
+			this.programId
+		);
+		const snapshotInfo = await this.connection.getAccountInfo(
+			validatorSetSnapshot
 		);
 
-		const tx = new Transaction();
+		// Drops come out of the SOL reserve and are tracked per recipient
+		const [solReserve] = PublicKey.findProgramAddressSync(
+			[Buffer.from("sol_reserve")],
+			this.programId
+		);
+		const [nativeDropReceipt] = PublicKey.findProgramAddressSync(
+			[Buffer.from("native_drop"), recipient.toBuffer()],
+			this.programId
+		);
 
-		// If recipient ATA doesn't exist, add instruction to create it
-		if (!recipientAtaInfo) {
-			tx.add(
-				createAssociatedTokenAccountInstruction(
-					payer,
-					recipientUsdcAta,
-					recipient,
-					this.usdcMint
-				)
-			);
-		}
+		// Marks the attestation as used, so it can only ever be submitted once
+		const [withdrawalRecord] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("withdrawal"),
+				sourceChainId.toArrayLike(Buffer, "le", 8),
+				Buffer.from(nonce),
+			],
+			this.programId
+		);
+
+		// If the pool is short or others are already waiting, the withdrawal is queued as an IOU
+		const poolState = await this.getPoolState();
+		const poolBalance = await this.connection.getTokenAccountBalance(
+			poolUsdcAta
+		);
+		const available = new BN(poolBalance.value.amount).sub(
+			poolState.accumulatedFees
+		);
+		const queued =
+			poolState.iouHead.lt(poolState.iouTail) || available.lt(amount);
+		const [withdrawalIou] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("withdrawal_iou"),
+				poolState.iouTail.toArrayLike(Buffer, "le", 8),
+			],
+			this.programId
+		);
+
+		// The program creates the recipient ATA if it doesn't exist yet
+		const tx = new Transaction();
 
 		// Process signatures into expected format
 		const MAX_VALIDATORS = 16;
@@ -273,6 +344,11 @@ export class HyperboreBridgeSDK {
 					amount: amount,
 					senderEvmAddress: Array.from(senderEvmAddress),
 					nonce: Array.from(nonce),
+					validatorSetEpoch,
+					reference: reference ? Array.from(reference) : null,
+					sourceChainId,
+					relayerFee,
+					nativeDrop,
 					r: paddedR.map((r) => Array.from(r)),
 					s: paddedS.map((s) => Array.from(s)),
 					v: paddedV,
@@ -281,8 +357,23 @@ export class HyperboreBridgeSDK {
 					payer: payer,
 					poolState: this.poolStateAddress,
 					mintAccount: this.usdcMint,
+					recipientWallet: recipient,
 					recipientAta: recipientUsdcAta,
+					payerAta: relayerFee.isZero()
+						? null
+						: await getAssociatedTokenAddress(this.usdcMint, payer, false),
+					validatorSetSnapshot: snapshotInfo ? validatorSetSnapshot : null,
 					poolAta: poolUsdcAta,
+					solReserve: nativeDrop.isZero() ? null : solReserve,
+					nativeDropReceipt: nativeDrop.isZero() ? null : nativeDropReceipt,
+					withdrawalRecord,
+					withdrawalIou: queued ? withdrawalIou : null,
+					recipientDenylistEntry: this.solanaDenylistAddress(recipient),
+					senderDenylistEntry: this.evmDenylistAddress(senderEvmAddress),
+					recipientAllowlistEntry: poolState.permissioned
+						? this.allowlistAddress(recipient)
+						: null,
+					dailyStats: this.dailyStatsAddress(),
 					tokenProgram: TOKEN_PROGRAM_ID,
 					associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
 					systemProgram: web3.SystemProgram.programId,
@@ -295,18 +386,299 @@ export class HyperboreBridgeSDK {
 		return signature;
 	}
 
+	/**
+	 * Pay out the oldest queued withdrawal IOU once the pool has liquidity again. If its
+	 * recipient has been denied since, the IOU is frozen instead and the queue moves on
+	 */
+	async settleWithdrawalIOU(
+		settler = this.provider.wallet.publicKey
+	): Promise<string> {
+		const poolState = await this.getPoolState();
+		const [withdrawalIou] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("withdrawal_iou"),
+				poolState.iouHead.toArrayLike(Buffer, "le", 8),
+			],
+			this.programId
+		);
+		const iou = await (this.program.account as any).withdrawalIou.fetch(
+			withdrawalIou
+		);
+
+		return await this.program.methods
+			.settleWithdrawalIou()
+			.accounts({
+				settler,
+				poolState: this.poolStateAddress,
+				mintAccount: this.usdcMint,
+				poolAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					this.poolStateAddress,
+					true
+				),
+				withdrawalIou,
+				payer: iou.payer,
+				recipientWallet: iou.recipient,
+				recipientDenylistEntry: this.solanaDenylistAddress(iou.recipient),
+				recipientAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					iou.recipient,
+					false
+				),
+				payerAta: iou.relayerFee.isZero()
+					? null
+					: await getAssociatedTokenAddress(this.usdcMint, iou.payer, false),
+				tokenProgram: TOKEN_PROGRAM_ID,
+				associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+				systemProgram: web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Pay out an IOU that was frozen because its recipient was denied when it reached the
+	 * head of the queue, once the recipient is off the denylist again
+	 */
+	async releaseFrozenIOU(
+		sequence: BN,
+		settler = this.provider.wallet.publicKey
+	): Promise<string> {
+		const [withdrawalIou] = PublicKey.findProgramAddressSync(
+			[Buffer.from("withdrawal_iou"), sequence.toArrayLike(Buffer, "le", 8)],
+			this.programId
+		);
+		const iou = await (this.program.account as any).withdrawalIou.fetch(
+			withdrawalIou
+		);
+
+		return await this.program.methods
+			.releaseFrozenIou(sequence)
+			.accounts({
+				settler,
+				poolState: this.poolStateAddress,
+				mintAccount: this.usdcMint,
+				poolAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					this.poolStateAddress,
+					true
+				),
+				withdrawalIou,
+				payer: iou.payer,
+				recipientWallet: iou.recipient,
+				recipientDenylistEntry: this.solanaDenylistAddress(iou.recipient),
+				recipientAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					iou.recipient,
+					false
+				),
+				payerAta: iou.relayerFee.isZero()
+					? null
+					: await getAssociatedTokenAddress(this.usdcMint, iou.payer, false),
+				tokenProgram: TOKEN_PROGRAM_ID,
+				associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+				systemProgram: web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Record the pool's current reserves in the on-chain proof-of-reserves history
+	 */
+	async snapshotReserves(payer = this.provider.wallet.publicKey): Promise<string> {
+		const [reservesHistory] = PublicKey.findProgramAddressSync(
+			[Buffer.from("reserves_history")],
+			this.programId
+		);
+
+		return await this.program.methods
+			.snapshotReserves()
+			.accounts({
+				payer,
+				poolState: this.poolStateAddress,
+				mintAccount: this.usdcMint,
+				poolAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					this.poolStateAddress,
+					true
+				),
+				reservesHistory,
+				systemProgram: web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Provide USDC liquidity to the pool in exchange for LP shares
+	 */
+	async addLiquidity(
+		amount: BN,
+		provider = this.provider.wallet.publicKey
+	): Promise<string> {
+		const [lpMint] = PublicKey.findProgramAddressSync(
+			[Buffer.from("lp_mint")],
+			this.programId
+		);
+
+		return await this.program.methods
+			.addLiquidity(amount)
+			.accounts({
+				provider,
+				poolState: this.poolStateAddress,
+				mintAccount: this.usdcMint,
+				providerAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					provider,
+					false
+				),
+				poolAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					this.poolStateAddress,
+					true
+				),
+				lpMint,
+				providerLpAta: await getAssociatedTokenAddress(lpMint, provider, false),
+				tokenProgram: TOKEN_PROGRAM_ID,
+				associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+				systemProgram: web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Burn LP shares for their part of the pool's liquidity
+	 */
+	async removeLiquidity(
+		shares: BN,
+		provider = this.provider.wallet.publicKey
+	): Promise<string> {
+		const [lpMint] = PublicKey.findProgramAddressSync(
+			[Buffer.from("lp_mint")],
+			this.programId
+		);
+
+		return await this.program.methods
+			.removeLiquidity(shares)
+			.accounts({
+				provider,
+				poolState: this.poolStateAddress,
+				mintAccount: this.usdcMint,
+				providerAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					provider,
+					false
+				),
+				poolAta: await getAssociatedTokenAddress(
+					this.usdcMint,
+					this.poolStateAddress,
+					true
+				),
+				lpMint,
+				providerLpAta: await getAssociatedTokenAddress(lpMint, provider, false),
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.rpc();
+	}
+
 	/**
 	 * Get total pool volume statistics
 	 */
 	async getPoolStatistics() {
 		const poolState = await this.getPoolState();
 		return {
-			totalVolume: poolState.totalVolume,
+			totalDeposited: poolState.totalDeposited,
+			totalWithdrawn: poolState.totalWithdrawn,
+			totalFeesCollected: poolState.totalFeesCollected,
+			depositCount: poolState.depositCount,
+			withdrawalCount: poolState.withdrawalCount,
 			accumulatedFees: poolState.accumulatedFees,
 			taxBasisPoints: poolState.tax,
 		};
 	}
 
+	/**
+	 * Get per-day stats for the days the program still keeps, oldest first.
+	 * Returns an empty list before the first deposit or withdrawal.
+	 */
+	async getDailyStats() {
+		const info = await this.connection.getAccountInfo(this.dailyStatsAddress());
+		if (!info) return [];
+		const stats = this.program.coder.accounts.decode("dailyStats", info.data);
+		return stats.days
+			.filter((entry: { day: BN }) => !entry.day.isZero())
+			.sort((a: { day: BN }, b: { day: BN }) => a.day.cmp(b.day));
+	}
+
+	/**
+	 * Check whether a Solana wallet or 20 byte EVM address is on the denylist.
+	 * Deposits and withdrawals touching a denied address fail with AddressDenied.
+	 */
+	async isAddressDenied(address: PublicKey | Uint8Array | number[]): Promise<boolean> {
+		const entry =
+			address instanceof PublicKey
+				? this.solanaDenylistAddress(address)
+				: this.evmDenylistAddress(address);
+		return (await this.connection.getAccountInfo(entry)) !== null;
+	}
+
+	/**
+	 * Get a wallet's allowlist entry, or null if it has none.
+	 * Only permissioned pools look at the allowlist.
+	 */
+	async getAllowlistEntry(wallet: PublicKey) {
+		const info = await this.connection.getAccountInfo(
+			this.allowlistAddress(wallet)
+		);
+		if (!info) return null;
+		return this.program.coder.accounts.decode("allowlistEntry", info.data);
+	}
+
+	private allowlistAddress(wallet: PublicKey): PublicKey {
+		const [entry] = PublicKey.findProgramAddressSync(
+			[Buffer.from("allowlist"), wallet.toBuffer()],
+			this.programId
+		);
+		return entry;
+	}
+
+	private solanaDenylistAddress(address: PublicKey): PublicKey {
+		const [entry] = PublicKey.findProgramAddressSync(
+			[Buffer.from("denylist_solana"), address.toBuffer()],
+			this.programId
+		);
+		return entry;
+	}
+
+	private evmDenylistAddress(address: Uint8Array | number[]): PublicKey {
+		const [entry] = PublicKey.findProgramAddressSync(
+			[Buffer.from("denylist_evm"), Buffer.from(address)],
+			this.programId
+		);
+		return entry;
+	}
+
+	/**
+	 * Address of the record a deposit made with `clientNonce` lands in
+	 */
+	depositRecordAddress(depositor: PublicKey, clientNonce: BN): PublicKey {
+		const [depositRecord] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("deposit_record"),
+				depositor.toBuffer(),
+				clientNonce.toArrayLike(Buffer, "le", 8),
+			],
+			this.programId
+		);
+		return depositRecord;
+	}
+
+	private dailyStatsAddress(): PublicKey {
+		const [dailyStats] = PublicKey.findProgramAddressSync(
+			[Buffer.from("daily_stats")],
+			this.programId
+		);
+		return dailyStats;
+	}
+
 	/**
 	 * Get list of active validators
 	 */
@@ -6,11 +6,20 @@ export interface PoolState {
 	proposedOwner: PublicKey;
 	usdcMint: PublicKey;
 	paused: boolean;
+	circuitBreakerTripped: boolean; // paused by the net flow breaker, the owner unpauses to reset it
+	netFlowThreshold: BN;
+	complianceAuthority: PublicKey; // manages the denylist alongside the owner, and the allowlist alone
+	permissioned: boolean; // only allowlisted wallets can deposit or receive withdrawals
 	validators: PublicKey[];
 	requiredSignatures: number;
 	tax: number;
-	totalVolume: BN;
+	totalDeposited: BN;
+	totalWithdrawn: BN;
+	totalFeesCollected: BN;
+	depositCount: BN;
+	withdrawalCount: BN;
 	accumulatedFees: BN;
+	depositSequence: BN;
 	treasury: PublicKey;
 	bump: number;
 }
@@ -18,12 +27,22 @@ export interface PoolState {
 export interface DepositUSDCArgs {
 	amount: BN;
 	recipientEvmAddress: number[]; // 20-byte array
+	destinationChainId: BN; // EVM chain id
+	targetContract: number[] | null; // 20-byte EVM contract to call on arrival
+	payload: Buffer; // calldata for targetContract, up to 512 bytes
+	reference: number[] | null; // 32-byte integrator reference id
+	clientNonce: BN; // unique per depositor, seeds the deposit record
 }
 
 export interface WithdrawUSDCArgs {
 	amount: BN;
 	senderEvmAddress: number[]; // 20-byte array
 	nonce: number[]; // 32-byte array
+	validatorSetEpoch: BN; // epoch of the validator set that signed
+	reference: number[] | null; // 32-byte integrator reference id
+	sourceChainId: BN; // EVM chain id the deposit was made on
+	relayerFee: BN; // part of amount paid to the relayer
+	nativeDrop: BN; // lamports for the recipient's gas, 0 for none
 	r: number[][]; // Array of 32-byte arrays
 	s: number[][]; // Array of 32-byte arrays
 	v: number[]; // Array of numbers
@@ -51,6 +70,10 @@ export interface DepositEvent extends BridgeEvent {
 		amount: BN;
 		tax: BN;
 		nonce: BN;
+		sequence: BN;
+		clientNonce: BN; // seeds the deposit record together with the depositor
+		destinationChainId: BN;
+		slot: BN;
 		timestamp: number;
 	};
 }
@@ -61,12 +84,23 @@ export interface WithdrawEvent extends BridgeEvent {
 		address: string;
 		recipient: string;
 		amount: BN;
+		relayerFee: BN;
+		nonce: string; // 32-byte EVM-side nonce, hex
+		senderEvmAddress: string;
+		sourceChainId: BN;
+		signerBitmap: number; // bit i set if validator i signed
+		payer: string; // relayer that submitted the withdrawal
+		nativeDrop: BN; // lamports actually dropped, may be capped below the signed amount
 		timestamp: number;
 	};
 }
 
 export interface PoolStatistics {
-	totalVolume: BN;
+	totalDeposited: BN;
+	totalWithdrawn: BN;
+	totalFeesCollected: BN;
+	depositCount: BN;
+	withdrawalCount: BN;
 	accumulatedFees: BN;
 	taxBasisPoints: number;
 }
@@ -10,13 +10,23 @@ import BN from 'bn.js';
  * @param amount - Amount to withdraw as BN
  * @param sourceAddress - 20-byte EVM address
  * @param destAddress - Solana public key
+ * @param validatorSetEpoch - Epoch of the validator set that signed
+ * @param sourceChainId - EVM chain id the deposit was made on
+ * @param relayerFee - Part of amount paid to whoever submits the withdrawal
+ * @param nativeDrop - Lamports dropped to the recipient wallet from the SOL reserve
+ * @param reference - Optional 32-byte integrator reference id
  * @returns 32-byte message hash
  */
 export function buildMessage(
   nonce: Uint8Array,
   amount: BN,
   sourceAddress: Uint8Array,
-  destAddress: PublicKey
+  destAddress: PublicKey,
+  validatorSetEpoch: BN,
+  sourceChainId: BN,
+  relayerFee: BN,
+  nativeDrop: BN,
+  reference: Uint8Array | null = null
 ): Uint8Array {
   // Ensure inputs are the correct size
   if (nonce.length !== 32) {
@@ -26,9 +36,13 @@ export function buildMessage(
   if (sourceAddress.length !== 20) {
     throw new Error('Source address must be 20 bytes (EVM address)');
   }
+
+  if (reference && reference.length !== 32) {
+    throw new Error('Reference must be 32 bytes');
+  }
   
   // Create buffer to match Solana program's implementation
-  const message = Buffer.alloc(1 + 32 + 32 + 32 + 32); // Total 129 bytes
+  const message = Buffer.alloc(1 + 32 + 32 + 32 + 32 + 8 + 32 + 8 + 8 + 8); // Total 193 bytes
   
   // Current position in the buffer
   let position = 0;
@@ -58,6 +72,28 @@ export function buildMessage(
   const destAddressPadded = Buffer.alloc(32, 0);
   Buffer.from(destAddress.toBuffer()).copy(destAddressPadded, 32 - destAddress.toBuffer().length);
   destAddressPadded.copy(message, position);
+  position += 32;
+
+  // Add validator set epoch (8 bytes big-endian)
+  validatorSetEpoch.toBuffer('be', 8).copy(message, position);
+  position += 8;
+
+  // Add reference (32 bytes, zeros if none)
+  if (reference) {
+    Buffer.from(reference).copy(message, position);
+  }
+  position += 32;
+
+  // Add source chain id (8 bytes big-endian)
+  sourceChainId.toBuffer('be', 8).copy(message, position);
+  position += 8;
+
+  // Add relayer fee (8 bytes big-endian)
+  relayerFee.toBuffer('be', 8).copy(message, position);
+  position += 8;
+
+  // Add native drop (8 bytes big-endian)
+  nativeDrop.toBuffer('be', 8).copy(message, position);
   
   // Hash the message using SHA-256 (matching Solana program)
   const hash = createHash('sha256').update(message).digest();
@@ -65,6 +101,69 @@ export function buildMessage(
   return new Uint8Array(hash);
 }
 
+/**
+ * Wraps a buildMessage hash for a CCTP withdrawal, matching the program's build_cctp_message.
+ * Validators sign this instead of the plain message, so the payout is tied to the burn
+ * message being relayed.
+ *
+ * @param withdrawalMessage - 32-byte hash from buildMessage
+ * @param cctpMessage - CCTP burn message passed to withdrawUsdcCctp
+ * @returns 32-byte message hash
+ */
+export function buildCctpMessage(
+  withdrawalMessage: Uint8Array,
+  cctpMessage: Uint8Array
+): Uint8Array {
+  const hash = createHash('sha256')
+    .update(Buffer.from('cctp'))
+    .update(withdrawalMessage)
+    .update(createHash('sha256').update(cctpMessage).digest())
+    .digest();
+
+  return new Uint8Array(hash);
+}
+
+/**
+ * Recomputes a reserves snapshot hash, matching the program's build_reserves_snapshot_hash.
+ * Walking the events from the first snapshot and checking each hash proves nothing in the
+ * history was dropped or altered.
+ */
+export function buildReservesSnapshotHash(
+  previousHash: Uint8Array,
+  snapshot: {
+    sequence: BN;
+    slot: BN;
+    timestamp: BN;
+    poolBalance: BN;
+    accumulatedFees: BN;
+    pendingDepositLiabilities: BN;
+    totalLiquidity: BN;
+    pendingIouLiabilities: BN;
+    totalDeposited: BN;
+    totalWithdrawn: BN;
+  }
+): Uint8Array {
+  if (previousHash.length !== 32) {
+    throw new Error('Previous hash must be 32 bytes');
+  }
+
+  const hash = createHash('sha256')
+    .update(previousHash)
+    .update(snapshot.sequence.toBuffer('be', 8))
+    .update(snapshot.slot.toBuffer('be', 8))
+    .update(snapshot.timestamp.toTwos(64).toBuffer('be', 8))
+    .update(snapshot.poolBalance.toBuffer('be', 8))
+    .update(snapshot.accumulatedFees.toBuffer('be', 8))
+    .update(snapshot.pendingDepositLiabilities.toBuffer('be', 8))
+    .update(snapshot.totalLiquidity.toBuffer('be', 8))
+    .update(snapshot.pendingIouLiabilities.toBuffer('be', 8))
+    .update(snapshot.totalDeposited.toBuffer('be', 16))
+    .update(snapshot.totalWithdrawn.toBuffer('be', 16))
+    .digest();
+
+  return new Uint8Array(hash);
+}
+
 /**
  * Helper to convert hex string to Uint8Array
  */
@@ -1,10 +1,39 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { createHash, randomBytes } from "crypto";
 import { BridgeSol } from "../target/types/bridge_sol";
+import { MockLendingMarket } from "../target/types/mock_lending_market";
+import { MockTokenMessenger } from "../target/types/mock_token_messenger";
 import {
+	AuthorityType,
 	TOKEN_PROGRAM_ID,
 	createInitializeMintInstruction,
+	getAccount,
+	getAssociatedTokenAddressSync,
+	getMint,
+	getOrCreateAssociatedTokenAccount,
+	mintTo,
+	setAuthority,
 } from "@solana/spl-token";
+import {
+	DepositStatus,
+	MEMO_PROGRAM_ID,
+	Signatures,
+	Validator,
+	WithdrawalFields,
+	buildBondAuthorityMessage,
+	buildCallMessage,
+	buildDepositAttestationMessage,
+	buildDepositId,
+	buildHeartbeatMessage,
+	buildMessage,
+	buildRotationMessage,
+	newValidator,
+	sign,
+	signAttestation,
+	waitUntilPast,
+} from "./helpers";
 
 describe("bridge-sol", () => {
 	// Configure the client to use the local cluster.
@@ -14,6 +43,167 @@ describe("bridge-sol", () => {
 	const user = anchor.web3.Keypair.generate();
 	const treasury = anchor.web3.Keypair.generate();
 	const usdcMint = anchor.web3.Keypair.generate();
+	const validator = newValidator();
+	const [poolState] = anchor.web3.PublicKey.findProgramAddressSync(
+		[Buffer.from("pool_state")],
+		program.programId
+	);
+	const poolAta = anchor.utils.token.associatedAddress({
+		mint: usdcMint.publicKey,
+		owner: poolState,
+	});
+	const [lpMint] = anchor.web3.PublicKey.findProgramAddressSync(
+		[Buffer.from("lp_mint")],
+		program.programId
+	);
+	const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+		[program.programId.toBuffer()],
+		new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+	);
+
+	const initializeArgs = (overrides = {}) => ({
+		tax: 50,
+		maxTax: 500,
+		validators: [validator.key],
+		requiredSignatures: 1,
+		ownershipTransferWindow: new anchor.BN(7 * 24 * 60 * 60),
+		maxDepositAmount: new anchor.BN(0),
+		maxWithdrawalAmount: new anchor.BN(0),
+		mode: { lockUnlock: {} },
+		permissioned: false,
+		...overrides,
+	});
+
+	const updateArgs = (overrides = {}) => ({
+		treasury: null,
+		owner: null,
+		tax: null,
+		maxTax: null,
+		paused: null,
+		rotationRequiresApproval: null,
+		validatorSetGracePeriod: null,
+		heartbeatStaleSlots: null,
+		bondUnbondingPeriod: null,
+		depositProcessingWindow: null,
+		reclaimBuffer: null,
+		refundFees: null,
+		ownershipTransferWindow: null,
+		maxDepositAmount: null,
+		maxWithdrawalAmount: null,
+		maxNativeDrop: null,
+		maxNativeDropPerRecipient: null,
+		lpFeeShareBps: null,
+		cctpTokenMessenger: null,
+		cctpMessageTransmitter: null,
+		complianceAuthority: null,
+		permissioned: null,
+		netFlowThreshold: null,
+		netFlowWindow: null,
+		reservesSnapshotInterval: null,
+		...overrides,
+	});
+
+	const expectError = async (promise: Promise<unknown>, code: string) => {
+		try {
+			await promise;
+		} catch (err) {
+			assert.include(err.toString(), code);
+			return;
+		}
+		assert.fail(`expected ${code}`);
+	};
+
+	// log lines of a landed transaction
+	const transactionLogs = async (signature: string) => {
+		await program.provider.connection.confirmTransaction(signature, "confirmed");
+		const tx = await program.provider.connection.getTransaction(signature, {
+			commitment: "confirmed",
+			maxSupportedTransactionVersion: 0,
+		});
+		return tx.meta.logMessages;
+	};
+
+	// the first `name` event a landed transaction emitted
+	const transactionEvent = async (signature: string, name: string) => {
+		const parser = new anchor.EventParser(program.programId, program.coder);
+		for (const event of parser.parseLogs(await transactionLogs(signature))) {
+			if (event.name.toLowerCase() === name.toLowerCase()) {
+				return event.data;
+			}
+		}
+		assert.fail(`no ${name} event`);
+	};
+
+	// each deposit record is seeded by its depositor and a nonce the client picks
+	const depositRecordAddress = (
+		depositor: anchor.web3.PublicKey,
+		clientNonce: anchor.BN
+	) =>
+		anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("deposit_record"),
+				depositor.toBuffer(),
+				clientNonce.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		)[0];
+
+	const withdrawalArgs = async (
+		overrides: Partial<WithdrawalFields> = {}
+	): Promise<WithdrawalFields> => {
+		const state = await program.account.poolState.fetch(poolState);
+		return {
+			nonce: [...randomBytes(32)],
+			amount: new anchor.BN(1_000_000),
+			senderEvmAddress: Array(20).fill(4),
+			validatorSetEpoch: state.validatorSetEpoch,
+			reference: null,
+			sourceChainId: new anchor.BN(1),
+			relayerFee: new anchor.BN(0),
+			nativeDrop: new anchor.BN(0),
+			...overrides,
+		};
+	};
+
+	// signs `args` with the live set and submits it, `signatures` swaps in other signers
+	const withdraw = async (
+		recipient: anchor.web3.PublicKey,
+		args: WithdrawalFields,
+		{
+			accounts = {},
+			signatures = (hash: Uint8Array, set: anchor.web3.PublicKey[]) =>
+				sign(hash, [validator], set),
+			signers = [],
+		}: {
+			accounts?: Record<string, anchor.web3.PublicKey | null>;
+			signatures?: (
+				hash: Uint8Array,
+				set: anchor.web3.PublicKey[]
+			) => Signatures;
+			signers?: anchor.web3.Keypair[];
+		} = {}
+	) => {
+		const state = await program.account.poolState.fetch(poolState);
+		return program.methods
+			.withdrawUsdc(recipient, {
+				...args,
+				...signatures(buildMessage(args, recipient), state.validators),
+			})
+			.accountsPartial({
+				recipientWallet: recipient,
+				mintAccount: usdcMint.publicKey,
+				payerAta: null,
+				validatorSetSnapshot: null,
+				memoProgram: null,
+				solReserve: null,
+				nativeDropReceipt: null,
+				recipientAllowlistEntry: null,
+				withdrawalIou: null,
+				...accounts,
+			})
+			.signers(signers)
+			.rpc();
+	};
 
 	before(async () => {
 		// Create the USDC Mint account
@@ -43,6 +233,49 @@ describe("bridge-sol", () => {
 		await program.provider.sendAndConfirm(transaction, [usdcMint]); // Add `usdcMint` as a signer
 	});
 
+	it("Rejects a fee ceiling above the protocol maximum", async () => {
+		await expectError(
+			program.methods
+				.initialize(initializeArgs({ tax: 50, maxTax: 1_001 }))
+				.accounts({
+					usdcMint: usdcMint.publicKey,
+					treasury: treasury.publicKey,
+					programData,
+				})
+				.rpc(),
+			"TaxCeilingTooHigh"
+		);
+	});
+
+	it("Rejects an initial tax above the fee ceiling", async () => {
+		await expectError(
+			program.methods
+				.initialize(initializeArgs({ tax: 501, maxTax: 500 }))
+				.accounts({
+					usdcMint: usdcMint.publicKey,
+					treasury: treasury.publicKey,
+					programData,
+				})
+				.rpc(),
+			"TaxTooHigh"
+		);
+	});
+
+	it("Rejects burn/mint mode when the pool can't mint the token", async () => {
+		// the test mint's authority is the wallet, not the pool
+		await expectError(
+			program.methods
+				.initialize(initializeArgs({ mode: { burnMint: {} } }))
+				.accounts({
+					usdcMint: usdcMint.publicKey,
+					treasury: treasury.publicKey,
+					programData,
+				})
+				.rpc(),
+			"InvalidMintAuthority"
+		);
+	});
+
 	it("Is initialized!", async () => {
 		// Add your test here.
 		// Create a treasury account
@@ -54,15 +287,2536 @@ describe("bridge-sol", () => {
 		);
 		await program.provider.connection.confirmTransaction(txFund);
 
-		// Initialize the program with the treasury account
+		// Initialize the program with the treasury account.
+		// The deployer is the upgrade authority, which is allowed to initialize
 		const tx = await program.methods
-			.initialize(50)
+			.initialize(initializeArgs())
 			.accounts({
 				usdcMint: usdcMint.publicKey,
 				treasury: treasury.publicKey,
+				programData,
 			})
 			.signers([])
 			.rpc();
 		console.log("Your transaction signature", tx);
 	});
+
+	it("Accepts a tax exactly at the fee ceiling", async () => {
+		await program.methods
+			.updateState(updateArgs({ tax: 500 }))
+			.accounts({})
+			.rpc();
+		const state = await program.account.poolState.fetch(poolState);
+		assert.equal(state.tax, 500);
+	});
+
+	it("Rejects a tax one bp above the fee ceiling", async () => {
+		await expectError(
+			program.methods
+				.updateState(updateArgs({ tax: 501 }))
+				.accounts({})
+				.rpc(),
+			"TaxTooHigh"
+		);
+	});
+
+	it("Rejects a zero tax", async () => {
+		await expectError(
+			program.methods
+				.updateState(updateArgs({ tax: 0 }))
+				.accounts({})
+				.rpc(),
+			"ZeroTax"
+		);
+	});
+
+	it("Rejects raising the fee ceiling", async () => {
+		await expectError(
+			program.methods
+				.updateState(updateArgs({ maxTax: 501 }))
+				.accounts({})
+				.rpc(),
+			"TaxCeilingTooHigh"
+		);
+	});
+
+	it("Rejects lowering the fee ceiling under the live tax", async () => {
+		await expectError(
+			program.methods
+				.updateState(updateArgs({ maxTax: 499 }))
+				.accounts({})
+				.rpc(),
+			"TaxTooHigh"
+		);
+	});
+
+	it("Lowers the fee ceiling together with the tax", async () => {
+		await program.methods
+			.updateState(updateArgs({ tax: 100, maxTax: 100 }))
+			.accounts({})
+			.rpc();
+		const state = await program.account.poolState.fetch(poolState);
+		assert.equal(state.tax, 100);
+		assert.equal(state.maxTax, 100);
+	});
+
+	describe("ownership transfer", () => {
+		const propose = () =>
+			program.methods
+				.updateState(updateArgs({ owner: user.publicKey }))
+				.rpc();
+
+		const accept = () =>
+			program.methods
+				.acceptOwnership()
+				.accounts({ signer: user.publicKey })
+				.signers([user])
+				.rpc();
+
+		after(async () => {
+			await program.methods
+				.updateState(
+					updateArgs({ ownershipTransferWindow: new anchor.BN(7 * 24 * 60 * 60) })
+				)
+				.rpc();
+		});
+
+		it("Rejects cancelling when nothing is pending", async () => {
+			await expectError(
+				program.methods.cancelOwnershipTransfer().rpc(),
+				"NoPendingOwnershipTransfer"
+			);
+		});
+
+		it("Cancels a pending transfer so it can't be accepted", async () => {
+			await propose();
+			await program.methods.cancelOwnershipTransfer().rpc();
+			const state = await program.account.poolState.fetch(poolState);
+			assert.isTrue(state.proposedOwner.equals(anchor.web3.PublicKey.default));
+			assert.equal(state.ownershipProposedAt.toNumber(), 0);
+			await expectError(accept(), "InvalidOwnershipChange");
+		});
+
+		it("Rejects accepting after the window ran out", async () => {
+			await program.methods
+				.updateState(updateArgs({ ownershipTransferWindow: new anchor.BN(1) }))
+				.rpc();
+			await propose();
+			const state = await program.account.poolState.fetch(poolState);
+			await waitUntilPast(
+				program.provider.connection,
+				state.ownershipProposedAt.toNumber() + 1
+			);
+			await expectError(accept(), "OwnershipTransferExpired");
+
+			await program.methods.cancelOwnershipTransfer().rpc();
+			const after = await program.account.poolState.fetch(poolState);
+			assert.isTrue(after.owner.equals(program.provider.wallet.publicKey));
+		});
+	});
+
+	describe("liquidity providers", () => {
+		const wallet = program.provider.wallet.publicKey;
+		let walletAta: anchor.web3.PublicKey;
+
+		// LP value per share is totalLiquidity / supply, compared by cross-multiplying
+		const lpSnapshot = async () => {
+			const state = await program.account.poolState.fetch(poolState);
+			const mint = await getMint(program.provider.connection, lpMint);
+			return {
+				totalLiquidity: new anchor.BN(state.totalLiquidity.toString()),
+				supply: new anchor.BN(mint.supply.toString()),
+				accumulatedFees: new anchor.BN(state.accumulatedFees.toString()),
+			};
+		};
+
+		const assertNotDiluted = (
+			before: { totalLiquidity: anchor.BN; supply: anchor.BN },
+			after: { totalLiquidity: anchor.BN; supply: anchor.BN }
+		) => {
+			assert.isTrue(
+				after.totalLiquidity
+					.mul(before.supply)
+					.gte(before.totalLiquidity.mul(after.supply)),
+				"value per share went down"
+			);
+		};
+
+		before(async () => {
+			const ata = await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				wallet
+			);
+			walletAta = ata.address;
+			await mintTo(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				walletAta,
+				wallet,
+				1_000_000_000
+			);
+			await program.methods
+				.updateState(updateArgs({ lpFeeShareBps: 5_000 }))
+				.accounts({})
+				.rpc();
+		});
+
+		it("Rejects an LP fee share above 100%", async () => {
+			await expectError(
+				program.methods
+					.updateState(updateArgs({ lpFeeShareBps: 10_001 }))
+					.accounts({})
+					.rpc(),
+				"InvalidLpFeeShare"
+			);
+		});
+
+		it("Mints shares 1:1 to the first LP", async () => {
+			await program.methods
+				.addLiquidity(new anchor.BN(100_000_000))
+				.accounts({
+					mintAccount: usdcMint.publicKey,
+					providerAta: walletAta,
+				})
+				.rpc();
+			const after = await lpSnapshot();
+			assert.equal(after.totalLiquidity.toNumber(), 100_000_000);
+			assert.equal(after.supply.toNumber(), 100_000_000);
+		});
+
+		it("Credits the LP fee share without minting shares", async () => {
+			const before = await lpSnapshot();
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const depositRecord = depositRecordAddress(wallet, clientNonce);
+			// 1% tax on 10 USDC is 100_000, half of it to LPs
+			await program.methods
+				.depositUsdc({
+					amount: new anchor.BN(10_000_000),
+					recipientEvmAddress: Array(20).fill(1),
+					destinationChainId: new anchor.BN(1),
+					targetContract: null,
+					payload: Buffer.alloc(0),
+					reference: null,
+					clientNonce,
+				})
+				.accountsPartial({
+					depositor: wallet,
+					mintAccount: usdcMint.publicKey,
+					depositorAta: walletAta,
+					depositRecord,
+					depositorAllowlistEntry: null,
+					memoProgram: null,
+				})
+				.rpc();
+			const after = await lpSnapshot();
+			assert.equal(after.supply.toString(), before.supply.toString());
+			assert.equal(
+				after.totalLiquidity.sub(before.totalLiquidity).toNumber(),
+				50_000
+			);
+			assert.equal(
+				after.accumulatedFees.sub(before.accumulatedFees).toNumber(),
+				50_000
+			);
+			assertNotDiluted(before, after);
+		});
+
+		it("Prices a later LP off the grown liquidity", async () => {
+			const before = await lpSnapshot();
+			const amount = new anchor.BN(50_000_000);
+			await program.methods
+				.addLiquidity(amount)
+				.accounts({
+					mintAccount: usdcMint.publicKey,
+					providerAta: walletAta,
+				})
+				.rpc();
+			const after = await lpSnapshot();
+			const expectedShares = amount
+				.mul(before.supply)
+				.div(before.totalLiquidity);
+			assert.equal(
+				after.supply.sub(before.supply).toString(),
+				expectedShares.toString()
+			);
+			assertNotDiluted(before, after);
+		});
+
+		it("Never pays out more than a share is worth", async () => {
+			const before = await lpSnapshot();
+			const shares = new anchor.BN(33_333_333);
+			const balanceBefore = (
+				await getAccount(program.provider.connection, walletAta)
+			).amount;
+			await program.methods
+				.removeLiquidity(shares)
+				.accounts({
+					mintAccount: usdcMint.publicKey,
+					providerAta: walletAta,
+					providerLpAta: anchor.utils.token.associatedAddress({
+						mint: lpMint,
+						owner: wallet,
+					}),
+				})
+				.rpc();
+			const after = await lpSnapshot();
+			const received = (await getAccount(program.provider.connection, walletAta))
+				.amount - balanceBefore;
+			assert.isTrue(
+				new anchor.BN(received.toString())
+					.mul(before.supply)
+					.lte(shares.mul(before.totalLiquidity))
+			);
+			assertNotDiluted(before, after);
+		});
+
+		it("Rejects redeeming more shares than exist", async () => {
+			const { supply } = await lpSnapshot();
+			await expectError(
+				program.methods
+					.removeLiquidity(supply.addn(1))
+					.accounts({
+						mintAccount: usdcMint.publicKey,
+						providerAta: walletAta,
+						providerLpAta: anchor.utils.token.associatedAddress({
+							mint: lpMint,
+							owner: wallet,
+						}),
+					})
+					.rpc(),
+				"InsufficientShares"
+			);
+		});
+
+		it("Hands the last LP everything that is left", async () => {
+			const before = await lpSnapshot();
+			await program.methods
+				.removeLiquidity(before.supply)
+				.accounts({
+					mintAccount: usdcMint.publicKey,
+					providerAta: walletAta,
+					providerLpAta: anchor.utils.token.associatedAddress({
+						mint: lpMint,
+						owner: wallet,
+					}),
+				})
+				.rpc();
+			const after = await lpSnapshot();
+			assert.equal(after.supply.toNumber(), 0);
+			assert.equal(after.totalLiquidity.toNumber(), 0);
+		});
+	});
+
+	describe("cctp", () => {
+		const messenger = anchor.workspace
+			.mockTokenMessenger as Program<MockTokenMessenger>;
+		const wallet = program.provider.wallet.publicKey;
+		let walletAta: anchor.web3.PublicKey;
+
+		// the mock's deposit_for_burn accounts, in order, behind the program itself
+		const burnAccounts = () => [
+			{ pubkey: messenger.programId, isSigner: false, isWritable: false },
+			{ pubkey: poolState, isSigner: false, isWritable: false },
+			{ pubkey: poolAta, isSigner: false, isWritable: true },
+			{ pubkey: usdcMint.publicKey, isSigner: false, isWritable: true },
+			{ pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+		];
+
+		const depositCctp = async (destinationChainId = 1) => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const depositRecord = depositRecordAddress(wallet, clientNonce);
+			const signature = await program.methods
+				.depositUsdcCctp({
+					amount: new anchor.BN(10_000_000),
+					recipientEvmAddress: Array(20).fill(2),
+					destinationChainId: new anchor.BN(destinationChainId),
+					targetContract: null,
+					payload: Buffer.alloc(0),
+					reference: null,
+					clientNonce,
+				})
+				.accountsPartial({
+					depositor: wallet,
+					mintAccount: usdcMint.publicKey,
+					depositorAta: walletAta,
+					depositRecord,
+					depositorAllowlistEntry: null,
+					memoProgram: null,
+				})
+				.remainingAccounts(burnAccounts())
+				.rpc();
+			return { depositRecord, signature };
+		};
+
+		before(async () => {
+			const ata = await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				wallet
+			);
+			walletAta = ata.address;
+		});
+
+		it("Rejects CCTP deposits until a token messenger is configured", async () => {
+			await expectError(depositCctp(), "CctpNotConfigured");
+		});
+
+		it("Burns the bridged amount through CCTP and keeps the tax", async () => {
+			await program.methods
+				.updateState(
+					updateArgs({
+						cctpTokenMessenger: messenger.programId,
+						cctpMessageTransmitter: messenger.programId,
+					})
+				)
+				.accounts({})
+				.rpc();
+			const poolBefore = (await getAccount(program.provider.connection, poolAta))
+				.amount;
+			const supplyBefore = (
+				await getMint(program.provider.connection, usdcMint.publicKey)
+			).supply;
+
+			const { depositRecord, signature } = await depositCctp();
+
+			// 1% of 10 USDC stays as tax, the other 9.9 USDC is gone
+			const poolAfter = (await getAccount(program.provider.connection, poolAta))
+				.amount;
+			const supplyAfter = (
+				await getMint(program.provider.connection, usdcMint.publicKey)
+			).supply;
+			assert.equal((poolAfter - poolBefore).toString(), "100000");
+			assert.equal((supplyBefore - supplyAfter).toString(), "9900000");
+			const record = await program.account.depositRecord.fetch(depositRecord);
+			assert.deepEqual(record.status, { processed: {} });
+
+			// the validators only pick up `USDCDeposited`, Circle delivers this one
+			const logs = await transactionLogs(signature);
+			const parser = new anchor.EventParser(program.programId, program.coder);
+			const names = [...parser.parseLogs(logs)].map((event) =>
+				event.name.toLowerCase()
+			);
+			assert.notInclude(names, "usdcdeposited");
+			const event = await transactionEvent(signature, "CctpDepositBurned");
+			assert.deepEqual(event.depositId, record.depositId);
+			assert.equal(event.destinationDomain, 0); // Ethereum
+			const burn = [
+				...new anchor.EventParser(messenger.programId, messenger.coder).parseLogs(
+					logs
+				),
+			][0];
+			assert.equal(burn.data.destinationDomain, 0);
+		});
+
+		it("Rejects CCTP deposits to a chain Circle doesn't serve", async () => {
+			await expectError(depositCctp(999), "CctpUnsupportedChain");
+		});
+	});
+
+	describe("denylist", () => {
+		const wallet = program.provider.wallet.publicKey;
+		const sanctioned = Array(20).fill(0x5a);
+		const [entry] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("denylist_evm"), Buffer.from(sanctioned)],
+			program.programId
+		);
+		let walletAta: anchor.web3.PublicKey;
+
+		const depositTo = async (recipientEvmAddress: number[]) => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const depositRecord = depositRecordAddress(wallet, clientNonce);
+			return program.methods
+				.depositUsdc({
+					amount: new anchor.BN(1_000_000),
+					recipientEvmAddress,
+					destinationChainId: new anchor.BN(1),
+					targetContract: null,
+					payload: Buffer.alloc(0),
+					reference: null,
+					clientNonce,
+				})
+				.accountsPartial({
+					depositor: wallet,
+					mintAccount: usdcMint.publicKey,
+					depositorAta: walletAta,
+					depositRecord,
+					depositorAllowlistEntry: null,
+					memoProgram: null,
+				})
+				.rpc();
+		};
+
+		before(async () => {
+			const ata = await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				wallet
+			);
+			walletAta = ata.address;
+		});
+
+		it("Only lets the owner or compliance authority deny addresses", async () => {
+			await expectError(
+				program.methods
+					.denyEvmAddress(sanctioned)
+					.accounts({ authority: user.publicKey })
+					.signers([user])
+					.rpc(),
+				"UnauthorizedCompliance"
+			);
+		});
+
+		it("Blocks deposits to a denied EVM address until it is removed", async () => {
+			await program.methods.denyEvmAddress(sanctioned).rpc();
+			await expectError(depositTo(sanctioned), "AddressDenied");
+
+			await program.methods.undenyEvmAddress(sanctioned).rpc();
+			assert.isNull(await program.provider.connection.getAccountInfo(entry));
+			await depositTo(sanctioned);
+		});
+	});
+
+	describe("allowlist", () => {
+		const wallet = program.provider.wallet.publicKey;
+		const compliance = anchor.web3.Keypair.generate();
+		const [entry] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("allowlist"), wallet.toBuffer()],
+			program.programId
+		);
+		let walletAta: anchor.web3.PublicKey;
+
+		const deposit = async (
+			amount: number,
+			allowlistEntry: anchor.web3.PublicKey | null = entry
+		) => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const depositRecord = depositRecordAddress(wallet, clientNonce);
+			return program.methods
+				.depositUsdc({
+					amount: new anchor.BN(amount),
+					recipientEvmAddress: Array(20).fill(3),
+					destinationChainId: new anchor.BN(1),
+					targetContract: null,
+					payload: Buffer.alloc(0),
+					reference: null,
+					clientNonce,
+				})
+				.accountsPartial({
+					depositor: wallet,
+					mintAccount: usdcMint.publicKey,
+					depositorAta: walletAta,
+					depositRecord,
+					depositorAllowlistEntry: allowlistEntry,
+					memoProgram: null,
+				})
+				.rpc();
+		};
+
+		const setEntry = (dailyLimit: number, signer = compliance) =>
+			program.methods
+				.setAllowlistEntry(wallet, {
+					dailyLimit: new anchor.BN(dailyLimit),
+					expiresAt: new anchor.BN(0),
+				})
+				.accounts({ authority: signer.publicKey })
+				.signers([signer])
+				.rpc();
+
+		before(async () => {
+			const ata = await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				wallet
+			);
+			walletAta = ata.address;
+			await program.provider.sendAndConfirm(
+				new anchor.web3.Transaction().add(
+					anchor.web3.SystemProgram.transfer({
+						fromPubkey: wallet,
+						toPubkey: compliance.publicKey,
+						lamports: anchor.web3.LAMPORTS_PER_SOL,
+					})
+				)
+			);
+			await program.methods
+				.updateState(
+					updateArgs({
+						complianceAuthority: compliance.publicKey,
+						permissioned: true,
+					})
+				)
+				.rpc();
+		});
+
+		after(async () => {
+			await program.methods
+				.updateState(updateArgs({ permissioned: false }))
+				.rpc();
+		});
+
+		it("Won't hand the compliance role to the owner", async () => {
+			await expectError(
+				program.methods
+					.updateState(updateArgs({ complianceAuthority: wallet }))
+					.rpc(),
+				"ComplianceAuthorityIsOwner"
+			);
+		});
+
+		it("Keeps the allowlist out of the owner's hands", async () => {
+			await expectError(
+				setEntry(0, (program.provider as anchor.AnchorProvider).wallet.payer),
+				"NotComplianceAuthority"
+			);
+		});
+
+		it("Rejects wallets that aren't allowlisted", async () => {
+			await expectError(deposit(1_000_000, null), "NotAllowlisted");
+		});
+
+		it("Holds allowlisted wallets to their daily limit", async () => {
+			await setEntry(2_000_000);
+			await deposit(1_000_000);
+			await expectError(deposit(1_500_000), "AllowlistDailyLimitExceeded");
+
+			const allowed = await program.account.allowlistEntry.fetch(entry);
+			assert.equal(allowed.usedToday.toNumber(), 1_000_000);
+		});
+
+		it("Counts withdrawals against the recipient's daily limit", async () => {
+			const args = await withdrawalArgs({ amount: new anchor.BN(1_500_000) });
+			await expectError(withdraw(wallet, args), "NotAllowlisted");
+			await expectError(
+				withdraw(wallet, args, { accounts: { recipientAllowlistEntry: entry } }),
+				"AllowlistDailyLimitExceeded"
+			);
+			await withdraw(
+				wallet,
+				await withdrawalArgs({ amount: new anchor.BN(1_000_000) }),
+				{ accounts: { recipientAllowlistEntry: entry } }
+			);
+
+			const allowed = await program.account.allowlistEntry.fetch(entry);
+			assert.equal(allowed.usedToday.toNumber(), 2_000_000);
+		});
+
+		it("Shuts the wallet out again once removed", async () => {
+			await program.methods
+				.removeAllowlistEntry(wallet)
+				.accounts({ authority: compliance.publicKey })
+				.signers([compliance])
+				.rpc();
+			assert.isNull(await program.provider.connection.getAccountInfo(entry));
+			await expectError(deposit(1_000_000, null), "NotAllowlisted");
+		});
+	});
+
+	describe("circuit breaker", () => {
+		it("Rejects a zero net flow window", async () => {
+			await expectError(
+				program.methods
+					.updateState(updateArgs({ netFlowWindow: new anchor.BN(0) }))
+					.rpc(),
+				"InvalidNetFlowWindow"
+			);
+		});
+
+		it("Counts deposits against the current window", async () => {
+			await program.methods
+				.updateState(
+					updateArgs({ netFlowThreshold: new anchor.BN(1_000_000_000) })
+				)
+				.rpc();
+			const state = await program.account.poolState.fetch(poolState);
+			assert.equal(state.netFlowThreshold.toNumber(), 1_000_000_000);
+			assert.isFalse(state.circuitBreakerTripped);
+			// nothing has been refunded yet and the default window is a day, so every transfer so far
+			// is still inside it
+			assert.equal(
+				state.netFlowDeposited.toString(),
+				state.totalDeposited.toString()
+			);
+			assert.equal(
+				state.netFlowWithdrawn.toString(),
+				state.totalWithdrawn.toString()
+			);
+		});
+	});
+
+	describe("stats", () => {
+		const wallet = program.provider.wallet.publicKey;
+		const [dailyStatsAddress] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("daily_stats")],
+			program.programId
+		);
+
+		const todayEntry = async (day: anchor.BN) => {
+			const stats = await program.account.dailyStats.fetch(dailyStatsAddress);
+			return stats.days.find((entry) => entry.day.eq(day));
+		};
+
+		it("Counts a deposit in today's bucket and the lifetime totals", async () => {
+			const walletAta = (
+				await getOrCreateAssociatedTokenAccount(
+					program.provider.connection,
+					(program.provider as anchor.AnchorProvider).wallet.payer,
+					usdcMint.publicKey,
+					wallet
+				)
+			).address;
+			const stateBefore = await program.account.poolState.fetch(poolState);
+			const day = new anchor.BN(Math.floor(Date.now() / 1000 / 86_400));
+			const before = await todayEntry(day);
+
+			const clientNonce = new anchor.BN(randomBytes(8));
+			await program.methods
+				.depositUsdc({
+					amount: new anchor.BN(2_000_000),
+					recipientEvmAddress: Array(20).fill(4),
+					destinationChainId: new anchor.BN(1),
+					targetContract: null,
+					payload: Buffer.alloc(0),
+					reference: null,
+					clientNonce,
+				})
+				.accountsPartial({
+					depositor: wallet,
+					mintAccount: usdcMint.publicKey,
+					depositorAta: walletAta,
+					depositRecord: depositRecordAddress(wallet, clientNonce),
+					depositorAllowlistEntry: null,
+					memoProgram: null,
+				})
+				.rpc();
+
+			const stateAfter = await program.account.poolState.fetch(poolState);
+			const after = await todayEntry(day);
+			assert.isDefined(after);
+			const fee = stateAfter.totalFeesCollected.sub(stateBefore.totalFeesCollected);
+			assert.isAbove(fee.toNumber(), 0);
+			assert.equal(
+				stateAfter.depositCount.sub(stateBefore.depositCount).toNumber(),
+				1
+			);
+			assert.equal(
+				stateAfter.totalDeposited.sub(stateBefore.totalDeposited).toNumber(),
+				2_000_000
+			);
+			// the bucket may not exist yet if this is the first deposit of the day
+			const zero = new anchor.BN(0);
+			assert.equal(
+				after.depositCount.sub(before?.depositCount ?? zero).toNumber(),
+				1
+			);
+			assert.equal(
+				after.deposited.sub(before?.deposited ?? zero).toNumber(),
+				2_000_000
+			);
+			assert.equal(
+				after.feesCollected.sub(before?.feesCollected ?? zero).toString(),
+				fee.toString()
+			);
+		});
+	});
+
+	describe("proof of reserves", () => {
+		const [reservesHistory] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("reserves_history")],
+			program.programId
+		);
+
+		const snapshot = () =>
+			program.methods
+				.snapshotReserves()
+				.accounts({ mintAccount: usdcMint.publicKey })
+				.rpc();
+
+		const waitSlots = async (slots: number) => {
+			const slot = await program.provider.connection.getSlot();
+			while ((await program.provider.connection.getSlot()) < slot + slots) {
+				await new Promise((resolve) => setTimeout(resolve, 100));
+			}
+		};
+
+		it("Rejects a zero snapshot interval", async () => {
+			await expectError(
+				program.methods
+					.updateState(updateArgs({ reservesSnapshotInterval: new anchor.BN(0) }))
+					.rpc(),
+				"InvalidSnapshotInterval"
+			);
+		});
+
+		it("Chains each snapshot to the one before it", async () => {
+			await program.methods
+				.updateState(updateArgs({ reservesSnapshotInterval: new anchor.BN(5) }))
+				.rpc();
+			await snapshot();
+			// too soon, the interval hasn't passed yet
+			await expectError(snapshot(), "SnapshotTooSoon");
+			await waitSlots(5);
+			await snapshot();
+
+			const history = await program.account.reservesHistory.fetch(
+				reservesHistory
+			);
+			assert.equal(history.count.toNumber(), 2);
+			const [first, second] = history.snapshots;
+			const u64 = (n: anchor.BN) => n.toTwos(64).toArrayLike(Buffer, "be", 8);
+			const expected = createHash("sha256")
+				.update(Buffer.from(first.hash))
+				.update(u64(second.sequence))
+				.update(u64(second.slot))
+				.update(u64(second.timestamp))
+				.update(u64(second.poolBalance))
+				.update(u64(second.accumulatedFees))
+				.update(u64(second.pendingDepositLiabilities))
+				.update(u64(second.totalLiquidity))
+				.update(u64(second.pendingIouLiabilities))
+				.update(second.totalDeposited.toArrayLike(Buffer, "be", 16))
+				.update(second.totalWithdrawn.toArrayLike(Buffer, "be", 16))
+				.digest();
+			assert.deepEqual(Buffer.from(second.hash), expected);
+			assert.deepEqual(Buffer.from(history.lastHash), expected);
+			const state = await program.account.poolState.fetch(poolState);
+			assert.equal(second.totalLiquidity.toString(), state.totalLiquidity.toString());
+			assert.equal(
+				second.pendingIouLiabilities.toString(),
+				state.pendingIouLiabilities.toString()
+			);
+		});
+	});
+
+	describe("withdrawals", () => {
+		const wallet = program.provider.wallet.publicKey;
+		let walletAta: anchor.web3.PublicKey;
+
+		before(async () => {
+			const ata = await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				wallet
+			);
+			walletAta = ata.address;
+			// liquidity for the withdrawals below
+			await mintTo(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				poolAta,
+				wallet,
+				100_000_000
+			);
+		});
+
+		it("Pays out a withdrawal signed by the validator set", async () => {
+			const before = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			await withdraw(wallet, await withdrawalArgs());
+			const after = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			assert.equal((after - before).toString(), "1000000");
+		});
+
+		it("Creates the token account of a first-time recipient", async () => {
+			const newcomer = anchor.web3.Keypair.generate().publicKey;
+			const newcomerAta = anchor.utils.token.associatedAddress({
+				mint: usdcMint.publicKey,
+				owner: newcomer,
+			});
+			assert.isNull(await program.provider.connection.getAccountInfo(newcomerAta));
+
+			await withdraw(newcomer, await withdrawalArgs());
+			const account = await getAccount(program.provider.connection, newcomerAta);
+			assert.isTrue(account.owner.equals(newcomer));
+			assert.equal(account.amount.toString(), "1000000");
+		});
+
+		it("Emits everything an indexer needs to match the EVM-side deposit", async () => {
+			const args = await withdrawalArgs({ reference: [...randomBytes(32)] });
+			const signature = await withdraw(wallet, args);
+			const event = await transactionEvent(signature, "USDCWithdrawn");
+
+			assert.isTrue(event.address.equals(poolState));
+			assert.isTrue(event.recipient.equals(wallet));
+			assert.isTrue(event.payer.equals(wallet));
+			assert.equal(event.amount.toString(), args.amount.toString());
+			assert.equal(event.relayerFee.toNumber(), 0);
+			assert.equal(event.nativeDrop.toNumber(), 0);
+			assert.deepEqual(event.nonce, args.nonce);
+			assert.deepEqual(event.reference, args.reference);
+			assert.deepEqual(event.senderEvmAddress, args.senderEvmAddress);
+			assert.equal(event.sourceChainId.toNumber(), 1);
+			assert.equal(
+				event.validatorSetEpoch.toString(),
+				args.validatorSetEpoch.toString()
+			);
+			// the only validator sits at index 0
+			assert.equal(event.signerBitmap, 1);
+		});
+
+		it("Rejects a withdrawal signed by a key outside the set", async () => {
+			const outsider = newValidator();
+			await expectError(
+				withdraw(wallet, await withdrawalArgs(), {
+					// the outsider's signature sits in the slot of the real validator
+					signatures: (hash) => sign(hash, [outsider], [outsider.key]),
+				}),
+				"FailedToValidate"
+			);
+		});
+
+		it("Writes the reference as a memo and needs it signed", async () => {
+			const reference = [...randomBytes(32)];
+			const args = await withdrawalArgs({ reference });
+			// a relayer can't attach a reference the validators didn't sign
+			await expectError(
+				withdraw(wallet, args, {
+					signatures: (_, set) =>
+						sign(buildMessage({ ...args, reference: null }, wallet), [validator], set),
+				}),
+				"FailedToValidate"
+			);
+
+			const signature = await withdraw(wallet, args, {
+				accounts: { memoProgram: MEMO_PROGRAM_ID },
+			});
+			const logs = await transactionLogs(signature);
+			assert.isTrue(
+				logs.some((line) => line.includes(Buffer.from(reference).toString("hex")))
+			);
+		});
+
+		it("Never pays the same attestation twice", async () => {
+			const args = await withdrawalArgs();
+			await withdraw(wallet, args);
+			await expectError(withdraw(wallet, args), "already in use");
+		});
+
+		describe("IOUs", () => {
+			const first = anchor.web3.Keypair.generate().publicKey;
+			const second = anchor.web3.Keypair.generate().publicKey;
+			let head: anchor.BN;
+			let owed: anchor.BN;
+
+			const iouAddress = (sequence: anchor.BN) =>
+				anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("withdrawal_iou"), sequence.toArrayLike(Buffer, "le", 8)],
+					program.programId
+				)[0];
+
+			const balanceOf = async (owner: anchor.web3.PublicKey) =>
+				(
+					await getAccount(
+						program.provider.connection,
+						anchor.utils.token.associatedAddress({ mint: usdcMint.publicKey, owner })
+					)
+				).amount.toString();
+
+			const denylistEntry = (address: anchor.web3.PublicKey) =>
+				anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("denylist_solana"), address.toBuffer()],
+					program.programId
+				)[0];
+
+			const settle = (iou: anchor.web3.PublicKey, recipient: anchor.web3.PublicKey) =>
+				program.methods
+					.settleWithdrawalIou()
+					.accountsPartial({
+						withdrawalIou: iou,
+						payer: wallet,
+						recipientWallet: recipient,
+						recipientDenylistEntry: denylistEntry(recipient),
+						mintAccount: usdcMint.publicKey,
+						payerAta: null,
+					})
+					.rpc();
+
+			const release = (sequence: anchor.BN, recipient: anchor.web3.PublicKey) =>
+				program.methods
+					.releaseFrozenIou(sequence)
+					.accountsPartial({
+						payer: wallet,
+						recipientWallet: recipient,
+						recipientDenylistEntry: denylistEntry(recipient),
+						mintAccount: usdcMint.publicKey,
+						payerAta: null,
+					})
+					.rpc();
+
+			it("Queues a withdrawal the pool can't cover and everything behind it", async () => {
+				const state = await program.account.poolState.fetch(poolState);
+				head = state.iouTail;
+				const pool = (await getAccount(program.provider.connection, poolAta)).amount;
+				// one USDC more than the LPs' side of the pool holds
+				owed = new anchor.BN(pool.toString())
+					.sub(state.accumulatedFees)
+					.addn(1_000_000);
+				await withdraw(first, await withdrawalArgs({ amount: owed }), {
+					accounts: { withdrawalIou: iouAddress(head) },
+				});
+
+				// the pool could pay this one, but it has to wait its turn
+				await expectError(
+					withdraw(second, await withdrawalArgs()),
+					"InsufficientLiquidity"
+				);
+				await withdraw(second, await withdrawalArgs(), {
+					accounts: { withdrawalIou: iouAddress(head.addn(1)) },
+				});
+
+				const queued = await program.account.poolState.fetch(poolState);
+				assert.equal(queued.iouTail.sub(head).toNumber(), 2);
+				assert.equal(
+					queued.pendingIouLiabilities.toString(),
+					owed.addn(1_000_000).toString()
+				);
+				const iou = await program.account.withdrawalIou.fetch(iouAddress(head));
+				assert.isTrue(iou.recipient.equals(first));
+				assert.equal(iou.amount.toString(), owed.toString());
+			});
+
+			it("Settles strictly from the head of the queue", async () => {
+				await mintTo(
+					program.provider.connection,
+					(program.provider as anchor.AnchorProvider).wallet.payer,
+					usdcMint.publicKey,
+					poolAta,
+					wallet,
+					// both IOUs plus liquidity for the tests after these
+					owed.addn(50_000_000).toNumber()
+				);
+				await expectError(settle(iouAddress(head.addn(1)), second), "ConstraintSeeds");
+
+				// a recipient denied while queued is set aside, still owed
+				await program.methods.denySolanaAddress(first).rpc();
+				await settle(iouAddress(head), first);
+				const frozen = await program.account.withdrawalIou.fetch(iouAddress(head));
+				assert.isTrue(frozen.frozen);
+				const moved = await program.account.poolState.fetch(poolState);
+				assert.equal(moved.iouHead.toString(), head.addn(1).toString());
+
+				// so the IOU behind it still settles. The allowlist was checked when it was
+				// queued, a pool going permissioned since doesn't hold it up either.
+				await program.methods.updateState(updateArgs({ permissioned: true })).rpc();
+				await settle(iouAddress(head.addn(1)), second);
+				await program.methods.updateState(updateArgs({ permissioned: false })).rpc();
+				assert.equal(await balanceOf(second), "1000000");
+
+				// the frozen one is only paid once the entry is lifted
+				await expectError(release(head.addn(1), second), "AccountNotInitialized");
+				await expectError(release(head, first), "AddressDenied");
+				await program.methods.undenySolanaAddress(first).rpc();
+				assert.isNull(
+					await program.provider.connection.getAccountInfo(denylistEntry(first))
+				);
+				await release(head, first);
+				assert.equal(await balanceOf(first), owed.toString());
+				assert.isNull(
+					await program.provider.connection.getAccountInfo(iouAddress(head))
+				);
+
+				const state = await program.account.poolState.fetch(poolState);
+				assert.equal(state.iouHead.toString(), state.iouTail.toString());
+				assert.equal(state.pendingIouLiabilities.toNumber(), 0);
+			});
+		});
+
+		describe("native drops", () => {
+			const [solReserve] = anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("sol_reserve")],
+				program.programId
+			);
+
+			const dropTo = async (recipient: anchor.web3.PublicKey, nativeDrop: number) => {
+				const [nativeDropReceipt] = anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("native_drop"), recipient.toBuffer()],
+					program.programId
+				);
+				const signature = await withdraw(
+					recipient,
+					await withdrawalArgs({ nativeDrop: new anchor.BN(nativeDrop) }),
+					{ accounts: { solReserve, nativeDropReceipt } }
+				);
+				return transactionEvent(signature, "USDCWithdrawn");
+			};
+
+			before(async () => {
+				await program.methods
+					.fundSolReserve(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL))
+					.rpc();
+				await program.methods
+					.updateState(
+						updateArgs({
+							maxNativeDrop: new anchor.BN(10_000_000),
+							maxNativeDropPerRecipient: new anchor.BN(100_000_000),
+						})
+					)
+					.rpc();
+			});
+
+			after(async () => {
+				await program.methods
+					.updateState(updateArgs({ maxNativeDrop: new anchor.BN(0) }))
+					.rpc();
+			});
+
+			it("Drops SOL on a first-time recipient", async () => {
+				const newcomer = anchor.web3.Keypair.generate().publicKey;
+				const event = await dropTo(newcomer, 10_000_000);
+				assert.equal(event.nativeDrop.toNumber(), 10_000_000);
+				assert.equal(
+					await program.provider.connection.getBalance(newcomer),
+					10_000_000
+				);
+			});
+
+			it("Skips a drop that wouldn't make the wallet rent exempt", async () => {
+				const newcomer = anchor.web3.Keypair.generate().publicKey;
+				const event = await dropTo(newcomer, 1_000);
+				// the tokens still arrive, only the drop is left out
+				assert.equal(event.nativeDrop.toNumber(), 0);
+				assert.equal(event.amount.toNumber(), 1_000_000);
+				assert.equal(await program.provider.connection.getBalance(newcomer), 0);
+			});
+		});
+
+		describe("relayer fees", () => {
+			const relayer = anchor.web3.Keypair.generate();
+			let relayerAta: anchor.web3.PublicKey;
+
+			const relay = (args: WithdrawalFields, payerAta = relayerAta) =>
+				withdraw(wallet, args, {
+					accounts: { payer: relayer.publicKey, payerAta },
+					signers: [relayer],
+				});
+
+			before(async () => {
+				await program.provider.sendAndConfirm(
+					new anchor.web3.Transaction().add(
+						anchor.web3.SystemProgram.transfer({
+							fromPubkey: wallet,
+							toPubkey: relayer.publicKey,
+							lamports: anchor.web3.LAMPORTS_PER_SOL,
+						})
+					)
+				);
+				const ata = await getOrCreateAssociatedTokenAccount(
+					program.provider.connection,
+					(program.provider as anchor.AnchorProvider).wallet.payer,
+					usdcMint.publicKey,
+					relayer.publicKey
+				);
+				relayerAta = ata.address;
+			});
+
+			it("Splits the signed fee off to whoever relays it", async () => {
+				const walletBefore = (
+					await getAccount(program.provider.connection, walletAta)
+				).amount;
+				const relayerBefore = (
+					await getAccount(program.provider.connection, relayerAta)
+				).amount;
+				await relay(await withdrawalArgs({ relayerFee: new anchor.BN(100_000) }));
+				const walletAfter = (
+					await getAccount(program.provider.connection, walletAta)
+				).amount;
+				const relayerAfter = (
+					await getAccount(program.provider.connection, relayerAta)
+				).amount;
+				assert.equal((walletAfter - walletBefore).toString(), "900000");
+				assert.equal((relayerAfter - relayerBefore).toString(), "100000");
+			});
+
+			it("Rejects a fee over the withdrawal amount", async () => {
+				await expectError(
+					relay(await withdrawalArgs({ relayerFee: new anchor.BN(1_000_001) })),
+					"RelayerFeeTooHigh"
+				);
+			});
+
+			it("Rejects a fee with nowhere to pay it", async () => {
+				await expectError(
+					relay(
+						await withdrawalArgs({ relayerFee: new anchor.BN(100_000) }),
+						null
+					),
+					"MissingRelayerAccount"
+				);
+			});
+		});
+
+		describe("and call", () => {
+			const data = Buffer.from("arrived");
+			// the wallet pays for the transaction, so the program always sees it as writable
+			const callAccounts = [{ pubkey: wallet, isSigner: true, isWritable: true }];
+
+			// by default the memo program is the target, it only needs its accounts to sign
+			const withdrawAndCall = async (
+				args: WithdrawalFields,
+				signedAccounts: anchor.web3.AccountMeta[],
+				passedAccounts: anchor.web3.AccountMeta[],
+				call = { recipient: wallet, target: MEMO_PROGRAM_ID, data }
+			) => {
+				const state = await program.account.poolState.fetch(poolState);
+				const hash = buildCallMessage(
+					buildMessage(args, call.recipient),
+					call.target,
+					call.data,
+					signedAccounts
+				);
+				return program.methods
+					.withdrawUsdcAndCall(
+						call.recipient,
+						{ ...args, ...sign(hash, [validator], state.validators) },
+						{ targetProgram: call.target, data: call.data }
+					)
+					.accountsPartial({
+						recipientWallet: call.recipient,
+						mintAccount: usdcMint.publicKey,
+						payerAta: null,
+						validatorSetSnapshot: null,
+						memoProgram: null,
+						solReserve: null,
+						nativeDropReceipt: null,
+						recipientAllowlistEntry: null,
+						withdrawalIou: null,
+					})
+					.remainingAccounts([
+						{ pubkey: call.target, isSigner: false, isWritable: false },
+						...passedAccounts,
+					])
+					.rpc();
+			};
+
+			it("Pays out and calls the signed target with the signed accounts", async () => {
+				const before = (await getAccount(program.provider.connection, walletAta))
+					.amount;
+				await withdrawAndCall(await withdrawalArgs(), callAccounts, callAccounts);
+				const after = (await getAccount(program.provider.connection, walletAta))
+					.amount;
+				assert.equal((after - before).toString(), "1000000");
+			});
+
+			it("Deposits into a market that holds the arrival in its own PDA", async () => {
+				const market = anchor.workspace
+					.mockLendingMarket as Program<MockLendingMarket>;
+				const [custody] = anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("custody")],
+					market.programId
+				);
+				const [vault] = anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("vault")],
+					market.programId
+				);
+				const [position] = anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("position"), wallet.toBuffer()],
+					market.programId
+				);
+				const custodyAta = getAssociatedTokenAddressSync(
+					usdcMint.publicKey,
+					custody,
+					true
+				);
+				const args = await withdrawalArgs();
+				const deposit = await market.methods
+					.deposit({ amount: args.amount, beneficiary: wallet })
+					.accountsPartial({
+						payer: wallet,
+						custody,
+						custodyAta,
+						vault,
+						mint: usdcMint.publicKey,
+						position,
+					})
+					.instruction();
+				await withdrawAndCall(args, deposit.keys, deposit.keys, {
+					recipient: custody,
+					target: market.programId,
+					data: deposit.data,
+				});
+				assert.equal(
+					(await getAccount(program.provider.connection, vault)).amount.toString(),
+					"1000000"
+				);
+				assert.equal(
+					(
+						await getAccount(program.provider.connection, custodyAta)
+					).amount.toString(),
+					"0"
+				);
+				const record = await market.account.position.fetch(position);
+				assert.isTrue(record.owner.equals(wallet));
+				assert.equal(record.deposited.toNumber(), 1_000_000);
+			});
+
+			it("Rejects accounts the validators didn't sign off on", async () => {
+				await expectError(
+					withdrawAndCall(await withdrawalArgs(), [], callAccounts),
+					"FailedToValidate"
+				);
+				await expectError(
+					withdrawAndCall(
+						await withdrawalArgs(),
+						[{ ...callAccounts[0], isWritable: false }],
+						callAccounts
+					),
+					"FailedToValidate"
+				);
+			});
+		});
+	});
+
+	describe("deposit records", () => {
+		const wallet = program.provider.wallet.publicKey;
+		const [depositorDenylistEntry] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("denylist_solana"), wallet.toBuffer()],
+			program.programId
+		);
+		let walletAta: anchor.web3.PublicKey;
+
+		const depositInstruction = (
+			clientNonce: anchor.BN,
+			overrides = {},
+			accounts = {}
+		) =>
+			program.methods
+				.depositUsdc({
+					amount: new anchor.BN(1_000_000),
+					recipientEvmAddress: Array(20).fill(6),
+					destinationChainId: new anchor.BN(1),
+					targetContract: null,
+					payload: Buffer.alloc(0),
+					reference: null,
+					clientNonce,
+					...overrides,
+				})
+				.accountsPartial({
+					depositor: wallet,
+					mintAccount: usdcMint.publicKey,
+					depositorAta: walletAta,
+					depositRecord: depositRecordAddress(wallet, clientNonce),
+					depositorAllowlistEntry: null,
+					memoProgram: null,
+					...accounts,
+				});
+
+		const refund = async (depositRecord: anchor.web3.PublicKey) => {
+			const record = await program.account.depositRecord.fetch(depositRecord);
+			const state = await program.account.poolState.fetch(poolState);
+			const hash = buildDepositAttestationMessage(
+				record.depositId,
+				DepositStatus.refunded,
+				state.validatorSetEpoch
+			);
+			return program.methods
+				.refundDeposit({
+					validatorSetEpoch: state.validatorSetEpoch,
+					...sign(hash, [validator], state.validators),
+				})
+				.accountsPartial({
+					depositRecord,
+					depositorDenylistEntry,
+					mintAccount: usdcMint.publicKey,
+					depositorAta: walletAta,
+					validatorSetSnapshot: null,
+				})
+				.rpc();
+		};
+
+		before(async () => {
+			const ata = await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				wallet
+			);
+			walletAta = ata.address;
+		});
+
+		it("Keeps two deposits from one wallet in the same transaction apart", async () => {
+			const first = new anchor.BN(randomBytes(8));
+			const second = new anchor.BN(randomBytes(8));
+			await program.provider.sendAndConfirm(
+				new anchor.web3.Transaction().add(
+					await depositInstruction(first).instruction(),
+					await depositInstruction(second).instruction()
+				)
+			);
+
+			const a = await program.account.depositRecord.fetch(
+				depositRecordAddress(wallet, first)
+			);
+			const b = await program.account.depositRecord.fetch(
+				depositRecordAddress(wallet, second)
+			);
+			assert.isTrue(a.depositor.equals(wallet));
+			assert.equal(a.clientNonce.toString(), first.toString());
+			assert.equal(b.clientNonce.toString(), second.toString());
+			assert.equal(b.sequence.sub(a.sequence).toNumber(), 1);
+			assert.notDeepEqual(a.depositId, b.depositId);
+			assert.deepEqual(a.status, { pending: {} });
+		});
+
+		it("Rejects a client nonce the wallet already used", async () => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			await depositInstruction(clientNonce).rpc();
+			await expectError(depositInstruction(clientNonce).rpc(), "already in use");
+		});
+
+		it("Refunds a deposit the validators rejected, once", async () => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const depositRecord = depositRecordAddress(wallet, clientNonce);
+			await depositInstruction(clientNonce).rpc();
+			const record = await program.account.depositRecord.fetch(depositRecord);
+
+			const before = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			await refund(depositRecord);
+			const after = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			// fees aren't refunded by default, only the principal comes back
+			assert.equal((after - before).toString(), record.amount.toString());
+			const refunded = await program.account.depositRecord.fetch(depositRecord);
+			assert.deepEqual(refunded.status, { refunded: {} });
+
+			await expectError(refund(depositRecord), "DepositNotPending");
+		});
+
+		it("Holds a denied depositor's refund back until the entry is lifted", async () => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const depositRecord = depositRecordAddress(wallet, clientNonce);
+			await depositInstruction(clientNonce).rpc();
+
+			await program.methods.denySolanaAddress(wallet).rpc();
+			await expectError(refund(depositRecord), "AddressDenied");
+			const held = await program.account.depositRecord.fetch(depositRecord);
+			assert.deepEqual(held.status, { pending: {} });
+
+			await program.methods.undenySolanaAddress(wallet).rpc();
+			await refund(depositRecord);
+			const refunded = await program.account.depositRecord.fetch(depositRecord);
+			assert.deepEqual(refunded.status, { refunded: {} });
+		});
+
+		it("Rejects calldata over the payload limit", async () => {
+			await expectError(
+				depositInstruction(new anchor.BN(randomBytes(8)), {
+					targetContract: Array(20).fill(7),
+					payload: Buffer.alloc(513, 1),
+				}).rpc(),
+				"PayloadTooLarge"
+			);
+		});
+
+		it("Rejects calldata without a contract to call", async () => {
+			await expectError(
+				depositInstruction(new anchor.BN(randomBytes(8)), {
+					payload: Buffer.from("calldata"),
+				}).rpc(),
+				"PayloadWithoutTarget"
+			);
+		});
+
+		it("Commits the payload hash to the deposit id", async () => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const payload = Buffer.alloc(512, 9);
+			await depositInstruction(clientNonce, {
+				targetContract: Array(20).fill(7),
+				payload,
+			}).rpc();
+
+			const record = await program.account.depositRecord.fetch(
+				depositRecordAddress(wallet, clientNonce)
+			);
+			assert.deepEqual(
+				Buffer.from(record.payloadHash),
+				createHash("sha256").update(payload).digest()
+			);
+			assert.deepEqual(record.targetContract, Array(20).fill(7));
+			assert.deepEqual(
+				Buffer.from(record.depositId),
+				buildDepositId(poolState, record)
+			);
+			// the same deposit with other calldata would have a different id
+			assert.notDeepEqual(
+				Buffer.from(record.depositId),
+				buildDepositId(poolState, { ...record, payloadHash: Array(32).fill(0) })
+			);
+		});
+
+		it("Writes the reference as a memo and commits it to the deposit id", async () => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const reference = [...randomBytes(32)];
+			const signature = await depositInstruction(
+				clientNonce,
+				{ reference },
+				{ memoProgram: MEMO_PROGRAM_ID }
+			).rpc();
+
+			const logs = await transactionLogs(signature);
+			assert.isTrue(
+				logs.some((line) => line.includes(Buffer.from(reference).toString("hex")))
+			);
+			const record = await program.account.depositRecord.fetch(
+				depositRecordAddress(wallet, clientNonce)
+			);
+			assert.deepEqual(record.reference, reference);
+			assert.deepEqual(
+				Buffer.from(record.depositId),
+				buildDepositId(poolState, record)
+			);
+			assert.notDeepEqual(
+				Buffer.from(record.depositId),
+				buildDepositId(poolState, { ...record, reference: null })
+			);
+		});
+
+		it("Rejects a zero reclaim buffer", async () => {
+			await expectError(
+				program.methods
+					.updateState(updateArgs({ reclaimBuffer: new anchor.BN(0) }))
+					.rpc(),
+				"InvalidReclaimBuffer"
+			);
+		});
+
+		it("Only lets a deposit be reclaimed once the buffer past its deadline is over", async () => {
+			await program.methods
+				.updateState(
+					updateArgs({
+						depositProcessingWindow: new anchor.BN(1),
+						reclaimBuffer: new anchor.BN(10),
+					})
+				)
+				.rpc();
+			const clientNonce = new anchor.BN(randomBytes(8));
+			const depositRecord = depositRecordAddress(wallet, clientNonce);
+			await depositInstruction(clientNonce).rpc();
+			const record = await program.account.depositRecord.fetch(depositRecord);
+			const reclaim = () =>
+				program.methods
+					.reclaimExpiredDeposit()
+					.accountsPartial({
+						depositRecord,
+						depositorDenylistEntry,
+						mintAccount: usdcMint.publicKey,
+						depositorAta: walletAta,
+					})
+					.rpc();
+
+			// past the deadline an EVM mint may still be waiting on its attestation
+			await waitUntilPast(program.provider.connection, record.deadline.toNumber());
+			await expectError(reclaim(), "DepositNotExpired");
+
+			await waitUntilPast(
+				program.provider.connection,
+				record.deadline.toNumber() + 10
+			);
+			// a denied depositor can't take the funds back either
+			await program.methods.denySolanaAddress(wallet).rpc();
+			await expectError(reclaim(), "AddressDenied");
+			await program.methods.undenySolanaAddress(wallet).rpc();
+
+			const before = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			const stateBefore = await program.account.poolState.fetch(poolState);
+			await reclaim();
+			const after = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			assert.equal((after - before).toString(), record.amount.toString());
+			const reclaimed = await program.account.depositRecord.fetch(depositRecord);
+			assert.deepEqual(reclaimed.status, { reclaimed: {} });
+			// a reclaim is outflow as far as the circuit breaker is concerned
+			const stateAfter = await program.account.poolState.fetch(poolState);
+			assert.equal(
+				stateAfter.netFlowWithdrawn.sub(stateBefore.netFlowWithdrawn).toString(),
+				record.amount.toString()
+			);
+
+			await program.methods
+				.updateState(
+					updateArgs({
+						depositProcessingWindow: new anchor.BN(3 * 24 * 60 * 60),
+						reclaimBuffer: new anchor.BN(60 * 60),
+					})
+				)
+				.rpc();
+		});
+
+		it("Trips the circuit breaker on refunds and starts a fresh window on unpause", async () => {
+			const previous = await program.account.poolState.fetch(poolState);
+			const clientNonces = [
+				new anchor.BN(randomBytes(8)),
+				new anchor.BN(randomBytes(8)),
+			];
+			for (const clientNonce of clientNonces) {
+				await depositInstruction(clientNonce).rpc();
+			}
+			const [tripping, blocked] = clientNonces.map((clientNonce) =>
+				depositRecordAddress(wallet, clientNonce)
+			);
+
+			// a one second window that has run out by the time of the refund leaves the refund
+			// as the only flow in the next one, so any threshold below it trips the breaker
+			await program.methods
+				.updateState(
+					updateArgs({
+						netFlowThreshold: new anchor.BN(1),
+						netFlowWindow: new anchor.BN(1),
+					})
+				)
+				.rpc();
+			const state = await program.account.poolState.fetch(poolState);
+			await waitUntilPast(
+				program.provider.connection,
+				state.netFlowWindowStart.toNumber() + 1
+			);
+			const record = await program.account.depositRecord.fetch(tripping);
+			const signature = await refund(tripping);
+
+			const tripped = await program.account.poolState.fetch(poolState);
+			assert.isTrue(tripped.paused);
+			assert.isTrue(tripped.circuitBreakerTripped);
+			assert.equal(tripped.netFlowDeposited.toNumber(), 0);
+			assert.equal(tripped.netFlowWithdrawn.toString(), record.amount.toString());
+			const event = await transactionEvent(signature, "CircuitBreakerTripped");
+			assert.equal(event.withdrawn.toString(), record.amount.toString());
+			assert.equal(event.threshold.toNumber(), 1);
+			await expectError(refund(blocked), "PoolPaused");
+
+			await program.methods
+				.updateState(
+					updateArgs({
+						paused: false,
+						netFlowThreshold: previous.netFlowThreshold,
+						netFlowWindow: previous.netFlowWindow,
+					})
+				)
+				.rpc();
+			const reset = await program.account.poolState.fetch(poolState);
+			assert.isFalse(reset.paused);
+			assert.isFalse(reset.circuitBreakerTripped);
+			assert.equal(reset.netFlowDeposited.toNumber(), 0);
+			assert.equal(reset.netFlowWithdrawn.toNumber(), 0);
+			assert.isAtLeast(
+				reset.netFlowWindowStart.toNumber(),
+				tripped.netFlowWindowStart.toNumber()
+			);
+
+			await refund(blocked);
+			const refunded = await program.account.depositRecord.fetch(blocked);
+			assert.deepEqual(refunded.status, { refunded: {} });
+		});
+	});
+
+	describe("fees", () => {
+		it("Sweeps the protocol fees from the pool to the treasury", async () => {
+			const treasuryAta = await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				treasury.publicKey
+			);
+			const before = await program.account.poolState.fetch(poolState);
+			assert.isAbove(before.accumulatedFees.toNumber(), 0);
+
+			await program.methods
+				.withdrawFees()
+				.accounts({
+					treasury: treasury.publicKey,
+					mintAccount: usdcMint.publicKey,
+					treasuryAta: treasuryAta.address,
+				})
+				.rpc();
+
+			const after = await program.account.poolState.fetch(poolState);
+			assert.equal(after.accumulatedFees.toNumber(), 0);
+			const swept = (
+				await getAccount(program.provider.connection, treasuryAta.address)
+			).amount;
+			assert.equal(swept.toString(), before.accumulatedFees.toString());
+		});
+	});
+
+	describe("validator set", () => {
+		const wallet = program.provider.wallet.publicKey;
+		const second = newValidator();
+		const [validatorSetSnapshot] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("validator_set_snapshot")],
+			program.programId
+		);
+
+		// the outgoing set approves the rotation, `signatures` swaps in other signers
+		const rotate = async (
+			validators: anchor.web3.PublicKey[],
+			requiredSignatures: number,
+			signers: Validator[],
+			signatures = (hash: Uint8Array, set: anchor.web3.PublicKey[]) =>
+				sign(hash, signers, set)
+		) => {
+			const state = await program.account.poolState.fetch(poolState);
+			const hash = buildRotationMessage(
+				program.programId,
+				poolState,
+				state.validatorSetEpoch.addn(1),
+				validators,
+				requiredSignatures
+			);
+			return program.methods
+				.rotateValidatorSet({
+					validators,
+					requiredSignatures,
+					...signatures(hash, state.validators),
+				})
+				.accounts({})
+				.rpc();
+		};
+
+		it("Only lets rotation approval be switched on", async () => {
+			await program.methods
+				.updateState(updateArgs({ rotationRequiresApproval: true }))
+				.rpc();
+			await expectError(
+				program.methods
+					.updateState(updateArgs({ rotationRequiresApproval: false }))
+					.rpc(),
+				"RotationApprovalLocked"
+			);
+			// asking for it again is fine
+			await program.methods
+				.updateState(updateArgs({ rotationRequiresApproval: true }))
+				.rpc();
+			const state = await program.account.poolState.fetch(poolState);
+			assert.isTrue(state.rotationRequiresApproval);
+		});
+
+		it("Rejects a rotation the outgoing set didn't approve", async () => {
+			await expectError(
+				rotate([validator.key, second.key], 1, [], (hash) =>
+					sign(hash, [second], [second.key])
+				),
+				"FailedToValidate"
+			);
+		});
+
+		it("Rejects an approval without the rotation domain", async () => {
+			const state = await program.account.poolState.fetch(poolState);
+			// the untagged message a validator might sign for some other purpose
+			const untagged = createHash("sha256")
+				.update(Buffer.from("rotate"))
+				.update(state.validatorSetEpoch.addn(1).toArrayLike(Buffer, "be", 8))
+				.update(Buffer.from([1]))
+				.update(validator.key.toBuffer())
+				.update(second.key.toBuffer())
+				.digest();
+			await expectError(
+				rotate([validator.key, second.key], 1, [], (_, set) =>
+					sign(untagged, [validator], set)
+				),
+				"FailedToValidate"
+			);
+		});
+
+		it("Rotates with the outgoing set's approval and bumps the epoch", async () => {
+			const before = await program.account.poolState.fetch(poolState);
+			await rotate([validator.key, second.key], 1, [validator]);
+			const after = await program.account.poolState.fetch(poolState);
+			assert.equal(
+				after.validatorSetEpoch.toString(),
+				before.validatorSetEpoch.addn(1).toString()
+			);
+			assert.isTrue(after.validators[0].equals(validator.key));
+			assert.isTrue(after.validators[1].equals(second.key));
+			assert.equal(after.requiredSignatures, 1);
+		});
+
+		it("Still accepts the previous set during its grace period", async () => {
+			const state = await program.account.poolState.fetch(poolState);
+			await withdraw(
+				wallet,
+				await withdrawalArgs({
+					validatorSetEpoch: state.validatorSetEpoch.subn(1),
+				}),
+				{
+					accounts: { validatorSetSnapshot },
+					// the previous set was just the first validator
+					signatures: (hash) => sign(hash, [validator], [validator.key]),
+				}
+			);
+		});
+
+		it("Keeps more than one previous set", async () => {
+			const before = await program.account.poolState.fetch(poolState);
+			await rotate([validator.key, second.key], 1, [validator]);
+			const snapshot = await program.account.validatorSetSnapshot.fetch(
+				validatorSetSnapshot
+			);
+			const epochs = snapshot.sets
+				.filter((set) => set.requiredSignatures > 0)
+				.map((set) => set.validatorSetEpoch.toNumber());
+			assert.include(epochs, before.validatorSetEpoch.toNumber());
+			assert.include(epochs, before.validatorSetEpoch.toNumber() - 1);
+			// two rotations back still verifies
+			await withdraw(
+				wallet,
+				await withdrawalArgs({
+					validatorSetEpoch: before.validatorSetEpoch.subn(1),
+				}),
+				{
+					accounts: { validatorSetSnapshot },
+					signatures: (hash) => sign(hash, [validator], [validator.key]),
+				}
+			);
+		});
+
+		it("Rejects an epoch it never had", async () => {
+			const state = await program.account.poolState.fetch(poolState);
+			await expectError(
+				withdraw(
+					wallet,
+					await withdrawalArgs({
+						validatorSetEpoch: state.validatorSetEpoch.addn(5),
+					}),
+					{ accounts: { validatorSetSnapshot } }
+				),
+				"UnknownValidatorSetEpoch"
+			);
+		});
+
+		it("Only lets the owner revoke a previous set", async () => {
+			const state = await program.account.poolState.fetch(poolState);
+			await expectError(
+				program.methods
+					.revokeValidatorSetSnapshot(state.validatorSetEpoch.subn(1))
+					.accounts({ owner: user.publicKey })
+					.signers([user])
+					.rpc(),
+				"ConstraintRaw"
+			);
+		});
+
+		it("Stops accepting a revoked set", async () => {
+			const state = await program.account.poolState.fetch(poolState);
+			const epoch = state.validatorSetEpoch.subn(2);
+			await program.methods.revokeValidatorSetSnapshot(epoch).rpc();
+			await expectError(
+				withdraw(wallet, await withdrawalArgs({ validatorSetEpoch: epoch }), {
+					accounts: { validatorSetSnapshot },
+					signatures: (hash) => sign(hash, [validator], [validator.key]),
+				}),
+				"UnknownValidatorSetEpoch"
+			);
+			await expectError(
+				program.methods.revokeValidatorSetSnapshot(epoch).rpc(),
+				"UnknownValidatorSetEpoch"
+			);
+		});
+
+		it("Stops accepting a previous set once its grace period is over", async () => {
+			await program.methods
+				.updateState(updateArgs({ validatorSetGracePeriod: new anchor.BN(1) }))
+				.rpc();
+			const before = await program.account.poolState.fetch(poolState);
+			await rotate([validator.key, second.key], 1, [validator]);
+			const snapshot = await program.account.validatorSetSnapshot.fetch(
+				validatorSetSnapshot
+			);
+			const previous = snapshot.sets.find(
+				(set) =>
+					set.requiredSignatures > 0 &&
+					set.validatorSetEpoch.eq(before.validatorSetEpoch)
+			);
+			await waitUntilPast(
+				program.provider.connection,
+				previous.expiresAt.toNumber()
+			);
+			await expectError(
+				withdraw(
+					wallet,
+					await withdrawalArgs({ validatorSetEpoch: before.validatorSetEpoch }),
+					{
+						accounts: { validatorSetSnapshot },
+						signatures: (hash) => sign(hash, [validator], before.validators),
+					}
+				),
+				"ValidatorSetExpired"
+			);
+			await program.methods
+				.updateState(
+					updateArgs({ validatorSetGracePeriod: new anchor.BN(24 * 60 * 60) })
+				)
+				.rpc();
+		});
+
+		describe("liveness", () => {
+			const third = newValidator();
+
+			const liveness = (key: anchor.web3.PublicKey) =>
+				anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("validator_liveness"), key.toBuffer()],
+					program.programId
+				)[0];
+
+			// the wallet relays a heartbeat `signer` signed for `key`
+			const heartbeat = (
+				key: anchor.web3.PublicKey,
+				slot: number,
+				signer: Validator
+			) => {
+				const hash = buildHeartbeatMessage(
+					program.programId,
+					poolState,
+					key,
+					slot
+				);
+				const signature = sign(hash, [signer], [signer.key]);
+				return program.methods
+					.validatorHeartbeat({
+						validator: key,
+						slot: new anchor.BN(slot),
+						r: signature.r[0],
+						s: signature.s[0],
+						v: signature.v[0],
+					})
+					.rpc();
+			};
+
+			const flag = (key: anchor.web3.PublicKey) =>
+				program.methods
+					.flagStaleValidator()
+					.accounts({ validator: key })
+					.rpc();
+
+			const waitOutStaleWindow = async () => {
+				const slot = await program.provider.connection.getSlot();
+				while ((await program.provider.connection.getSlot()) <= slot + 2) {
+					await new Promise((resolve) => setTimeout(resolve, 100));
+				}
+			};
+
+			after(async () => {
+				await program.methods
+					.updateState(
+						updateArgs({ heartbeatStaleSlots: new anchor.BN(216_000) })
+					)
+					.rpc();
+			});
+
+			it("Records a heartbeat signed with the validator's key", async () => {
+				const slot = await program.provider.connection.getSlot();
+				await heartbeat(validator.key, slot, validator);
+				const record = await program.account.validatorLiveness.fetch(
+					liveness(validator.key)
+				);
+				assert.isTrue(record.validator.equals(validator.key));
+				assert.equal(record.lastSeenSlot.toNumber(), slot);
+				assert.isFalse(record.stale);
+			});
+
+			it("Rejects a heartbeat signed by another key", async () => {
+				const slot = await program.provider.connection.getSlot();
+				await expectError(
+					heartbeat(validator.key, slot + 1, second),
+					"FailedToValidate"
+				);
+			});
+
+			it("Rejects a replayed or future heartbeat", async () => {
+				const record = await program.account.validatorLiveness.fetch(
+					liveness(validator.key)
+				);
+				await expectError(
+					heartbeat(validator.key, record.lastSeenSlot.toNumber(), validator),
+					"InvalidHeartbeatSlot"
+				);
+				const slot = await program.provider.connection.getSlot();
+				await expectError(
+					heartbeat(validator.key, slot + 1_000, validator),
+					"InvalidHeartbeatSlot"
+				);
+			});
+
+			it("Counts a new validator from the slot it joined", async () => {
+				await rotate([validator.key, second.key, third.key], 1, [validator]);
+				// stale by the slot count if it were counted from slot 0, fresh counted from joining
+				const slot = await program.provider.connection.getSlot();
+				await program.methods
+					.updateState(
+						updateArgs({ heartbeatStaleSlots: new anchor.BN(slot - 10) })
+					)
+					.rpc();
+				await expectError(flag(third.key), "ValidatorNotStale");
+			});
+
+			it("Flags a validator that went quiet and takes it out of the set", async () => {
+				await program.methods
+					.updateState(updateArgs({ heartbeatStaleSlots: new anchor.BN(1) }))
+					.rpc();
+				await waitOutStaleWindow();
+				await flag(third.key);
+				const record = await program.account.validatorLiveness.fetch(
+					liveness(third.key)
+				);
+				assert.isTrue(record.stale);
+				const state = await program.account.poolState.fetch(poolState);
+				assert.isFalse(state.validators.some((v) => v.equals(third.key)));
+				assert.equal(state.requiredSignatures, 1);
+			});
+
+			it("Flags the last validator without removing it, and only once", async () => {
+				await rotate([validator.key], 1, [validator]);
+				await waitOutStaleWindow();
+				await flag(validator.key);
+				const state = await program.account.poolState.fetch(poolState);
+				assert.isTrue(state.validators[0].equals(validator.key));
+				assert.isTrue(
+					(await program.account.validatorLiveness.fetch(liveness(validator.key)))
+						.stale
+				);
+				await expectError(flag(validator.key), "ValidatorAlreadyStale");
+
+				// a heartbeat clears the flag
+				await heartbeat(
+					validator.key,
+					await program.provider.connection.getSlot(),
+					validator
+				);
+				assert.isFalse(
+					(await program.account.validatorLiveness.fetch(liveness(validator.key)))
+						.stale
+				);
+				await rotate([validator.key, second.key], 1, [validator]);
+			});
+
+			it("Flags a validator again after it is rotated back in", async () => {
+				await rotate([validator.key, second.key, third.key], 1, [validator]);
+				await waitOutStaleWindow();
+				await flag(third.key);
+				const state = await program.account.poolState.fetch(poolState);
+				assert.isFalse(state.validators.some((v) => v.equals(third.key)));
+			});
+		});
+
+		describe("bonds and slashing", () => {
+			const bonder = anchor.web3.Keypair.generate();
+			const fourth = newValidator();
+			const [insuranceFund] = anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("insurance_fund")],
+				program.programId
+			);
+			const [bondVault] = anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("bond_vault")],
+				program.programId
+			);
+			let bonderAta: anchor.web3.PublicKey;
+
+			const bondAddress = (key: anchor.web3.PublicKey) =>
+				anchor.web3.PublicKey.findProgramAddressSync(
+					[Buffer.from("validator_bond"), key.toBuffer()],
+					program.programId
+				)[0];
+
+			// `signer` names the bonder as the authority for `key`'s bond
+			const bond = (key: anchor.web3.PublicKey, amount: number, signer: Validator) => {
+				const signature = sign(
+					buildBondAuthorityMessage(
+						program.programId,
+						poolState,
+						key,
+						bonder.publicKey
+					),
+					[signer],
+					[signer.key]
+				);
+				return program.methods
+					.bondValidator({
+						validator: key,
+						amount: new anchor.BN(amount),
+						r: signature.r[0],
+						s: signature.s[0],
+						v: signature.v[0],
+					})
+					.accounts({
+						authority: bonder.publicKey,
+						mintAccount: usdcMint.publicKey,
+						authorityAta: bonderAta,
+					})
+					.signers([bonder])
+					.rpc();
+			};
+
+			// two different transfers `signer` attested under the same nonce
+			const equivocation = async (
+				signer: Validator,
+				overrides: Partial<WithdrawalFields> = {}
+			) => {
+				const args = await withdrawalArgs();
+				return {
+					first: signAttestation(signer, args, wallet),
+					second: signAttestation(
+						signer,
+						{ ...args, amount: args.amount.muln(2), ...overrides },
+						wallet
+					),
+				};
+			};
+
+			const slash = (
+				key: anchor.web3.PublicKey,
+				proof: Awaited<ReturnType<typeof equivocation>>
+			) =>
+				program.methods
+					.slashValidator(proof)
+					.accounts({ validator: key, mintAccount: usdcMint.publicKey })
+					.rpc();
+
+			// the insurance fund only exists after the first slash
+			const insuranceBalance = async () =>
+				(await program.provider.connection.getAccountInfo(insuranceFund))
+					? Number(
+							(await getAccount(program.provider.connection, insuranceFund))
+								.amount
+					  )
+					: 0;
+
+			before(async () => {
+				await program.provider.sendAndConfirm(
+					new anchor.web3.Transaction().add(
+						anchor.web3.SystemProgram.transfer({
+							fromPubkey: wallet,
+							toPubkey: bonder.publicKey,
+							lamports: anchor.web3.LAMPORTS_PER_SOL,
+						})
+					)
+				);
+				const ata = await getOrCreateAssociatedTokenAccount(
+					program.provider.connection,
+					(program.provider as anchor.AnchorProvider).wallet.payer,
+					usdcMint.publicKey,
+					bonder.publicKey
+				);
+				bonderAta = ata.address;
+				await mintTo(
+					program.provider.connection,
+					(program.provider as anchor.AnchorProvider).wallet.payer,
+					usdcMint.publicKey,
+					bonderAta,
+					wallet,
+					100_000_000
+				);
+			});
+
+			// first, while no validator has bonded and there is no bond vault yet
+			it("Slashes an active validator that never bonded", async () => {
+				const unbonded = newValidator();
+				const set = [validator.key, second.key, third.key, unbonded.key];
+				await rotate(set, 1, [validator]);
+				assert.isNull(await program.provider.connection.getAccountInfo(bondVault));
+
+				await slash(unbonded.key, await equivocation(unbonded));
+
+				const state = await program.account.poolState.fetch(poolState);
+				assert.isFalse(state.validators.some((key) => key.equals(unbonded.key)));
+				const record = await program.account.validatorBond.fetch(
+					bondAddress(unbonded.key)
+				);
+				assert.equal(record.amount.toNumber(), 0);
+			});
+
+			it("Rejects a bond authority the validator didn't name", async () => {
+				await expectError(bond(third.key, 5_000_000, second), "FailedToValidate");
+			});
+
+			it("Bonds a validator through the authority it signed for", async () => {
+				await bond(third.key, 5_000_000, third);
+				const record = await program.account.validatorBond.fetch(
+					bondAddress(third.key)
+				);
+				assert.isTrue(record.validator.equals(third.key));
+				assert.isTrue(record.authority.equals(bonder.publicKey));
+				assert.equal(record.amount.toNumber(), 5_000_000);
+				assert.equal(record.unbondingAt.toNumber(), 0);
+			});
+
+			it("Rejects unbonding while the validator is in the live set", async () => {
+				await expectError(
+					program.methods
+						.startUnbonding()
+						.accounts({
+							authority: bonder.publicKey,
+							validatorBond: bondAddress(third.key),
+						})
+						.signers([bonder])
+						.rpc(),
+					"ValidatorStillActive"
+				);
+			});
+
+			it("Rejects the same transfer signed twice", async () => {
+				const args = await withdrawalArgs();
+				const attestation = signAttestation(third, args, wallet);
+				await expectError(
+					slash(third.key, { first: attestation, second: attestation }),
+					"InvalidEquivocationProof"
+				);
+			});
+
+			it("Rejects the same nonce from two different chains", async () => {
+				await expectError(
+					slash(
+						third.key,
+						await equivocation(third, { sourceChainId: new anchor.BN(2) })
+					),
+					"InvalidEquivocationProof"
+				);
+			});
+
+			it("Rejects a proof signed by a different validator", async () => {
+				await expectError(
+					slash(third.key, await equivocation(second)),
+					"InvalidEquivocationProof"
+				);
+			});
+
+			it("Slashes the bond and drops the validator without touching the threshold", async () => {
+				await rotate([validator.key, second.key, third.key], 2, [validator]);
+				const insuranceBefore = await insuranceBalance();
+
+				await slash(third.key, await equivocation(third));
+
+				assert.equal((await insuranceBalance()) - insuranceBefore, 5_000_000);
+				const record = await program.account.validatorBond.fetch(
+					bondAddress(third.key)
+				);
+				assert.equal(record.amount.toNumber(), 0);
+				const state = await program.account.poolState.fetch(poolState);
+				assert.isFalse(state.validators.some((key) => key.equals(third.key)));
+				assert.equal(state.requiredSignatures, 2);
+				// the set that was live a moment ago doesn't verify its signatures anymore either
+				const snapshot = await program.account.validatorSetSnapshot.fetch(
+					validatorSetSnapshot
+				);
+				assert.isFalse(
+					snapshot.sets.some((set) =>
+						set.validators.some((key) => key.equals(third.key))
+					)
+				);
+
+				await rotate([validator.key, second.key], 1, [validator, second]);
+			});
+
+			it("Still slashes an unbonding validator after it was rotated out", async () => {
+				await rotate([validator.key, second.key, fourth.key], 1, [validator]);
+				await bond(fourth.key, 3_000_000, fourth);
+				await rotate([validator.key, second.key], 1, [validator]);
+
+				const unbond = (signer: anchor.web3.Keypair) =>
+					program.methods
+						.startUnbonding()
+						.accounts({
+							authority: signer.publicKey,
+							validatorBond: bondAddress(fourth.key),
+						})
+						.signers([signer])
+						.rpc();
+				await expectError(unbond(user), "NotBondAuthority");
+				await unbond(bonder);
+				await expectError(
+					program.methods
+						.withdrawBond()
+						.accounts({
+							authority: bonder.publicKey,
+							mintAccount: usdcMint.publicKey,
+							authorityAta: bonderAta,
+							validatorBond: bondAddress(fourth.key),
+						})
+						.signers([bonder])
+						.rpc(),
+					"BondStillLocked"
+				);
+
+				const insuranceBefore = await insuranceBalance();
+				const before = await program.account.poolState.fetch(poolState);
+				await slash(fourth.key, await equivocation(fourth));
+				assert.equal((await insuranceBalance()) - insuranceBefore, 3_000_000);
+				const after = await program.account.poolState.fetch(poolState);
+				assert.equal(
+					after.validatorSetEpoch.toString(),
+					before.validatorSetEpoch.toString()
+				);
+			});
+
+			it("Rejects slashing a validator with nothing left to lose", async () => {
+				await expectError(
+					slash(fourth.key, await equivocation(fourth)),
+					"NothingToSlash"
+				);
+			});
+
+			it("Pays an unbonded bond back to its authority", async () => {
+				await bond(second.key, 1_000_000, second);
+				await program.methods
+					.updateState(updateArgs({ bondUnbondingPeriod: new anchor.BN(1) }))
+					.rpc();
+				await rotate([validator.key], 1, [validator]);
+				await program.methods
+					.startUnbonding()
+					.accounts({
+						authority: bonder.publicKey,
+						validatorBond: bondAddress(second.key),
+					})
+					.signers([bonder])
+					.rpc();
+				const record = await program.account.validatorBond.fetch(
+					bondAddress(second.key)
+				);
+				await waitUntilPast(
+					program.provider.connection,
+					record.unbondingAt.toNumber()
+				);
+
+				const before = (await getAccount(program.provider.connection, bonderAta))
+					.amount;
+				await program.methods
+					.withdrawBond()
+					.accounts({
+						authority: bonder.publicKey,
+						mintAccount: usdcMint.publicKey,
+						authorityAta: bonderAta,
+						validatorBond: bondAddress(second.key),
+					})
+					.signers([bonder])
+					.rpc();
+				const after = (await getAccount(program.provider.connection, bonderAta))
+					.amount;
+				assert.equal((after - before).toString(), "1000000");
+				assert.isNull(
+					await program.provider.connection.getAccountInfo(
+						bondAddress(second.key)
+					)
+				);
+
+				await program.methods
+					.updateState(
+						updateArgs({ bondUnbondingPeriod: new anchor.BN(7 * 24 * 60 * 60) })
+					)
+					.rpc();
+			});
+		});
+	});
+
+	// after the bonds, so there is a bond vault to try burning from
+	describe("cctp burn accounts", () => {
+		const messenger = anchor.workspace
+			.mockTokenMessenger as Program<MockTokenMessenger>;
+		const wallet = program.provider.wallet.publicKey;
+		const [bondVault] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("bond_vault")],
+			program.programId
+		);
+
+		it("Rejects burning from a pool-owned vault other than the pool's account", async () => {
+			const clientNonce = new anchor.BN(randomBytes(8));
+			await expectError(
+				program.methods
+					.depositUsdcCctp({
+						amount: new anchor.BN(1_000_000),
+						recipientEvmAddress: Array(20).fill(2),
+						destinationChainId: new anchor.BN(1),
+						targetContract: null,
+						payload: Buffer.alloc(0),
+						reference: null,
+						clientNonce,
+					})
+					.accountsPartial({
+						depositor: wallet,
+						mintAccount: usdcMint.publicKey,
+						depositorAta: anchor.utils.token.associatedAddress({
+							mint: usdcMint.publicKey,
+							owner: wallet,
+						}),
+						depositRecord: depositRecordAddress(wallet, clientNonce),
+						depositorAllowlistEntry: null,
+						memoProgram: null,
+					})
+					.remainingAccounts([
+						{ pubkey: messenger.programId, isSigner: false, isWritable: false },
+						{ pubkey: poolState, isSigner: false, isWritable: false },
+						{ pubkey: bondVault, isSigner: false, isWritable: true },
+						{ pubkey: usdcMint.publicKey, isSigner: false, isWritable: true },
+						{ pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+					])
+					.rpc(),
+				"InvalidCctpTokenAccount"
+			);
+		});
+	});
+
+	// last, since it hands the test mint over to the mock for good
+	describe("cctp withdrawals", () => {
+		const messenger = anchor.workspace
+			.mockTokenMessenger as Program<MockTokenMessenger>;
+		const wallet = program.provider.wallet.publicKey;
+		const [minter] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("minter")],
+			messenger.programId
+		);
+
+		// a CCTP v1 burn message from Ethereum minting `amount` into the pool
+		const burnMessage = (amount: anchor.BN) => {
+			const message = Buffer.alloc(248);
+			message.writeUInt32BE(5, 8); // Solana's domain
+			randomBytes(8).copy(message, 12);
+			poolAta.toBuffer().copy(message, 152);
+			amount.toArrayLike(Buffer, "be", 32).copy(message, 184);
+			return message;
+		};
+
+		// `signedMessage` is the burn message the validators attested to, the relayed one by default
+		const withdrawCctp = async (
+			args: WithdrawalFields,
+			minted: anchor.BN,
+			signedMessage?: Buffer
+		) => {
+			const state = await program.account.poolState.fetch(poolState);
+			const message = burnMessage(minted);
+			const [usedNonce] = anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("used_nonce"), message.subarray(4, 8), message.subarray(12, 20)],
+				messenger.programId
+			);
+			return program.methods
+				.withdrawUsdcCctp(
+					wallet,
+					{
+						...args,
+						...sign(
+							buildMessage(args, wallet, signedMessage ?? message),
+							[validator],
+							state.validators
+						),
+					},
+					{ message, attestation: Buffer.alloc(0) }
+				)
+				.accountsPartial({
+					recipientWallet: wallet,
+					mintAccount: usdcMint.publicKey,
+					payerAta: null,
+					validatorSetSnapshot: null,
+					memoProgram: null,
+					solReserve: null,
+					nativeDropReceipt: null,
+					recipientAllowlistEntry: null,
+					withdrawalIou: null,
+				})
+				.remainingAccounts([
+					{ pubkey: messenger.programId, isSigner: false, isWritable: false },
+					{ pubkey: wallet, isSigner: true, isWritable: true },
+					{ pubkey: poolState, isSigner: false, isWritable: false },
+					{ pubkey: usedNonce, isSigner: false, isWritable: true },
+					{ pubkey: minter, isSigner: false, isWritable: false },
+					{ pubkey: usdcMint.publicKey, isSigner: false, isWritable: true },
+					{ pubkey: poolAta, isSigner: false, isWritable: true },
+					{ pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+					{
+						pubkey: anchor.web3.SystemProgram.programId,
+						isSigner: false,
+						isWritable: false,
+					},
+				])
+				.rpc();
+		};
+
+		before(async () => {
+			await setAuthority(
+				program.provider.connection,
+				(program.provider as anchor.AnchorProvider).wallet.payer,
+				usdcMint.publicKey,
+				wallet,
+				AuthorityType.MintTokens,
+				minter
+			);
+		});
+
+		it("Pays out exactly what Circle minted into the pool", async () => {
+			const walletAta = anchor.utils.token.associatedAddress({
+				mint: usdcMint.publicKey,
+				owner: wallet,
+			});
+			const poolBefore = (await getAccount(program.provider.connection, poolAta))
+				.amount;
+			const walletBefore = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			const args = await withdrawalArgs();
+			await withdrawCctp(args, args.amount);
+
+			const poolAfter = (await getAccount(program.provider.connection, poolAta))
+				.amount;
+			const walletAfter = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			assert.equal((poolAfter - poolBefore).toString(), "0");
+			assert.equal((walletAfter - walletBefore).toString(), args.amount.toString());
+		});
+
+		it("Rejects a mint that doesn't match the attested amount", async () => {
+			const args = await withdrawalArgs();
+			await expectError(withdrawCctp(args, args.amount.addn(1)), "CctpMintMismatch");
+			await expectError(withdrawCctp(args, args.amount.subn(1)), "CctpMintMismatch");
+		});
+
+		it("Rejects an attestation for a different burn message", async () => {
+			const args = await withdrawalArgs();
+			await expectError(
+				withdrawCctp(args, args.amount, burnMessage(args.amount)),
+				"FailedToValidate"
+			);
+		});
+
+		// last, the pool has no way left to pay the IOU off
+		it("Pays out the minted amount even with IOUs queued", async () => {
+			const state = await program.account.poolState.fetch(poolState);
+			const [iou] = anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("withdrawal_iou"), state.iouTail.toArrayLike(Buffer, "le", 8)],
+				program.programId
+			);
+			const pool = (await getAccount(program.provider.connection, poolAta)).amount;
+			await withdraw(
+				anchor.web3.Keypair.generate().publicKey,
+				await withdrawalArgs({
+					amount: new anchor.BN(pool.toString())
+						.sub(state.accumulatedFees)
+						.addn(1_000_000),
+				}),
+				{ accounts: { withdrawalIou: iou } }
+			);
+			const queued = await program.account.poolState.fetch(poolState);
+			assert.isTrue(queued.iouHead.lt(queued.iouTail));
+
+			const walletAta = anchor.utils.token.associatedAddress({
+				mint: usdcMint.publicKey,
+				owner: wallet,
+			});
+			const walletBefore = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			const args = await withdrawalArgs();
+			await withdrawCctp(args, args.amount);
+			const walletAfter = (await getAccount(program.provider.connection, walletAta))
+				.amount;
+			assert.equal((walletAfter - walletBefore).toString(), args.amount.toString());
+
+			const after = await program.account.poolState.fetch(poolState);
+			assert.isTrue(after.iouTail.eq(queued.iouTail));
+			assert.equal(
+				after.pendingIouLiabilities.toString(),
+				queued.pendingIouLiabilities.toString()
+			);
+		});
+	});
 });
@@ -0,0 +1,210 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { randomBytes } from "crypto";
+import { BridgeSol } from "../../target/types/bridge_sol";
+import {
+	createMint,
+	getAccount,
+	getMint,
+	getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+import {
+	DepositStatus,
+	buildDepositAttestationMessage,
+	buildMessage,
+	newValidator,
+	sign,
+} from "../helpers";
+
+describe("bridge-sol burn/mint", () => {
+	anchor.setProvider(anchor.AnchorProvider.env());
+
+	const program = anchor.workspace.bridgeSol as Program<BridgeSol>;
+	const wallet = program.provider.wallet.publicKey;
+	const payer = (program.provider as anchor.AnchorProvider).wallet.payer;
+	const treasury = anchor.web3.Keypair.generate();
+	const validator = newValidator();
+	const [poolState] = anchor.web3.PublicKey.findProgramAddressSync(
+		[Buffer.from("pool_state")],
+		program.programId
+	);
+	const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+		[program.programId.toBuffer()],
+		new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+	);
+	let mint: anchor.web3.PublicKey;
+	let poolAta: anchor.web3.PublicKey;
+	let walletAta: anchor.web3.PublicKey;
+	let depositRecord: anchor.web3.PublicKey;
+
+	const expectError = async (promise: Promise<unknown>, code: string) => {
+		try {
+			await promise;
+		} catch (err) {
+			assert.include(err.toString(), code);
+			return;
+		}
+		assert.fail(`expected ${code}`);
+	};
+
+	const supply = async () =>
+		(await getMint(program.provider.connection, mint)).supply;
+	const balance = async (ata: anchor.web3.PublicKey) =>
+		(await getAccount(program.provider.connection, ata)).amount;
+
+	before(async () => {
+		// the pool has to be the wrapped token's mint authority
+		mint = await createMint(program.provider.connection, payer, poolState, null, 6);
+		poolAta = (
+			await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				payer,
+				mint,
+				poolState,
+				true
+			)
+		).address;
+		walletAta = (
+			await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				payer,
+				mint,
+				wallet
+			)
+		).address;
+		await program.methods
+			.initialize({
+				tax: 50,
+				maxTax: 500,
+				validators: [validator.key],
+				requiredSignatures: 1,
+				ownershipTransferWindow: new anchor.BN(7 * 24 * 60 * 60),
+				maxDepositAmount: new anchor.BN(0),
+				maxWithdrawalAmount: new anchor.BN(0),
+				mode: { burnMint: {} },
+				permissioned: false,
+			})
+			.accounts({ usdcMint: mint, treasury: treasury.publicKey, programData })
+			.rpc();
+	});
+
+	it("Mints a signed withdrawal to the recipient", async () => {
+		const state = await program.account.poolState.fetch(poolState);
+		const args = {
+			nonce: [...randomBytes(32)],
+			amount: new anchor.BN(10_000_000),
+			senderEvmAddress: Array(20).fill(4),
+			validatorSetEpoch: state.validatorSetEpoch,
+			reference: null,
+			sourceChainId: new anchor.BN(1),
+			relayerFee: new anchor.BN(0),
+			nativeDrop: new anchor.BN(0),
+		};
+		await program.methods
+			.withdrawUsdc(wallet, {
+				...args,
+				...sign(buildMessage(args, wallet), [validator], state.validators),
+			})
+			.accountsPartial({
+				recipientWallet: wallet,
+				mintAccount: mint,
+				payerAta: null,
+				validatorSetSnapshot: null,
+				memoProgram: null,
+				solReserve: null,
+				nativeDropReceipt: null,
+				recipientAllowlistEntry: null,
+				withdrawalIou: null,
+			})
+			.rpc();
+
+		assert.equal((await supply()).toString(), "10000000");
+		assert.equal((await balance(walletAta)).toString(), "10000000");
+		// nothing is locked on this side
+		assert.equal((await balance(poolAta)).toString(), "0");
+	});
+
+	it("Burns the principal of a deposit and keeps the tax", async () => {
+		const clientNonce = new anchor.BN(randomBytes(8));
+		[depositRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("deposit_record"),
+				wallet.toBuffer(),
+				clientNonce.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+		const supplyBefore = await supply();
+		await program.methods
+			.depositUsdc({
+				amount: new anchor.BN(5_000_000),
+				recipientEvmAddress: Array(20).fill(1),
+				destinationChainId: new anchor.BN(1),
+				targetContract: null,
+				payload: Buffer.alloc(0),
+				reference: null,
+				clientNonce,
+			})
+			.accountsPartial({
+				depositor: wallet,
+				mintAccount: mint,
+				depositorAta: walletAta,
+				depositRecord,
+				depositorAllowlistEntry: null,
+				memoProgram: null,
+			})
+			.rpc();
+
+		// 0.5% of 5 tokens stays in the pool as tax, the rest is burned
+		const record = await program.account.depositRecord.fetch(depositRecord);
+		assert.equal(record.tax.toNumber(), 25_000);
+		assert.equal((supplyBefore - (await supply())).toString(), "4975000");
+		assert.equal((await balance(poolAta)).toString(), "25000");
+	});
+
+	it("Mints a refunded deposit back to the depositor", async () => {
+		const record = await program.account.depositRecord.fetch(depositRecord);
+		const state = await program.account.poolState.fetch(poolState);
+		const supplyBefore = await supply();
+		const walletBefore = await balance(walletAta);
+		const hash = buildDepositAttestationMessage(
+			record.depositId,
+			DepositStatus.refunded,
+			state.validatorSetEpoch
+		);
+		await program.methods
+			.refundDeposit({
+				validatorSetEpoch: state.validatorSetEpoch,
+				...sign(hash, [validator], state.validators),
+			})
+			.accountsPartial({
+				depositRecord,
+				mintAccount: mint,
+				depositorAta: walletAta,
+				validatorSetSnapshot: null,
+			})
+			.rpc();
+
+		assert.equal(
+			((await supply()) - supplyBefore).toString(),
+			record.amount.toString()
+		);
+		assert.equal(
+			((await balance(walletAta)) - walletBefore).toString(),
+			record.amount.toString()
+		);
+		// the tax was never burned, so it isn't minted either
+		assert.equal((await balance(poolAta)).toString(), "25000");
+	});
+
+	it("Rejects liquidity, there is nothing to lock", async () => {
+		await expectError(
+			program.methods
+				.addLiquidity(new anchor.BN(1_000_000))
+				.accounts({ mintAccount: mint, providerAta: walletAta })
+				.rpc(),
+			"WrongBridgeMode"
+		);
+	});
+});
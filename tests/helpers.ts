@@ -0,0 +1,247 @@
+import * as anchor from "@coral-xyz/anchor";
+import { secp256k1 } from "@noble/curves/secp256k1";
+import { createHash } from "crypto";
+
+export const MAX_VALIDATORS = 16;
+
+// validators sign with secp256k1 and are stored on chain as the x-coordinate of their public key
+export type Validator = {
+	secret: Uint8Array;
+	key: anchor.web3.PublicKey;
+};
+
+export const newValidator = (): Validator => {
+	const secret = secp256k1.utils.randomPrivateKey();
+	return {
+		secret,
+		key: new anchor.web3.PublicKey(
+			secp256k1.getPublicKey(secret, true).slice(1)
+		),
+	};
+};
+
+export type Signatures = {
+	r: number[][];
+	s: number[][];
+	v: number[];
+};
+
+// r/s/v arrays index-aligned with `set`, the way the program reads them. Slots
+// for validators that aren't in `signers` stay zeroed.
+export const sign = (
+	hash: Uint8Array,
+	signers: Validator[],
+	set: anchor.web3.PublicKey[]
+): Signatures => {
+	const out: Signatures = {
+		r: Array.from({ length: MAX_VALIDATORS }, () => Array(32).fill(0)),
+		s: Array.from({ length: MAX_VALIDATORS }, () => Array(32).fill(0)),
+		v: Array(MAX_VALIDATORS).fill(0),
+	};
+	for (const signer of signers) {
+		const i = set.findIndex((key) => key.equals(signer.key));
+		if (i < 0) {
+			throw new Error(`${signer.key.toBase58()} is not in the set`);
+		}
+		const signature = secp256k1.sign(hash, signer.secret);
+		out.r[i] = [...signature.toCompactRawBytes().slice(0, 32)];
+		out.s[i] = [...signature.toCompactRawBytes().slice(32)];
+		out.v[i] = 27 + signature.recovery;
+	}
+	return out;
+};
+
+const u64 = (n: anchor.BN | number) =>
+	new anchor.BN(n).toArrayLike(Buffer, "be", 8);
+
+export type WithdrawalFields = {
+	nonce: number[];
+	amount: anchor.BN;
+	senderEvmAddress: number[];
+	validatorSetEpoch: anchor.BN;
+	reference: number[] | null;
+	sourceChainId: anchor.BN;
+	relayerFee: anchor.BN;
+	nativeDrop: anchor.BN;
+};
+
+// mirrors the program's build_message, wrapped in build_cctp_message when the withdrawal
+// relays a CCTP burn message
+export const buildMessage = (
+	args: WithdrawalFields,
+	recipient: anchor.web3.PublicKey,
+	cctpMessage?: Buffer
+): Uint8Array => {
+	const message = createHash("sha256")
+		.update(Buffer.from([1]))
+		.update(Buffer.from(args.nonce))
+		.update(args.amount.toArrayLike(Buffer, "be", 32))
+		.update(Buffer.concat([Buffer.alloc(12), Buffer.from(args.senderEvmAddress)]))
+		.update(recipient.toBuffer())
+		.update(u64(args.validatorSetEpoch))
+		.update(args.reference ? Buffer.from(args.reference) : Buffer.alloc(32))
+		.update(u64(args.sourceChainId))
+		.update(u64(args.relayerFee))
+		.update(u64(args.nativeDrop))
+		.digest();
+	if (!cctpMessage) return message;
+	return createHash("sha256")
+		.update(Buffer.from("cctp"))
+		.update(message)
+		.update(createHash("sha256").update(cctpMessage).digest())
+		.digest();
+};
+
+// mirrors the program's build_rotation_message
+export const buildRotationMessage = (
+	programId: anchor.web3.PublicKey,
+	poolState: anchor.web3.PublicKey,
+	newEpoch: anchor.BN,
+	validators: anchor.web3.PublicKey[],
+	requiredSignatures: number
+): Uint8Array =>
+	createHash("sha256")
+		.update(Buffer.from("bridge-sol:rotate"))
+		.update(programId.toBuffer())
+		.update(poolState.toBuffer())
+		.update(u64(newEpoch))
+		.update(Buffer.from([requiredSignatures]))
+		.update(Buffer.concat(validators.map((key) => key.toBuffer())))
+		.digest();
+
+// waits until the cluster clock is past `timestamp`
+export const waitUntilPast = async (
+	connection: anchor.web3.Connection,
+	timestamp: number
+) => {
+	while ((await connection.getBlockTime(await connection.getSlot())) <= timestamp) {
+		await new Promise((resolve) => setTimeout(resolve, 500));
+	}
+};
+
+// mirrors the program's build_heartbeat_message
+export const buildHeartbeatMessage = (
+	programId: anchor.web3.PublicKey,
+	poolState: anchor.web3.PublicKey,
+	validator: anchor.web3.PublicKey,
+	slot: number
+): Uint8Array =>
+	createHash("sha256")
+		.update(Buffer.from("bridge-sol:heartbeat"))
+		.update(programId.toBuffer())
+		.update(poolState.toBuffer())
+		.update(validator.toBuffer())
+		.update(u64(slot))
+		.digest();
+
+// mirrors the program's build_bond_authority_message
+export const buildBondAuthorityMessage = (
+	programId: anchor.web3.PublicKey,
+	poolState: anchor.web3.PublicKey,
+	validator: anchor.web3.PublicKey,
+	authority: anchor.web3.PublicKey
+): Uint8Array =>
+	createHash("sha256")
+		.update(Buffer.from("bridge-sol:bond"))
+		.update(programId.toBuffer())
+		.update(poolState.toBuffer())
+		.update(validator.toBuffer())
+		.update(authority.toBuffer())
+		.digest();
+
+// a single validator's signature over a withdrawal, the shape `slash_validator` takes
+export const signAttestation = (
+	signer: Validator,
+	args: WithdrawalFields,
+	recipient: anchor.web3.PublicKey
+) => {
+	const signature = sign(buildMessage(args, recipient), [signer], [signer.key]);
+	return {
+		...args,
+		recipient,
+		r: signature.r[0],
+		s: signature.s[0],
+		v: signature.v[0],
+	};
+};
+
+// mirrors the program's DepositStatus
+export const DepositStatus = { processed: 1, refunded: 2 };
+
+// mirrors the program's build_deposit_attestation_message
+export const buildDepositAttestationMessage = (
+	depositId: number[],
+	status: number,
+	validatorSetEpoch: anchor.BN
+): Uint8Array =>
+	createHash("sha256")
+		.update(Buffer.from("deposit_status"))
+		.update(Buffer.from(depositId))
+		.update(Buffer.from([status]))
+		.update(u64(validatorSetEpoch))
+		.digest();
+
+export type DepositIdFields = {
+	sequence: anchor.BN;
+	depositor: anchor.web3.PublicKey;
+	amount: anchor.BN;
+	tax: anchor.BN;
+	recipientEvmAddress: number[];
+	destinationChainId: anchor.BN;
+	deadline: anchor.BN;
+	targetContract: number[] | null;
+	payloadHash: number[];
+	reference: number[] | null;
+};
+
+// mirrors the program's build_deposit_id, takes a fetched deposit record
+export const buildDepositId = (
+	poolState: anchor.web3.PublicKey,
+	record: DepositIdFields
+): Buffer =>
+	createHash("sha256")
+		.update(Buffer.from("deposit"))
+		.update(poolState.toBuffer())
+		.update(u64(record.sequence))
+		.update(record.depositor.toBuffer())
+		.update(u64(record.amount))
+		.update(u64(record.tax))
+		.update(Buffer.from(record.recipientEvmAddress))
+		.update(u64(record.destinationChainId))
+		.update(u64(record.deadline))
+		.update(record.targetContract ? Buffer.from(record.targetContract) : Buffer.alloc(20))
+		.update(Buffer.from(record.payloadHash))
+		.update(record.reference ? Buffer.from(record.reference) : Buffer.alloc(32))
+		.digest();
+
+export const MEMO_PROGRAM_ID = new anchor.web3.PublicKey(
+	"MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+);
+
+// mirrors the program's build_call_message and hash_account_metas, `accounts` are the ones
+// handed to the target, without the target itself
+export const buildCallMessage = (
+	withdrawalMessage: Uint8Array,
+	targetProgram: anchor.web3.PublicKey,
+	data: Buffer,
+	accounts: anchor.web3.AccountMeta[]
+): Uint8Array =>
+	createHash("sha256")
+		.update(Buffer.from("call"))
+		.update(withdrawalMessage)
+		.update(targetProgram.toBuffer())
+		.update(
+			data.length ? createHash("sha256").update(data).digest() : Buffer.alloc(32)
+		)
+		.update(
+			accounts
+				.reduce(
+					(hasher, meta) =>
+						hasher
+							.update(meta.pubkey.toBuffer())
+							.update(Buffer.from([meta.isSigner ? 1 : 0, meta.isWritable ? 1 : 0])),
+					createHash("sha256")
+				)
+				.digest()
+		)
+		.digest();